@@ -0,0 +1,235 @@
+use crate::mat4::Mat4d;
+use crate::vec3::Vec3d;
+
+/// An axis-aligned bounding box of `f64` values stored as its minimum and maximum corners
+///
+/// The box is closed: points on its faces are inside it, and boxes that only touch along a face
+/// still intersect. A box with `min` greater than `max` on any axis contains nothing.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Aabb3d {
+    /// The corner with the smallest coordinates
+    pub min: Vec3d,
+    /// The corner with the largest coordinates
+    pub max: Vec3d,
+}
+
+impl Aabb3d {
+    /// Creates a new box from its corners
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - A `Vec3d` holding the corner with the smallest coordinates
+    ///
+    /// * `max` - A `Vec3d` holding the corner with the largest coordinates
+    pub const fn new(min: Vec3d, max: Vec3d) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates a box of size `size` centered on `center`
+    pub fn from_center_size(center: Vec3d, size: Vec3d) -> Self {
+        let half = size * 0.5;
+        Self::new(center - half, center + half)
+    }
+
+    /// Creates the smallest box containing every point of `points`, or `None` if there are no
+    /// points
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::aabb::Aabb3d;
+    /// use yavml::vec3::Vec3d;
+    /// let points = [Vec3d::new(1.0, 5.0, 0.0), Vec3d::new(-2.0, 3.0, 4.0)];
+    /// let aabb = Aabb3d::from_points(points).unwrap();
+    /// assert_eq!(aabb, Aabb3d::new(Vec3d::new(-2.0, 3.0, 0.0), Vec3d::new(1.0, 5.0, 4.0)));
+    /// ```
+    pub fn from_points<I: IntoIterator<Item = Vec3d>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, first), |aabb, point| aabb.expand(point)))
+    }
+
+    /// Returns the extent of the box along each axis
+    pub fn size(&self) -> Vec3d {
+        self.max - self.min
+    }
+
+    /// Returns the center of the box
+    pub fn center(&self) -> Vec3d {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Returns the volume of the box, or zero if it is empty
+    pub fn volume(&self) -> f64 {
+        let size = self.size();
+        size.x.max(0.0) * size.y.max(0.0) * size.z.max(0.0)
+    }
+
+    /// Returns the total area of the six faces of the box, or zero if it is empty
+    ///
+    /// This is the usual cost metric when building bounding volume hierarchies.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::aabb::Aabb3d;
+    /// use yavml::vec3::Vec3d;
+    /// let aabb = Aabb3d::new(Vec3d::ZERO, Vec3d::new(1.0, 2.0, 3.0));
+    /// assert_eq!(aabb.surface_area(), 22.0);
+    /// ```
+    pub fn surface_area(&self) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        let size = self.size();
+        2.0 * (size.x * size.y + size.y * size.z + size.z * size.x)
+    }
+
+    /// Returns `true` if `min` is greater than `max` on any axis
+    ///
+    /// A box with `min == max` is a single point and is not empty.
+    pub fn is_empty(&self) -> bool {
+        !(self.min.x <= self.max.x && self.min.y <= self.max.y && self.min.z <= self.max.z)
+    }
+
+    /// Returns `true` if `point` is inside the box or on its faces
+    pub fn contains_point(&self, point: Vec3d) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+            && point.z >= self.min.z
+            && point.z <= self.max.z
+    }
+
+    /// Returns `true` if every point of `aabb` is inside `self`
+    ///
+    /// An empty `aabb` is contained in every box.
+    pub fn contains_aabb(&self, aabb: &Self) -> bool {
+        aabb.is_empty() || (self.contains_point(aabb.min) && self.contains_point(aabb.max))
+    }
+
+    /// Returns `true` if `self` and `aabb` share at least one point
+    ///
+    /// An empty box intersects nothing, matching [`Self::intersection`].
+    pub fn intersects(&self, aabb: &Self) -> bool {
+        self.intersection(aabb).is_some()
+    }
+
+    /// Returns the overlap of `self` and `aabb`, or `None` if they do not intersect
+    pub fn intersection(&self, aabb: &Self) -> Option<Self> {
+        let overlap = Self::new(
+            Vec3d::new(
+                self.min.x.max(aabb.min.x),
+                self.min.y.max(aabb.min.y),
+                self.min.z.max(aabb.min.z),
+            ),
+            Vec3d::new(
+                self.max.x.min(aabb.max.x),
+                self.max.y.min(aabb.max.y),
+                self.max.z.min(aabb.max.z),
+            ),
+        );
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    /// Returns the smallest box containing both `self` and `aabb`
+    ///
+    /// Empty boxes are ignored, and the union of two empty boxes is `self`.
+    pub fn union(&self, aabb: &Self) -> Self {
+        if aabb.is_empty() {
+            return *self;
+        }
+        if self.is_empty() {
+            return *aabb;
+        }
+        Self::new(
+            Vec3d::new(
+                self.min.x.min(aabb.min.x),
+                self.min.y.min(aabb.min.y),
+                self.min.z.min(aabb.min.z),
+            ),
+            Vec3d::new(
+                self.max.x.max(aabb.max.x),
+                self.max.y.max(aabb.max.y),
+                self.max.z.max(aabb.max.z),
+            ),
+        )
+    }
+
+    /// Returns the smallest box containing both `self` and `point`
+    pub fn expand(&self, point: Vec3d) -> Self {
+        self.union(&Self::new(point, point))
+    }
+
+    /// Returns the box with each face moved outwards by `amount`
+    ///
+    /// Negative values shrink the box, which becomes empty once a face passes its opposite face.
+    pub fn inflate(&self, amount: Vec3d) -> Self {
+        Self::new(self.min - amount, self.max + amount)
+    }
+
+    /// Returns the point of the box closest to `point`, which is `point` itself when it is inside
+    ///
+    /// The box must not be empty.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::aabb::Aabb3d;
+    /// use yavml::vec3::Vec3d;
+    /// let aabb = Aabb3d::new(Vec3d::ZERO, Vec3d::ONE);
+    /// let closest = aabb.closest_point(Vec3d::new(2.0, 0.5, -3.0));
+    /// assert_eq!(closest, Vec3d::new(1.0, 0.5, 0.0));
+    /// assert_eq!(aabb.distance_squared(Vec3d::new(2.0, 0.5, -3.0)), 10.0);
+    /// ```
+    pub fn closest_point(&self, point: Vec3d) -> Vec3d {
+        Vec3d::new(
+            point.x.max(self.min.x).min(self.max.x),
+            point.y.max(self.min.y).min(self.max.y),
+            point.z.max(self.min.z).min(self.max.z),
+        )
+    }
+
+    /// Returns the squared distance from `point` to the box, which is zero when it is inside
+    ///
+    /// The box must not be empty.
+    pub fn distance_squared(&self, point: Vec3d) -> f64 {
+        let offset = point - self.closest_point(point);
+        offset.dot(offset)
+    }
+
+    /// Returns the bounding box of the eight corners of `self` transformed by the affine matrix
+    /// `mat`
+    ///
+    /// This uses Arvo's method, which finds the new extent from the matrix elements and the old
+    /// extent without transforming each corner. The bottom row of `mat` is ignored, so
+    /// projective matrices are not supported. The box must not be empty.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::aabb::Aabb3d;
+    /// use yavml::mat4::Mat4d;
+    /// use yavml::vec3::Vec3d;
+    /// let aabb = Aabb3d::new(Vec3d::new(-1.0, -1.0, 0.0), Vec3d::new(1.0, 1.0, 2.0));
+    /// let rotation = Mat4d::from_axis_angle(Vec3d::Z, core::f64::consts::FRAC_PI_4);
+    /// let moved = Mat4d::from_translation(Vec3d::new(0.0, 0.0, 5.0)) * rotation;
+    /// let bounds = aabb.transformed_by(&moved);
+    /// let half_diagonal = 2.0_f64.sqrt();
+    /// assert!((bounds.max.x - half_diagonal).abs() < 1e-6);
+    /// assert!((bounds.min.z - 5.0).abs() < 1e-6);
+    /// ```
+    pub fn transformed_by(&self, mat: &Mat4d) -> Self {
+        let cols = [mat.x_axis, mat.y_axis, mat.z_axis].map(|col| [col.x, col.y, col.z]);
+        let old_min = self.min.to_array();
+        let old_max = self.max.to_array();
+        let mut min = [mat.w_axis.x, mat.w_axis.y, mat.w_axis.z];
+        let mut max = min;
+        for (col, (&low, &high)) in cols.iter().zip(old_min.iter().zip(old_max.iter())) {
+            for row in 0..3 {
+                let a = col[row] * low;
+                let b = col[row] * high;
+                min[row] += a.min(b);
+                max[row] += a.max(b);
+            }
+        }
+        Self::new(Vec3d::from_arr(min), Vec3d::from_arr(max))
+    }
+}
@@ -0,0 +1,214 @@
+use super::Aabb3f;
+use crate::vec3::Vec3i;
+
+/// An axis-aligned box of integer cells stored as its minimum and maximum corners
+///
+/// Like [`Rect2i`](crate::rect::Rect2i) the box is half-open: `min` is inclusive and `max` is
+/// exclusive, so it covers the cells with `min <= p < max` on every axis. Boxes that only share a
+/// face do not intersect, and a box with `min` greater than or equal to `max` on any axis covers
+/// no cells. Transform the box with [`Aabb3f::transformed_by`] after converting it with
+/// [`Aabb3i::as_aabb3f`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Aabb3i {
+    /// The first cell inside the box
+    pub min: Vec3i,
+    /// The corner one past the last cell inside the box
+    pub max: Vec3i,
+}
+
+impl Aabb3i {
+    /// Creates a new box from its inclusive `min` and exclusive `max` corners
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - A `Vec3i` holding the first cell inside the box
+    ///
+    /// * `max` - A `Vec3i` holding the corner one past the last cell inside the box
+    pub const fn new(min: Vec3i, max: Vec3i) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates a box of `size` cells placed at `center`
+    ///
+    /// When a side has an even number of cells, `center` is the first cell of the upper half.
+    pub fn from_center_size(center: Vec3i, size: Vec3i) -> Self {
+        let min = center - size / 2;
+        Self::new(min, min + size)
+    }
+
+    /// Creates the smallest box covering every cell of `points`, or `None` if there are no
+    /// points
+    pub fn from_points<I: IntoIterator<Item = Vec3i>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(
+            points.fold(Self::new(first, first + Vec3i::ONE), |aabb, point| {
+                aabb.expand(point)
+            }),
+        )
+    }
+
+    /// Returns the number of cells along each axis
+    ///
+    /// A side of an empty box can be zero or negative.
+    pub fn size(&self) -> Vec3i {
+        self.max - self.min
+    }
+
+    /// Returns the cell at the center of the box, rounding towards `min`
+    pub fn center(&self) -> Vec3i {
+        self.min + self.size() / 2
+    }
+
+    /// Returns the number of cells in the box, or zero if it is empty
+    ///
+    /// The count is a `u128`, which holds the volume of every box with corners in the `i32`
+    /// range without overflowing.
+    pub fn volume(&self) -> u128 {
+        let [x, y, z] = self.sides();
+        x * y * z
+    }
+
+    /// Returns the number of unit cell faces on the outside of the box, or zero if it is empty
+    ///
+    /// Like [`Aabb3i::volume`] the count cannot overflow.
+    pub fn surface_area(&self) -> u128 {
+        if self.is_empty() {
+            return 0;
+        }
+        let [x, y, z] = self.sides();
+        2 * (x * y + y * z + z * x)
+    }
+
+    /// Returns `true` if the box covers no cells
+    pub fn is_empty(&self) -> bool {
+        self.min.x >= self.max.x || self.min.y >= self.max.y || self.min.z >= self.max.z
+    }
+
+    /// Returns `true` if the cell `point` is inside the box
+    pub fn contains_point(&self, point: Vec3i) -> bool {
+        point.x >= self.min.x
+            && point.x < self.max.x
+            && point.y >= self.min.y
+            && point.y < self.max.y
+            && point.z >= self.min.z
+            && point.z < self.max.z
+    }
+
+    /// Returns `true` if every cell of `aabb` is inside `self`
+    ///
+    /// An empty `aabb` is contained in every box.
+    pub fn contains_aabb(&self, aabb: &Self) -> bool {
+        aabb.is_empty()
+            || (aabb.min.x >= self.min.x
+                && aabb.max.x <= self.max.x
+                && aabb.min.y >= self.min.y
+                && aabb.max.y <= self.max.y
+                && aabb.min.z >= self.min.z
+                && aabb.max.z <= self.max.z)
+    }
+
+    /// Returns `true` if `self` and `aabb` share at least one cell
+    pub fn intersects(&self, aabb: &Self) -> bool {
+        self.intersection(aabb).is_some()
+    }
+
+    /// Returns the cells shared by `self` and `aabb`, or `None` if there are none
+    pub fn intersection(&self, aabb: &Self) -> Option<Self> {
+        let overlap = Self::new(
+            Vec3i::new(
+                self.min.x.max(aabb.min.x),
+                self.min.y.max(aabb.min.y),
+                self.min.z.max(aabb.min.z),
+            ),
+            Vec3i::new(
+                self.max.x.min(aabb.max.x),
+                self.max.y.min(aabb.max.y),
+                self.max.z.min(aabb.max.z),
+            ),
+        );
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    /// Returns the smallest box covering both `self` and `aabb`
+    ///
+    /// Empty boxes are ignored, and the union of two empty boxes is `self`.
+    pub fn union(&self, aabb: &Self) -> Self {
+        if aabb.is_empty() {
+            return *self;
+        }
+        if self.is_empty() {
+            return *aabb;
+        }
+        Self::new(
+            Vec3i::new(
+                self.min.x.min(aabb.min.x),
+                self.min.y.min(aabb.min.y),
+                self.min.z.min(aabb.min.z),
+            ),
+            Vec3i::new(
+                self.max.x.max(aabb.max.x),
+                self.max.y.max(aabb.max.y),
+                self.max.z.max(aabb.max.z),
+            ),
+        )
+    }
+
+    /// Returns the smallest box covering both `self` and the cell `point`
+    ///
+    /// The box must not be empty.
+    pub fn expand(&self, point: Vec3i) -> Self {
+        self.union(&Self::new(point, point + Vec3i::ONE))
+    }
+
+    /// Returns the box with each face moved outwards by `amount` cells
+    ///
+    /// Negative values shrink the box, which becomes empty once a face reaches its opposite face.
+    pub fn inflate(&self, amount: Vec3i) -> Self {
+        Self::new(self.min - amount, self.max + amount)
+    }
+
+    /// Returns the cell of the box closest to `point`
+    ///
+    /// The result is always between `min` and `max - 1`, so the box must not be empty.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::aabb::Aabb3i;
+    /// use yavml::vec3::Vec3i;
+    /// let aabb = Aabb3i::new(Vec3i::ZERO, Vec3i::splat(4));
+    /// assert_eq!(aabb.closest_point(Vec3i::new(9, 2, -1)), Vec3i::new(3, 2, 0));
+    /// assert_eq!(aabb.distance_squared(Vec3i::new(9, 2, -1)), 37);
+    /// ```
+    pub fn closest_point(&self, point: Vec3i) -> Vec3i {
+        Vec3i::new(
+            point.x.min(self.max.x - 1).max(self.min.x),
+            point.y.min(self.max.y - 1).max(self.min.y),
+            point.z.min(self.max.z - 1).max(self.min.z),
+        )
+    }
+
+    /// Returns the squared distance from the cell `point` to the closest cell of the box, which
+    /// is zero when it is inside
+    ///
+    /// The box must not be empty.
+    pub fn distance_squared(&self, point: Vec3i) -> i32 {
+        let offset = point - self.closest_point(point);
+        offset.dot(offset)
+    }
+
+    /// Returns the volume covered by the cells as an `Aabb3f`
+    pub const fn as_aabb3f(&self) -> Aabb3f {
+        Aabb3f::new(self.min.as_vec3f(), self.max.as_vec3f())
+    }
+
+    /// Returns the number of cells along each axis, or zero for an empty side
+    fn sides(&self) -> [u128; 3] {
+        let side = |min: i32, max: i32| (i64::from(max) - i64::from(min)).max(0) as u128;
+        [
+            side(self.min.x, self.max.x),
+            side(self.min.y, self.max.y),
+            side(self.min.z, self.max.z),
+        ]
+    }
+}
@@ -0,0 +1,11 @@
+pub mod aabb3f;
+pub use aabb3f::Aabb3f;
+
+pub mod aabb3d;
+pub use aabb3d::Aabb3d;
+
+pub mod aabb3i;
+pub use aabb3i::Aabb3i;
+
+#[cfg(test)]
+mod tests;
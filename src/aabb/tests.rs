@@ -0,0 +1,224 @@
+use super::Aabb3d;
+use super::Aabb3f;
+use super::Aabb3i;
+use crate::mat4::Mat4d;
+use crate::mat4::Mat4f;
+use crate::vec3::Vec3d;
+use crate::vec3::Vec3f;
+use crate::vec3::Vec3i;
+
+mod aabb3f_tests {
+    use super::Aabb3f;
+    use super::Mat4f;
+    use super::Vec3f;
+
+    fn sample() -> Aabb3f {
+        Aabb3f::new(Vec3f::new(-1.0, 2.0, 0.0), Vec3f::new(3.0, 5.0, 2.0))
+    }
+
+    fn corners(aabb: &Aabb3f) -> [Vec3f; 8] {
+        let (min, max) = (aabb.min, aabb.max);
+        [
+            Vec3f::new(min.x, min.y, min.z),
+            Vec3f::new(max.x, min.y, min.z),
+            Vec3f::new(min.x, max.y, min.z),
+            Vec3f::new(max.x, max.y, min.z),
+            Vec3f::new(min.x, min.y, max.z),
+            Vec3f::new(max.x, min.y, max.z),
+            Vec3f::new(min.x, max.y, max.z),
+            Vec3f::new(max.x, max.y, max.z),
+        ]
+    }
+
+    // Tests the size, center, volume and surface area, including empty boxes
+    #[test]
+    fn test_aabb3f_measurements() {
+        let aabb = sample();
+        assert_eq!(aabb.size(), Vec3f::new(4.0, 3.0, 2.0));
+        assert_eq!(aabb.center(), Vec3f::new(1.0, 3.5, 1.0));
+        assert_eq!(aabb.volume(), 24.0);
+        assert_eq!(aabb.surface_area(), 52.0);
+        assert_eq!(Aabb3f::from_center_size(aabb.center(), aabb.size()), aabb);
+        let empty = aabb.inflate(Vec3f::new(0.0, 0.0, -1.5));
+        assert!(empty.is_empty());
+        assert_eq!(empty.volume(), 0.0);
+        assert_eq!(empty.surface_area(), 0.0);
+    }
+
+    // Tests containment and intersection with closed faces
+    #[test]
+    fn test_aabb3f_set_operations() {
+        let aabb = sample();
+        assert!(aabb.contains_point(Vec3f::new(3.0, 5.0, 0.0)));
+        assert!(!aabb.contains_point(Vec3f::new(3.0, 5.0, -0.1)));
+        assert!(aabb.contains_aabb(&aabb.inflate(Vec3f::splat(-0.5))));
+
+        let touching = Aabb3f::new(Vec3f::new(3.0, 0.0, 0.0), Vec3f::new(4.0, 3.0, 1.0));
+        assert!(aabb.intersects(&touching));
+        assert_eq!(
+            aabb.intersection(&touching),
+            Some(Aabb3f::new(
+                Vec3f::new(3.0, 2.0, 0.0),
+                Vec3f::new(3.0, 3.0, 1.0)
+            ))
+        );
+        let apart = Aabb3f::new(Vec3f::new(0.0, 0.0, 2.5), Vec3f::ONE * 3.0);
+        assert!(!aabb.intersects(&apart));
+        assert_eq!(aabb.intersection(&apart), None);
+
+        let union = aabb.union(&apart);
+        assert_eq!(
+            union,
+            Aabb3f::new(Vec3f::new(-1.0, 0.0, 0.0), Vec3f::new(3.0, 5.0, 3.0))
+        );
+        assert_eq!(aabb.expand(Vec3f::new(0.0, 0.0, -4.0)).min.z, -4.0);
+        assert_eq!(Aabb3f::from_points(corners(&aabb)), Some(aabb));
+        assert_eq!(Aabb3f::from_points(core::iter::empty()), None);
+    }
+
+    // Tests that empty boxes are ignored by union and contained in every box
+    #[test]
+    fn test_aabb3f_empty_operands() {
+        let aabb = sample();
+        let empty = Aabb3f::new(Vec3f::splat(50.0), Vec3f::new(60.0, 40.0, 60.0));
+        assert!(aabb.contains_aabb(&empty));
+        assert!(empty.contains_aabb(&empty));
+        assert!(!empty.contains_aabb(&aabb));
+        assert_eq!(aabb.union(&empty), aabb);
+        assert_eq!(empty.union(&aabb), aabb);
+        let inside = Aabb3f::new(Vec3f::new(1.0, 4.0, 1.5), Vec3f::new(0.0, 3.0, 0.5));
+        assert!(!aabb.intersects(&inside));
+        assert!(!inside.intersects(&aabb));
+        assert_eq!(aabb.intersection(&inside), None);
+    }
+
+    // Tests the closest point and squared distance for points inside and outside
+    #[test]
+    fn test_aabb3f_closest_point() {
+        let aabb = sample();
+        let inside = Vec3f::new(0.0, 3.0, 1.0);
+        assert_eq!(aabb.closest_point(inside), inside);
+        assert_eq!(aabb.distance_squared(inside), 0.0);
+        let outside = Vec3f::new(5.0, 3.0, -1.0);
+        assert_eq!(aabb.closest_point(outside), Vec3f::new(3.0, 3.0, 0.0));
+        assert_eq!(aabb.distance_squared(outside), 5.0);
+        assert_eq!(aabb.distance_squared(Vec3f::new(-2.0, 0.0, 4.0)), 9.0);
+    }
+
+    // Tests that the transformed box is the bounds of the transformed corners
+    #[test]
+    fn test_aabb3f_transformed_by() {
+        let aabb = sample();
+        let mat = Mat4f::from_translation(Vec3f::new(1.0, -2.0, 3.0))
+            * Mat4f::from_axis_angle(Vec3f::new(0.0, 0.6, 0.8), 0.9)
+            * Mat4f::from_scale(Vec3f::new(2.0, -1.0, 0.5));
+        let transformed = corners(&aabb).map(|corner| mat.transform_point3(corner));
+        let expected = Aabb3f::from_points(transformed).unwrap();
+        let bounds = aabb.transformed_by(&mat);
+        assert!((bounds.min - expected.min).length() < 1e-5);
+        assert!((bounds.max - expected.max).length() < 1e-5);
+        assert_eq!(aabb.transformed_by(&Mat4f::IDENTITY), aabb);
+    }
+}
+
+mod aabb3d_tests {
+    use super::Aabb3d;
+    use super::Mat4d;
+    use super::Vec3d;
+
+    // Tests the basic operations and transformation in double precision
+    #[test]
+    fn test_aabb3d_operations() {
+        let aabb = Aabb3d::from_center_size(Vec3d::ZERO, Vec3d::new(2.0, 4.0, 6.0));
+        assert_eq!(aabb.surface_area(), 88.0);
+        assert_eq!(aabb.distance_squared(Vec3d::new(0.0, 4.0, 0.0)), 4.0);
+        let empty = Aabb3d::new(Vec3d::splat(9.0), Vec3d::splat(-9.0));
+        assert!(aabb.contains_aabb(&empty));
+        assert_eq!(aabb.union(&empty), aabb);
+        let inside = Aabb3d::new(Vec3d::splat(0.5), Vec3d::splat(-0.5));
+        assert!(!aabb.intersects(&inside));
+        assert_eq!(aabb.intersection(&inside), None);
+        let rotated = aabb.transformed_by(&Mat4d::from_axis_angle(
+            Vec3d::Z,
+            core::f64::consts::FRAC_PI_2,
+        ));
+        assert!((rotated.max - Vec3d::new(2.0, 1.0, 3.0)).length() < 1e-12);
+        assert!((rotated.min - Vec3d::new(-2.0, -1.0, -3.0)).length() < 1e-12);
+    }
+}
+
+mod aabb3i_tests {
+    use super::Aabb3i;
+    use super::Vec3i;
+
+    fn sample() -> Aabb3i {
+        Aabb3i::new(Vec3i::new(-1, 2, 0), Vec3i::new(3, 5, 2))
+    }
+
+    // Tests that the maximum corner is excluded and counts match the cells covered
+    #[test]
+    fn test_aabb3i_half_open() {
+        let aabb = sample();
+        assert_eq!(aabb.volume(), 24);
+        assert_eq!(aabb.surface_area(), 52);
+        assert!(aabb.contains_point(Vec3i::new(2, 4, 1)));
+        assert!(!aabb.contains_point(Vec3i::new(2, 4, 2)));
+        let mut count = 0;
+        for z in -3..6 {
+            for y in -3..8 {
+                for x in -3..6 {
+                    count += aabb.contains_point(Vec3i::new(x, y, z)) as u128;
+                }
+            }
+        }
+        assert_eq!(count, aabb.volume());
+        assert!(Aabb3i::new(Vec3i::ZERO, Vec3i::new(1, 0, 1)).is_empty());
+        let large = Aabb3i::new(Vec3i::ZERO, Vec3i::splat(2_000));
+        assert_eq!(large.volume(), 8_000_000_000);
+        assert_eq!(large.surface_area(), 24_000_000);
+        let huge = Aabb3i::new(Vec3i::splat(i32::MIN), Vec3i::splat(i32::MAX));
+        assert_eq!(huge.volume(), u128::from(u32::MAX).pow(3));
+    }
+
+    // Tests intersection, union and construction from points
+    #[test]
+    fn test_aabb3i_set_operations() {
+        let aabb = sample();
+        let touching = Aabb3i::new(Vec3i::new(3, 2, 0), Vec3i::new(5, 5, 2));
+        assert!(!aabb.intersects(&touching));
+        let overlapping = Aabb3i::new(Vec3i::new(2, 4, 1), Vec3i::new(5, 5, 2));
+        assert_eq!(
+            aabb.intersection(&overlapping),
+            Some(Aabb3i::new(Vec3i::new(2, 4, 1), Vec3i::new(3, 5, 2)))
+        );
+        assert_eq!(
+            aabb.union(&touching),
+            Aabb3i::new(Vec3i::new(-1, 2, 0), Vec3i::new(5, 5, 2))
+        );
+        assert_eq!(aabb.union(&Aabb3i::default()), aabb);
+        assert!(aabb.contains_aabb(&overlapping.intersection(&aabb).unwrap()));
+        let empty = Aabb3i::new(Vec3i::splat(50), Vec3i::new(60, 40, 60));
+        assert!(aabb.contains_aabb(&empty));
+        assert!(!empty.contains_aabb(&aabb));
+        assert_eq!(empty.union(&aabb), aabb);
+        let points = [Vec3i::new(-1, 4, 1), Vec3i::new(2, 2, 0)];
+        assert_eq!(
+            Aabb3i::from_points(points),
+            Some(Aabb3i::new(Vec3i::new(-1, 2, 0), Vec3i::new(3, 5, 2)))
+        );
+        assert_eq!(aabb.center(), Vec3i::new(1, 3, 1));
+        assert_eq!(aabb.as_aabb3f().volume(), 24.0);
+    }
+
+    // Tests the closest cell and squared distance to cells outside the box
+    #[test]
+    fn test_aabb3i_closest_point() {
+        let aabb = sample();
+        assert_eq!(
+            aabb.closest_point(Vec3i::new(10, 0, 1)),
+            Vec3i::new(2, 2, 1)
+        );
+        assert_eq!(aabb.distance_squared(Vec3i::new(10, 0, 1)), 68);
+        assert_eq!(aabb.distance_squared(Vec3i::new(0, 3, 1)), 0);
+    }
+}
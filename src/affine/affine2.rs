@@ -0,0 +1,202 @@
+use core::ops::*;
+
+use crate::angle::Radians;
+use crate::error::Error;
+use crate::mat2::Mat2f;
+use crate::mat3::Mat3f;
+use crate::vec2::Vec2f;
+use crate::vec3::Vec3f;
+
+/// A 2D affine transform made of a linear `Mat2f` part followed by a translation
+///
+/// This holds the same transform as a 2D affine `Mat3f` without storing the constant bottom row.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Affine2 {
+    /// The linear part of the transform
+    pub matrix2: Mat2f,
+    /// The translation applied after the linear part
+    pub translation: Vec2f,
+}
+
+impl Affine2 {
+    /// The transform that maps every point to the origin
+    pub const ZERO: Self = Self::from_mat2_translation(Mat2f::ZERO, Vec2f::ZERO);
+    /// The identity transform
+    pub const IDENTITY: Self = Self::from_mat2_translation(Mat2f::IDENTITY, Vec2f::ZERO);
+
+    /// Creates a transform from a linear part and a translation
+    pub const fn from_mat2_translation(matrix2: Mat2f, translation: Vec2f) -> Self {
+        Self {
+            matrix2,
+            translation,
+        }
+    }
+
+    /// Creates a transform from a linear part, without a translation
+    pub const fn from_mat2(matrix2: Mat2f) -> Self {
+        Self::from_mat2_translation(matrix2, Vec2f::ZERO)
+    }
+
+    /// Creates a transform that translates by `translation`
+    pub const fn from_translation(translation: Vec2f) -> Self {
+        Self::from_mat2_translation(Mat2f::IDENTITY, translation)
+    }
+
+    /// Creates a transform that rotates counter-clockwise by `angle` radians
+    pub fn from_angle(angle: impl Into<Radians<f32>>) -> Self {
+        Self::from_mat2(Mat2f::from_angle(angle))
+    }
+
+    /// Creates a transform that scales along the x and y axes by `scale`
+    pub const fn from_scale(scale: Vec2f) -> Self {
+        Self::from_mat2(Mat2f::from_scale(scale))
+    }
+
+    /// Creates a transform that scales by `scale`, then rotates by `angle` radians and then
+    /// translates by `translation`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::affine::Affine2;
+    /// use yavml::mat3::Mat3f;
+    /// use yavml::vec2::Vec2f;
+    /// let scale = Vec2f::new(2.0, 3.0);
+    /// let translation = Vec2f::new(1.0, -1.0);
+    /// let affine = Affine2::from_scale_angle_translation(scale, 0.5, translation);
+    /// let matrix = Mat3f::from_scale_angle_translation(scale, 0.5, translation);
+    /// assert_eq!(affine.to_mat3(), matrix);
+    /// ```
+    pub fn from_scale_angle_translation(
+        scale: Vec2f,
+        angle: impl Into<Radians<f32>>,
+        translation: Vec2f,
+    ) -> Self {
+        Self::from_mat2_translation(
+            Mat2f::from_angle(angle) * Mat2f::from_scale(scale),
+            translation,
+        )
+    }
+
+    /// Splits the transform into the arguments of [`Affine2::from_scale_angle_translation`]
+    ///
+    /// A transform with a negative determinant is reported with a negative x scale.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Degenerate`] if an axis has zero length and [`Error::Shear`] if the axes
+    /// are not perpendicular.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::affine::Affine2;
+    /// use yavml::vec2::Vec2f;
+    /// let affine = Affine2::from_scale_angle_translation(Vec2f::new(2.0, 3.0), 0.5, Vec2f::ONE);
+    /// let (scale, angle, translation) = affine.to_scale_angle_translation().unwrap();
+    /// assert!((scale - Vec2f::new(2.0, 3.0)).length() < 1e-5);
+    /// assert!((angle - 0.5).abs() < 1e-5);
+    /// assert_eq!(translation, Vec2f::ONE);
+    /// ```
+    pub fn to_scale_angle_translation(&self) -> Result<(Vec2f, f32, Vec2f), Error> {
+        let (scale, angle) = self.matrix2.to_scale_angle()?;
+        Ok((scale, angle, self.translation))
+    }
+
+    /// Creates a transform from a 2D affine `Mat3f`, ignoring its bottom row
+    pub const fn from_mat3(mat: Mat3f) -> Self {
+        Self::from_mat2_translation(mat.to_mat2(), Vec2f::new(mat.z_axis.x, mat.z_axis.y))
+    }
+
+    /// Returns the transform as a 2D affine `Mat3f`
+    pub const fn to_mat3(&self) -> Mat3f {
+        Mat3f::from_cols(
+            Vec3f::new(self.matrix2.x_axis.x, self.matrix2.x_axis.y, 0.0),
+            Vec3f::new(self.matrix2.y_axis.x, self.matrix2.y_axis.y, 0.0),
+            Vec3f::new(self.translation.x, self.translation.y, 1.0),
+        )
+    }
+
+    /// Transforms `point`, applying both the linear part and the translation
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::affine::Affine2;
+    /// use yavml::vec2::Vec2f;
+    /// let affine = Affine2::from_translation(Vec2f::new(1.0, 2.0));
+    /// assert_eq!(affine.transform_point(Vec2f::ONE), Vec2f::new(2.0, 3.0));
+    /// assert_eq!(affine.transform_vector(Vec2f::ONE), Vec2f::ONE);
+    /// ```
+    pub fn transform_point(&self, point: Vec2f) -> Vec2f {
+        self.matrix2 * point + self.translation
+    }
+
+    /// Transforms `vector`, applying only the linear part
+    pub fn transform_vector(&self, vector: Vec2f) -> Vec2f {
+        self.matrix2 * vector
+    }
+
+    /// Returns the inverse transform, or `None` if the linear part is not invertible
+    ///
+    /// Only the 2x2 linear part is inverted, which is cheaper and loses less precision than
+    /// inverting the equivalent `Mat3f`.
+    pub fn try_inverse(&self) -> Option<Self> {
+        let matrix2 = self.matrix2.try_inverse()?;
+        Some(Self::from_mat2_translation(
+            matrix2,
+            -(matrix2 * self.translation),
+        ))
+    }
+
+    /// Returns the inverse transform
+    ///
+    /// # Panics
+    ///
+    /// Panics if the linear part is not invertible. Use [`Affine2::try_inverse`] when that can happen.
+    pub fn inverse(&self) -> Self {
+        self.try_inverse()
+            .expect("affine transform is not invertible")
+    }
+
+    /// Returns `true` if every element of `self` and `rhs` differs by at most `max_abs_diff`
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.matrix2.abs_diff_eq(rhs.matrix2, max_abs_diff)
+            && (self.translation.x - rhs.translation.x).abs() <= max_abs_diff
+            && (self.translation.y - rhs.translation.y).abs() <= max_abs_diff
+    }
+}
+
+impl Default for Affine2 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Affine2> for Mat3f {
+    fn from(affine: Affine2) -> Self {
+        affine.to_mat3()
+    }
+}
+
+impl From<Mat3f> for Affine2 {
+    fn from(mat: Mat3f) -> Self {
+        Self::from_mat3(mat)
+    }
+}
+
+/// Composition of transforms, applying `rhs` first
+impl Mul<Affine2> for Affine2 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_mat2_translation(
+            self.matrix2 * rhs.matrix2,
+            self.transform_point(rhs.translation),
+        )
+    }
+}
+
+/// Composition assignment of transforms, applying `rhs` first
+impl MulAssign<Affine2> for Affine2 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
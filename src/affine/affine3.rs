@@ -0,0 +1,219 @@
+use core::ops::*;
+
+use crate::angle::Radians;
+use crate::error::Error;
+use crate::mat3::Mat3f;
+use crate::mat4::Mat4f;
+use crate::quat::Quatf;
+use crate::vec3::Vec3f;
+use crate::vec4::Vec4f;
+
+/// A 3D affine transform made of a linear `Mat3f` part followed by a translation
+///
+/// This holds the same transform as a 3D affine 4x4 matrix without storing the constant bottom
+/// row.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Affine3 {
+    /// The linear part of the transform
+    pub matrix3: Mat3f,
+    /// The translation applied after the linear part
+    pub translation: Vec3f,
+}
+
+impl Affine3 {
+    /// The transform that maps every point to the origin
+    pub const ZERO: Self = Self::from_mat3_translation(Mat3f::ZERO, Vec3f::ZERO);
+    /// The identity transform
+    pub const IDENTITY: Self = Self::from_mat3_translation(Mat3f::IDENTITY, Vec3f::ZERO);
+
+    /// Creates a transform from a linear part and a translation
+    pub const fn from_mat3_translation(matrix3: Mat3f, translation: Vec3f) -> Self {
+        Self {
+            matrix3,
+            translation,
+        }
+    }
+
+    /// Creates a transform from a linear part, without a translation
+    pub const fn from_mat3(matrix3: Mat3f) -> Self {
+        Self::from_mat3_translation(matrix3, Vec3f::ZERO)
+    }
+
+    /// Creates a transform that translates by `translation`
+    pub const fn from_translation(translation: Vec3f) -> Self {
+        Self::from_mat3_translation(Mat3f::IDENTITY, translation)
+    }
+
+    /// Creates a transform that rotates by `angle` radians around the normalized `axis`
+    pub fn from_axis_angle(axis: Vec3f, angle: impl Into<Radians<f32>>) -> Self {
+        Self::from_mat3(Mat3f::from_axis_angle(axis, angle))
+    }
+
+    /// Creates a transform that scales along the x, y and z axes by `scale`
+    pub const fn from_scale(scale: Vec3f) -> Self {
+        Self::from_mat3(Mat3f::from_diagonal(scale))
+    }
+
+    /// Creates a transform that scales by `scale`, then applies the pure rotation `rotation` and
+    /// then translates by `translation`
+    pub fn from_scale_rotation_translation(
+        scale: Vec3f,
+        rotation: Mat3f,
+        translation: Vec3f,
+    ) -> Self {
+        Self::from_mat3_translation(Mat3f::from_scale_rotation(scale, rotation), translation)
+    }
+
+    /// Splits the transform into the arguments of [`Affine3::from_scale_rotation_translation`]
+    ///
+    /// A transform with a negative determinant is reported with a negative x scale, so the
+    /// returned rotation never contains a reflection.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Degenerate`] if an axis has zero length and [`Error::Shear`] if the axes
+    /// are not perpendicular.
+    pub fn to_scale_rotation_translation(&self) -> Result<(Vec3f, Mat3f, Vec3f), Error> {
+        let (scale, rotation) = self.matrix3.to_scale_rotation()?;
+        Ok((scale, rotation, self.translation))
+    }
+
+    /// Creates a transform that scales by `scale`, then rotates by the unit quaternion `rotation`
+    /// and then translates by `translation`
+    pub fn from_scale_quat_translation(scale: Vec3f, rotation: Quatf, translation: Vec3f) -> Self {
+        Self::from_scale_rotation_translation(scale, rotation.to_mat3(), translation)
+    }
+
+    /// Splits the transform into the arguments of [`Affine3::from_scale_quat_translation`]
+    ///
+    /// This is [`Affine3::to_scale_rotation_translation`] with the rotation converted to a unit
+    /// quaternion, so a transform with a negative determinant is reported with a negative x scale.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Degenerate`] if an axis has zero length and [`Error::Shear`] if the axes
+    /// are not perpendicular.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::affine::Affine3;
+    /// use yavml::quat::Quatf;
+    /// use yavml::vec3::Vec3f;
+    /// let rotation = Quatf::from_axis_angle(Vec3f::Y, 0.5);
+    /// let affine = Affine3::from_scale_quat_translation(Vec3f::new(1.0, 2.0, 3.0), rotation, Vec3f::ONE);
+    /// let (scale, decomposed, translation) = affine.to_scale_quat_translation().unwrap();
+    /// assert!((scale - Vec3f::new(1.0, 2.0, 3.0)).length() < 1e-5);
+    /// assert!(decomposed.abs_diff_eq(rotation, 1e-5));
+    /// assert_eq!(translation, Vec3f::ONE);
+    /// ```
+    pub fn to_scale_quat_translation(&self) -> Result<(Vec3f, Quatf, Vec3f), Error> {
+        let (scale, rotation, translation) = self.to_scale_rotation_translation()?;
+        Ok((scale, Quatf::from_mat3(&rotation), translation))
+    }
+
+    /// Creates a transform from an affine `Mat4f`, ignoring its bottom row
+    pub const fn from_mat4(mat: Mat4f) -> Self {
+        Self::from_mat3_translation(
+            mat.to_mat3(),
+            Vec3f::new(mat.w_axis.x, mat.w_axis.y, mat.w_axis.z),
+        )
+    }
+
+    /// Returns the transform as an affine `Mat4f`
+    pub const fn to_mat4(&self) -> Mat4f {
+        let m = &self.matrix3;
+        let t = self.translation;
+        Mat4f::from_cols(
+            Vec4f::new(m.x_axis.x, m.x_axis.y, m.x_axis.z, 0.0),
+            Vec4f::new(m.y_axis.x, m.y_axis.y, m.y_axis.z, 0.0),
+            Vec4f::new(m.z_axis.x, m.z_axis.y, m.z_axis.z, 0.0),
+            Vec4f::new(t.x, t.y, t.z, 1.0),
+        )
+    }
+
+    /// Transforms `point`, applying both the linear part and the translation
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::affine::Affine3;
+    /// use yavml::vec3::Vec3f;
+    /// let affine = Affine3::from_translation(Vec3f::new(1.0, 2.0, 3.0));
+    /// assert_eq!(affine.transform_point(Vec3f::ONE), Vec3f::new(2.0, 3.0, 4.0));
+    /// assert_eq!(affine.transform_vector(Vec3f::ONE), Vec3f::ONE);
+    /// ```
+    pub fn transform_point(&self, point: Vec3f) -> Vec3f {
+        self.matrix3 * point + self.translation
+    }
+
+    /// Transforms `vector`, applying only the linear part
+    pub fn transform_vector(&self, vector: Vec3f) -> Vec3f {
+        self.matrix3 * vector
+    }
+
+    /// Returns the inverse transform, or `None` if the linear part is not invertible
+    ///
+    /// Only the 3x3 linear part is inverted, which is cheaper and loses less precision than
+    /// inverting the equivalent 4x4 matrix.
+    pub fn try_inverse(&self) -> Option<Self> {
+        let matrix3 = self.matrix3.try_inverse()?;
+        Some(Self::from_mat3_translation(
+            matrix3,
+            -(matrix3 * self.translation),
+        ))
+    }
+
+    /// Returns the inverse transform
+    ///
+    /// # Panics
+    ///
+    /// Panics if the linear part is not invertible. Use [`Affine3::try_inverse`] when that can happen.
+    pub fn inverse(&self) -> Self {
+        self.try_inverse()
+            .expect("affine transform is not invertible")
+    }
+
+    /// Returns `true` if every element of `self` and `rhs` differs by at most `max_abs_diff`
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.matrix3.abs_diff_eq(rhs.matrix3, max_abs_diff)
+            && (self.translation.x - rhs.translation.x).abs() <= max_abs_diff
+            && (self.translation.y - rhs.translation.y).abs() <= max_abs_diff
+            && (self.translation.z - rhs.translation.z).abs() <= max_abs_diff
+    }
+}
+
+impl Default for Affine3 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Affine3> for Mat4f {
+    fn from(affine: Affine3) -> Self {
+        affine.to_mat4()
+    }
+}
+
+impl From<Mat4f> for Affine3 {
+    fn from(mat: Mat4f) -> Self {
+        Self::from_mat4(mat)
+    }
+}
+
+/// Composition of transforms, applying `rhs` first
+impl Mul<Affine3> for Affine3 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_mat3_translation(
+            self.matrix3 * rhs.matrix3,
+            self.transform_point(rhs.translation),
+        )
+    }
+}
+
+/// Composition assignment of transforms, applying `rhs` first
+impl MulAssign<Affine3> for Affine3 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
@@ -0,0 +1,8 @@
+pub mod affine2;
+pub use affine2::Affine2;
+
+pub mod affine3;
+pub use affine3::Affine3;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,164 @@
+use super::Affine2;
+use super::Affine3;
+use crate::mat2::Mat2f;
+use crate::mat3::Mat3f;
+use crate::quat::Quatf;
+use crate::vec2::Vec2f;
+use crate::vec3::Vec3f;
+
+mod affine2_tests {
+    use super::Affine2;
+    use super::Mat2f;
+    use super::Mat3f;
+    use super::Vec2f;
+    use crate::Error;
+
+    fn sample() -> Affine2 {
+        Affine2::from_scale_angle_translation(Vec2f::new(2.0, 0.5), 0.7, Vec2f::new(3.0, -4.0))
+    }
+
+    // Tests that points are translated but directions are not
+    #[test]
+    fn test_affine2_point_vs_vector() {
+        let affine =
+            Affine2::from_mat2_translation(Mat2f::from_scale(Vec2f::splat(2.0)), Vec2f::ONE);
+        assert_eq!(affine.transform_point(Vec2f::ONE), Vec2f::new(3.0, 3.0));
+        assert_eq!(affine.transform_vector(Vec2f::ONE), Vec2f::new(2.0, 2.0));
+    }
+
+    // Tests that the transform agrees with the equivalent `Mat3f`
+    #[test]
+    fn test_affine2_matches_mat3() {
+        let affine = sample();
+        let matrix: Mat3f = affine.into();
+        let point = Vec2f::new(-1.5, 2.5);
+        assert!((affine.transform_point(point) - matrix.transform_point2(point)).length() < 1e-5);
+        assert!((affine.transform_vector(point) - matrix.transform_vector2(point)).length() < 1e-5);
+        assert_eq!(Affine2::from(matrix), affine);
+    }
+
+    // Tests that composition matches matrix multiplication
+    #[test]
+    fn test_affine2_composition() {
+        let a = sample();
+        let b = Affine2::from_translation(Vec2f::new(1.0, 1.0)) * Affine2::from_angle(-0.3);
+        assert!((a * b)
+            .to_mat3()
+            .abs_diff_eq(a.to_mat3() * b.to_mat3(), 1e-5));
+        let mut c = a;
+        c *= b;
+        assert_eq!(c, a * b);
+    }
+
+    // Tests that a transform composed with its inverse is the identity
+    #[test]
+    fn test_affine2_inverse() {
+        let affine = sample();
+        assert!((affine * affine.inverse()).abs_diff_eq(Affine2::IDENTITY, 1e-5));
+        assert!((affine.inverse() * affine).abs_diff_eq(Affine2::IDENTITY, 1e-5));
+        assert!(affine
+            .inverse()
+            .to_mat3()
+            .abs_diff_eq(affine.to_mat3().inverse(), 1e-5));
+    }
+
+    // Tests that degenerate transforms have no inverse
+    #[test]
+    fn test_affine2_try_inverse_degenerate() {
+        assert_eq!(
+            Affine2::from_scale(Vec2f::new(1.0, 0.0)).try_inverse(),
+            None
+        );
+        assert_eq!(Affine2::ZERO.try_inverse(), None);
+    }
+
+    // Tests that decomposing and recomposing a transform round-trips
+    #[test]
+    fn test_affine2_scale_angle_translation_round_trip() {
+        let affine = sample();
+        let (scale, angle, translation) = affine.to_scale_angle_translation().unwrap();
+        let recomposed = Affine2::from_scale_angle_translation(scale, angle, translation);
+        assert!(recomposed.abs_diff_eq(affine, 1e-5));
+        let shear = Affine2::from_mat2(Mat2f::from_cols_array([1.0, 0.0, 1.0, 1.0]));
+        assert_eq!(shear.to_scale_angle_translation(), Err(Error::Shear));
+    }
+}
+
+mod affine3_tests {
+    use super::Affine3;
+    use super::Mat3f;
+    use super::Quatf;
+    use super::Vec3f;
+    use crate::Error;
+
+    fn sample() -> Affine3 {
+        let axis = Vec3f::new(2.0, -1.0, 2.0) / 3.0;
+        Affine3::from_translation(Vec3f::new(1.0, 2.0, 3.0))
+            * Affine3::from_axis_angle(axis, 1.3)
+            * Affine3::from_scale(Vec3f::new(2.0, 3.0, 0.5))
+    }
+
+    // Tests that points are translated but directions are not
+    #[test]
+    fn test_affine3_point_vs_vector() {
+        let affine =
+            Affine3::from_mat3_translation(Mat3f::from_diagonal(Vec3f::splat(2.0)), Vec3f::ONE);
+        assert_eq!(affine.transform_point(Vec3f::ONE), Vec3f::splat(3.0));
+        assert_eq!(affine.transform_vector(Vec3f::ONE), Vec3f::splat(2.0));
+    }
+
+    // Tests that composition applies the right-hand transform first
+    #[test]
+    fn test_affine3_composition() {
+        let a = sample();
+        let b = Affine3::from_axis_angle(Vec3f::Y, 0.4);
+        let point = Vec3f::new(0.5, -1.0, 4.0);
+        let composed = (a * b).transform_point(point);
+        assert!((composed - a.transform_point(b.transform_point(point))).length() < 1e-5);
+    }
+
+    // Tests that a transform composed with its inverse is the identity
+    #[test]
+    fn test_affine3_inverse() {
+        let affine = sample();
+        assert!((affine * affine.inverse()).abs_diff_eq(Affine3::IDENTITY, 1e-5));
+        let point = Vec3f::new(7.0, -3.0, 0.25);
+        let round_trip = affine
+            .inverse()
+            .transform_point(affine.transform_point(point));
+        assert!((round_trip - point).length() < 1e-5);
+        assert_eq!(
+            Affine3::from_scale(Vec3f::new(1.0, 1.0, 0.0)).try_inverse(),
+            None
+        );
+    }
+
+    // Tests that decomposing and recomposing a transform round-trips
+    #[test]
+    fn test_affine3_scale_rotation_translation_round_trip() {
+        let affine = sample();
+        let (scale, rotation, translation) = affine.to_scale_rotation_translation().unwrap();
+        assert!((scale - Vec3f::new(2.0, 3.0, 0.5)).length() < 1e-5);
+        let recomposed = Affine3::from_scale_rotation_translation(scale, rotation, translation);
+        assert!(recomposed.abs_diff_eq(affine, 1e-5));
+        assert_eq!(
+            Affine3::ZERO.to_scale_rotation_translation(),
+            Err(Error::Degenerate)
+        );
+    }
+
+    // Tests that decomposing into a quaternion and recomposing round-trips
+    #[test]
+    fn test_affine3_scale_quat_translation_round_trip() {
+        let affine = sample();
+        let (scale, rotation, translation) = affine.to_scale_quat_translation().unwrap();
+        let axis = Vec3f::new(2.0, -1.0, 2.0) / 3.0;
+        assert!(rotation.abs_diff_eq(Quatf::from_axis_angle(axis, 1.3), 1e-5));
+        let recomposed = Affine3::from_scale_quat_translation(scale, rotation, translation);
+        assert!(recomposed.abs_diff_eq(affine, 1e-5));
+        assert_eq!(
+            Affine3::ZERO.to_scale_quat_translation(),
+            Err(Error::Degenerate)
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use core::ops::*;
+
+use super::radians::{wrap_signed, wrap_unsigned};
+use super::Radians;
+use crate::scalar::Float;
+
+/// An angle measured in degrees
+///
+/// Degrees convert into [`Radians`], so they can be passed to every function in the crate that
+/// takes an angle.
+///
+/// # Examples:
+/// ```
+/// use yavml::angle::{Degrees, Radians};
+/// let radians: Radians<f64> = Degrees(180.0).into();
+/// assert_eq!(radians, Radians(core::f64::consts::PI));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Degrees<T>(pub T);
+
+impl<T: Float> Degrees<T> {
+    /// An angle of zero
+    pub const ZERO: Self = Self(T::ZERO);
+
+    /// Returns the angle converted to radians
+    pub fn to_radians(self) -> Radians<T> {
+        Radians(self.0.to_radians())
+    }
+
+    /// Returns the sine and cosine of the angle
+    pub fn sin_cos(self) -> (T, T) {
+        self.0.to_radians().sin_cos()
+    }
+
+    /// Returns the same angle wrapped into `[-180, 180)`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::angle::Degrees;
+    /// assert_eq!(Degrees(270.0_f32).wrap_pi(), Degrees(-90.0));
+    /// assert_eq!(Degrees(-180.0_f32).wrap_pi(), Degrees(-180.0));
+    /// ```
+    pub fn wrap_pi(self) -> Self {
+        Self(wrap_signed(self.0, half_turn()))
+    }
+
+    /// Returns the same angle wrapped into `[0, 360)`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::angle::Degrees;
+    /// assert_eq!(Degrees(-90.0_f32).wrap_2pi(), Degrees(270.0));
+    /// ```
+    pub fn wrap_2pi(self) -> Self {
+        Self(wrap_unsigned(self.0, half_turn::<T>() + half_turn()))
+    }
+
+    /// Returns the signed angle in `[-180, 180)` that turns `self` to `to` the short way around
+    pub fn shortest_delta(self, to: Self) -> Self {
+        (to - self).wrap_pi()
+    }
+
+    /// Interpolates from `self` towards `end` the short way around
+    ///
+    /// The result is `self + self.shortest_delta(end) * t`, which is not wrapped.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::angle::Degrees;
+    /// assert_eq!(Degrees(350.0_f32).lerp(Degrees(30.0), 0.25), Degrees(360.0));
+    /// ```
+    pub fn lerp(self, end: Self, t: T) -> Self {
+        self + self.shortest_delta(end) * t
+    }
+}
+
+impl<T: Float> From<Radians<T>> for Degrees<T> {
+    fn from(radians: Radians<T>) -> Self {
+        radians.to_degrees()
+    }
+}
+
+/// Addition of angles
+impl<T: Float> Add<Degrees<T>> for Degrees<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+/// Addition assignment of angles
+impl<T: Float> AddAssign<Degrees<T>> for Degrees<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+/// Subtraction of angles
+impl<T: Float> Sub<Degrees<T>> for Degrees<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+/// Subtraction assignment of angles
+impl<T: Float> SubAssign<Degrees<T>> for Degrees<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+/// Negation of an angle
+impl<T: Float> Neg for Degrees<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+/// Multiplication of an angle by a scalar
+impl<T: Float> Mul<T> for Degrees<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+/// Multiplication assignment of an angle by a scalar
+impl<T: Float> MulAssign<T> for Degrees<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.0 *= rhs;
+    }
+}
+
+/// Division of an angle by a scalar
+impl<T: Float> Div<T> for Degrees<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+
+/// Division assignment of an angle by a scalar
+impl<T: Float> DivAssign<T> for Degrees<T> {
+    fn div_assign(&mut self, rhs: T) {
+        self.0 /= rhs;
+    }
+}
+
+/// Ratio of two angles
+impl<T: Float> Div<Degrees<T>> for Degrees<T> {
+    type Output = T;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.0 / rhs.0
+    }
+}
+
+/// Returns half a turn in degrees
+fn half_turn<T: Float>() -> T {
+    T::from_usize(180)
+}
@@ -0,0 +1,8 @@
+pub mod radians;
+pub use radians::Radians;
+
+pub mod degrees;
+pub use degrees::Degrees;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,193 @@
+use core::ops::*;
+
+use super::Degrees;
+use crate::scalar::Float;
+
+/// An angle measured in radians
+///
+/// Functions throughout the crate that take an angle accept any `impl Into<Radians<f32>>` (or
+/// `f64`), so a bare number is still read as radians while a [`Degrees`] is converted first.
+///
+/// # Examples:
+/// ```
+/// use yavml::angle::{Degrees, Radians};
+/// use yavml::mat2::Mat2f;
+/// let quarter = Mat2f::from_angle(Degrees(90.0_f32));
+/// assert!(quarter.abs_diff_eq(Mat2f::from_angle(Radians(core::f32::consts::FRAC_PI_2)), 1e-6));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Radians<T>(pub T);
+
+impl<T: Float> Radians<T> {
+    /// An angle of zero
+    pub const ZERO: Self = Self(T::ZERO);
+    /// Half a turn
+    pub const PI: Self = Self(T::PI);
+
+    /// Returns the angle converted to degrees
+    pub fn to_degrees(self) -> Degrees<T> {
+        Degrees(self.0.to_degrees())
+    }
+
+    /// Returns the sine and cosine of the angle
+    pub fn sin_cos(self) -> (T, T) {
+        self.0.sin_cos()
+    }
+
+    /// Returns the same angle wrapped into `[-PI, PI)`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::angle::Radians;
+    /// use core::f32::consts::PI;
+    /// assert!((Radians(1.5 * PI).wrap_pi().0 + 0.5 * PI).abs() < 1e-6);
+    /// assert_eq!(Radians(PI).wrap_pi(), Radians(-PI));
+    /// ```
+    pub fn wrap_pi(self) -> Self {
+        Self(wrap_signed(self.0, T::PI))
+    }
+
+    /// Returns the same angle wrapped into `[0, 2 * PI)`
+    pub fn wrap_2pi(self) -> Self {
+        Self(wrap_unsigned(self.0, T::PI + T::PI))
+    }
+
+    /// Returns the signed angle in `[-PI, PI)` that turns `self` to `to` the short way around
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::angle::Radians;
+    /// use core::f32::consts::PI;
+    /// let delta = Radians(PI - 0.1).shortest_delta(Radians(-PI + 0.1));
+    /// assert!((delta.0 - 0.2).abs() < 1e-5);
+    /// ```
+    pub fn shortest_delta(self, to: Self) -> Self {
+        (to - self).wrap_pi()
+    }
+
+    /// Interpolates from `self` towards `end` the short way around
+    ///
+    /// The result is `self + self.shortest_delta(end) * t`, which is not wrapped, so `t = 0`
+    /// returns `self` exactly.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::angle::Radians;
+    /// use core::f32::consts::PI;
+    /// let half = Radians(PI - 0.1).lerp(Radians(-PI + 0.1), 0.5);
+    /// assert!((half.0 - PI).abs() < 1e-5);
+    /// ```
+    pub fn lerp(self, end: Self, t: T) -> Self {
+        self + self.shortest_delta(end) * t
+    }
+}
+
+impl<T: Float> From<T> for Radians<T> {
+    fn from(radians: T) -> Self {
+        Self(radians)
+    }
+}
+
+impl<T: Float> From<Degrees<T>> for Radians<T> {
+    fn from(degrees: Degrees<T>) -> Self {
+        degrees.to_radians()
+    }
+}
+
+/// Addition of angles
+impl<T: Float> Add<Radians<T>> for Radians<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+/// Addition assignment of angles
+impl<T: Float> AddAssign<Radians<T>> for Radians<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+/// Subtraction of angles
+impl<T: Float> Sub<Radians<T>> for Radians<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+/// Subtraction assignment of angles
+impl<T: Float> SubAssign<Radians<T>> for Radians<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+/// Negation of an angle
+impl<T: Float> Neg for Radians<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+/// Multiplication of an angle by a scalar
+impl<T: Float> Mul<T> for Radians<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+/// Multiplication assignment of an angle by a scalar
+impl<T: Float> MulAssign<T> for Radians<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.0 *= rhs;
+    }
+}
+
+/// Division of an angle by a scalar
+impl<T: Float> Div<T> for Radians<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+
+/// Division assignment of an angle by a scalar
+impl<T: Float> DivAssign<T> for Radians<T> {
+    fn div_assign(&mut self, rhs: T) {
+        self.0 /= rhs;
+    }
+}
+
+/// Ratio of two angles
+impl<T: Float> Div<Radians<T>> for Radians<T> {
+    type Output = T;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.0 / rhs.0
+    }
+}
+
+/// Returns `value` wrapped into `[0, full_turn)`
+pub(super) fn wrap_unsigned<T: Float>(value: T, full_turn: T) -> T {
+    let wrapped = value.rem_euclid(full_turn);
+    // Tiny negative values round up to exactly `full_turn`
+    if wrapped >= full_turn {
+        T::ZERO
+    } else {
+        wrapped
+    }
+}
+
+/// Returns `value` wrapped into `[-half_turn, half_turn)`
+pub(super) fn wrap_signed<T: Float>(value: T, half_turn: T) -> T {
+    wrap_unsigned(value + half_turn, half_turn + half_turn) - half_turn
+}
@@ -0,0 +1,113 @@
+use super::Degrees;
+use super::Radians;
+
+mod radians_tests {
+    use super::Degrees;
+    use super::Radians;
+    use core::f32::consts::{FRAC_PI_2, PI, TAU};
+
+    // Tests conversion to degrees and back
+    #[test]
+    fn test_radians_conversion() {
+        assert_eq!(Radians(PI).to_degrees(), Degrees(180.0));
+        assert_eq!(Degrees::from(Radians(FRAC_PI_2)), Degrees(90.0));
+        assert_eq!(Radians::from(0.25_f32), Radians(0.25));
+        for i in -10..=10 {
+            let angle = Radians(i as f32 * 0.37);
+            assert!((angle.to_degrees().to_radians() - angle).0.abs() < 1e-6);
+        }
+    }
+
+    // Tests the arithmetic operators
+    #[test]
+    fn test_radians_arithmetic() {
+        let mut angle = Radians(1.0_f32) + Radians(0.5) - Radians(0.25);
+        assert_eq!(angle, Radians(1.25));
+        assert_eq!(-angle, Radians(-1.25));
+        assert_eq!(angle * 2.0, Radians(2.5));
+        assert_eq!(angle / 5.0, Radians(0.25));
+        assert_eq!(angle / Radians(0.25), 5.0);
+        angle += Radians(0.75);
+        angle -= Radians(1.0);
+        angle *= 3.0;
+        angle /= 2.0;
+        assert_eq!(angle, Radians(1.5));
+        assert!(Radians(0.1) < Radians(0.2_f32));
+    }
+
+    // Tests wrapping into both ranges, including values on the boundaries
+    #[test]
+    fn test_radians_wrap() {
+        assert!((Radians(3.0 * PI).wrap_pi().0 + PI).abs() < 1e-5);
+        assert!((Radians(-1.5 * PI).wrap_pi().0 - 0.5 * PI).abs() < 1e-5);
+        assert_eq!(Radians(0.5_f32).wrap_pi(), Radians(0.5));
+        assert_eq!(Radians(-PI).wrap_pi(), Radians(-PI));
+        assert!((Radians(-0.5_f32).wrap_2pi().0 - (TAU - 0.5)).abs() < 1e-6);
+        assert_eq!(Radians(TAU).wrap_2pi(), Radians(0.0));
+        assert_eq!(Radians(-1e-9_f32).wrap_2pi(), Radians(0.0));
+        for i in -50..=50 {
+            let wrapped = Radians(i as f32 * 0.9).wrap_pi();
+            assert!(wrapped.0 >= -PI && wrapped.0 < PI);
+            let wrapped = Radians(i as f32 * 0.9).wrap_2pi();
+            assert!(wrapped.0 >= 0.0 && wrapped.0 < TAU);
+        }
+    }
+
+    // Tests that deltas and interpolation take the short way around
+    #[test]
+    fn test_radians_shortest_delta_lerp() {
+        let from = Radians(0.3_f32);
+        let to = Radians(TAU - 0.3);
+        assert!((from.shortest_delta(to).0 + 0.6).abs() < 1e-5);
+        assert!((to.shortest_delta(from).0 - 0.6).abs() < 1e-5);
+        assert_eq!(from.lerp(to, 0.0), from);
+        assert!((from.lerp(to, 0.5).0).abs() < 1e-5);
+        assert!((from.lerp(to, 1.0).wrap_2pi() - to).0.abs() < 1e-5);
+        assert!((Radians(1.0_f32).lerp(Radians(2.0), 0.25).0 - 1.25).abs() < 1e-6);
+    }
+}
+
+mod degrees_tests {
+    use super::Degrees;
+    use super::Radians;
+    use crate::mat2::Mat2f;
+    use crate::mat3::Mat3d;
+    use crate::mat4::Mat4f;
+    use crate::quat::Quatf;
+    use crate::rot2::Rot2f;
+    use crate::vec3::Vec3f;
+    use core::f64::consts::FRAC_PI_2;
+
+    // Tests wrapping, deltas and interpolation in degrees
+    #[test]
+    fn test_degrees_wrap() {
+        assert_eq!(Degrees(540.0_f64).wrap_pi(), Degrees(-180.0));
+        assert_eq!(Degrees(-450.0_f64).wrap_2pi(), Degrees(270.0));
+        assert_eq!(Degrees(720.0_f64).wrap_2pi(), Degrees(0.0));
+        assert_eq!(
+            Degrees(10.0_f64).shortest_delta(Degrees(350.0)),
+            Degrees(-20.0)
+        );
+        assert_eq!(
+            Degrees(170.0_f64).lerp(Degrees(-170.0), 0.5),
+            Degrees(180.0)
+        );
+        assert_eq!(Degrees(90.0_f64).to_radians(), Radians(FRAC_PI_2));
+        assert_eq!(Degrees(30.0_f64) * 3.0 / Degrees(45.0), 2.0);
+    }
+
+    // Tests that angular constructors accept degrees, radians and bare radians alike
+    #[test]
+    fn test_degrees_angular_apis() {
+        let quarter = core::f32::consts::FRAC_PI_2;
+        assert!(Mat2f::from_angle(Degrees(90.0)).abs_diff_eq(Mat2f::from_angle(quarter), 1e-6));
+        assert!(Mat3d::from_rotation_x(Degrees(90.0))
+            .abs_diff_eq(Mat3d::from_rotation_x(Radians(FRAC_PI_2)), 1e-12));
+        let axis = Vec3f::new(0.0, 0.6, 0.8);
+        assert!(Quatf::from_axis_angle(axis, Degrees(90.0))
+            .abs_diff_eq(Quatf::from_axis_angle(axis, quarter), 1e-6));
+        assert!(Rot2f::from_angle(Degrees(-90.0)).abs_diff_eq(Rot2f::from_angle(-quarter), 1e-6));
+        assert!(Mat4f::perspective_rh(Degrees(90.0), 1.5, 0.1, 100.0)
+            .abs_diff_eq(Mat4f::perspective_rh(quarter, 1.5, 0.1, 100.0), 1e-6));
+    }
+}
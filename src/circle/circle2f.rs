@@ -0,0 +1,139 @@
+use crate::rect::Rect2f;
+use crate::vec2::Vec2f;
+
+/// A solid circle of `f32` values
+///
+/// Points on the edge of the circle are inside it, and circles that only touch still intersect.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Circle {
+    /// The center of the circle
+    pub center: Vec2f,
+    /// The radius of the circle, which should not be negative
+    pub radius: f32,
+}
+
+impl Circle {
+    /// Creates a new circle
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - A `Vec2f` holding the center of the circle
+    ///
+    /// * `radius` - The radius of the circle, which should not be negative
+    pub const fn new(center: Vec2f, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    /// Creates a circle containing every point of `points` using Ritter's algorithm, or `None`
+    /// if `points` is empty
+    ///
+    /// The result is found in a few linear passes and is usually within a few percent of the
+    /// smallest enclosing circle, but it is not guaranteed to be minimal.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::circle::Circle;
+    /// use yavml::vec2::Vec2f;
+    /// let points = [Vec2f::new(-2.0, 0.0), Vec2f::new(2.0, 0.0), Vec2f::new(0.0, 1.0)];
+    /// let circle = Circle::from_points(&points).unwrap();
+    /// assert_eq!(circle, Circle::new(Vec2f::ZERO, 2.0));
+    /// ```
+    pub fn from_points(points: &[Vec2f]) -> Option<Self> {
+        let first = *points.first()?;
+        let farthest_from = |from: Vec2f| {
+            points.iter().copied().fold(from, |best, point| {
+                let offset = point - from;
+                let best_offset = best - from;
+                if offset.dot(offset) > best_offset.dot(best_offset) {
+                    point
+                } else {
+                    best
+                }
+            })
+        };
+        let a = farthest_from(first);
+        let b = farthest_from(a);
+        let diameter = b - a;
+        let mut circle = Self::new((a + b) * 0.5, diameter.dot(diameter).sqrt() * 0.5);
+        for &point in points {
+            let offset = point - circle.center;
+            let distance = offset.dot(offset).sqrt();
+            if distance > circle.radius {
+                let radius = (circle.radius + distance) * 0.5;
+                circle.center += offset * ((radius - circle.radius) / distance);
+                circle.radius = radius;
+            }
+        }
+        Some(circle)
+    }
+
+    /// Returns `true` if `point` is inside the circle or on its edge
+    pub fn contains_point(&self, point: Vec2f) -> bool {
+        let offset = point - self.center;
+        offset.dot(offset) <= self.radius * self.radius
+    }
+
+    /// Returns `true` if `self` and `circle` share at least one point
+    pub fn intersects_circle(&self, circle: &Self) -> bool {
+        let offset = circle.center - self.center;
+        let radii = self.radius + circle.radius;
+        offset.dot(offset) <= radii * radii
+    }
+
+    /// Returns `true` if the circle and the non-empty `rect` share at least one point
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::circle::Circle;
+    /// use yavml::rect::Rect2f;
+    /// use yavml::vec2::Vec2f;
+    /// let rect = Rect2f::new(Vec2f::ZERO, Vec2f::ONE);
+    /// assert!(Circle::new(Vec2f::new(1.5, 0.5), 0.5).intersects_rect(&rect));
+    /// assert!(!Circle::new(Vec2f::new(1.5, 1.5), 0.5).intersects_rect(&rect));
+    /// ```
+    pub fn intersects_rect(&self, rect: &Rect2f) -> bool {
+        self.contains_point(rect.clamp_point(self.center))
+    }
+
+    /// Returns the point of the solid circle closest to `point`, which is `point` itself when
+    /// it is inside
+    pub fn closest_point(&self, point: Vec2f) -> Vec2f {
+        let offset = point - self.center;
+        let distance_squared = offset.dot(offset);
+        if distance_squared <= self.radius * self.radius {
+            return point;
+        }
+        self.center + offset * (self.radius / distance_squared.sqrt())
+    }
+
+    /// Returns the smallest rectangle containing the circle
+    pub fn bounding_rect(&self) -> Rect2f {
+        Rect2f::from_center_size(self.center, Vec2f::splat(self.radius * 2.0))
+    }
+
+    /// Returns the smallest circle containing both `self` and `circle`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::circle::Circle;
+    /// use yavml::vec2::Vec2f;
+    /// let a = Circle::new(Vec2f::new(-2.0, 0.0), 1.0);
+    /// let b = Circle::new(Vec2f::new(3.0, 0.0), 2.0);
+    /// assert_eq!(a.merge(&b), Circle::new(Vec2f::new(1.0, 0.0), 4.0));
+    /// ```
+    pub fn merge(&self, circle: &Self) -> Self {
+        let offset = circle.center - self.center;
+        let distance = offset.dot(offset).sqrt();
+        if distance + circle.radius <= self.radius {
+            return *self;
+        }
+        if distance + self.radius <= circle.radius {
+            return *circle;
+        }
+        let radius = (distance + self.radius + circle.radius) * 0.5;
+        Self::new(
+            self.center + offset * ((radius - self.radius) / distance),
+            radius,
+        )
+    }
+}
@@ -0,0 +1,8 @@
+pub mod circle2f;
+pub use circle2f::Circle;
+
+pub mod sphere3f;
+pub use sphere3f::Sphere;
+
+#[cfg(test)]
+mod tests;
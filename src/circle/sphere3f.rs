@@ -0,0 +1,143 @@
+use crate::aabb::Aabb3f;
+use crate::vec3::Vec3f;
+
+/// A solid sphere of `f32` values
+///
+/// Points on the surface of the sphere are inside it, and spheres that only touch still intersect.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Sphere {
+    /// The center of the sphere
+    pub center: Vec3f,
+    /// The radius of the sphere, which should not be negative
+    pub radius: f32,
+}
+
+impl Sphere {
+    /// Creates a new sphere
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - A `Vec3f` holding the center of the sphere
+    ///
+    /// * `radius` - The radius of the sphere, which should not be negative
+    pub const fn new(center: Vec3f, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    /// Creates a sphere containing every point of `points` using Ritter's algorithm, or `None`
+    /// if `points` is empty
+    ///
+    /// The result is found in a few linear passes and is usually within a few percent of the
+    /// smallest enclosing sphere, but it is not guaranteed to be minimal.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::circle::Sphere;
+    /// use yavml::vec3::Vec3f;
+    /// let points = [
+    ///     Vec3f::new(0.0, 0.0, -2.0),
+    ///     Vec3f::new(0.0, 0.0, 2.0),
+    ///     Vec3f::new(1.0, 1.0, 0.0),
+    /// ];
+    /// let sphere = Sphere::from_points(&points).unwrap();
+    /// assert_eq!(sphere, Sphere::new(Vec3f::ZERO, 2.0));
+    /// ```
+    pub fn from_points(points: &[Vec3f]) -> Option<Self> {
+        let first = *points.first()?;
+        let farthest_from = |from: Vec3f| {
+            points.iter().copied().fold(from, |best, point| {
+                let offset = point - from;
+                let best_offset = best - from;
+                if offset.dot(offset) > best_offset.dot(best_offset) {
+                    point
+                } else {
+                    best
+                }
+            })
+        };
+        let a = farthest_from(first);
+        let b = farthest_from(a);
+        let diameter = b - a;
+        let mut sphere = Self::new((a + b) * 0.5, diameter.dot(diameter).sqrt() * 0.5);
+        for &point in points {
+            let offset = point - sphere.center;
+            let distance = offset.dot(offset).sqrt();
+            if distance > sphere.radius {
+                let radius = (sphere.radius + distance) * 0.5;
+                sphere.center += offset * ((radius - sphere.radius) / distance);
+                sphere.radius = radius;
+            }
+        }
+        Some(sphere)
+    }
+
+    /// Returns `true` if `point` is inside the sphere or on its surface
+    pub fn contains_point(&self, point: Vec3f) -> bool {
+        let offset = point - self.center;
+        offset.dot(offset) <= self.radius * self.radius
+    }
+
+    /// Returns `true` if `self` and `sphere` share at least one point
+    pub fn intersects_sphere(&self, sphere: &Self) -> bool {
+        let offset = sphere.center - self.center;
+        let radii = self.radius + sphere.radius;
+        offset.dot(offset) <= radii * radii
+    }
+
+    /// Returns `true` if the sphere and the non-empty `aabb` share at least one point
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::aabb::Aabb3f;
+    /// use yavml::circle::Sphere;
+    /// use yavml::vec3::Vec3f;
+    /// let aabb = Aabb3f::new(Vec3f::ZERO, Vec3f::ONE);
+    /// assert!(Sphere::new(Vec3f::new(1.5, 0.5, 0.5), 0.5).intersects_aabb(&aabb));
+    /// assert!(!Sphere::new(Vec3f::new(1.5, 1.5, 1.5), 0.5).intersects_aabb(&aabb));
+    /// ```
+    pub fn intersects_aabb(&self, aabb: &Aabb3f) -> bool {
+        self.contains_point(aabb.closest_point(self.center))
+    }
+
+    /// Returns the point of the solid sphere closest to `point`, which is `point` itself when
+    /// it is inside
+    pub fn closest_point(&self, point: Vec3f) -> Vec3f {
+        let offset = point - self.center;
+        let distance_squared = offset.dot(offset);
+        if distance_squared <= self.radius * self.radius {
+            return point;
+        }
+        self.center + offset * (self.radius / distance_squared.sqrt())
+    }
+
+    /// Returns the smallest box containing the sphere
+    pub fn bounding_aabb(&self) -> Aabb3f {
+        Aabb3f::from_center_size(self.center, Vec3f::splat(self.radius * 2.0))
+    }
+
+    /// Returns the smallest sphere containing both `self` and `sphere`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::circle::Sphere;
+    /// use yavml::vec3::Vec3f;
+    /// let a = Sphere::new(Vec3f::new(-2.0, 0.0, 0.0), 1.0);
+    /// let b = Sphere::new(Vec3f::new(3.0, 0.0, 0.0), 2.0);
+    /// assert_eq!(a.merge(&b), Sphere::new(Vec3f::new(1.0, 0.0, 0.0), 4.0));
+    /// ```
+    pub fn merge(&self, sphere: &Self) -> Self {
+        let offset = sphere.center - self.center;
+        let distance = offset.dot(offset).sqrt();
+        if distance + sphere.radius <= self.radius {
+            return *self;
+        }
+        if distance + self.radius <= sphere.radius {
+            return *sphere;
+        }
+        let radius = (distance + self.radius + sphere.radius) * 0.5;
+        Self::new(
+            self.center + offset * ((radius - self.radius) / distance),
+            radius,
+        )
+    }
+}
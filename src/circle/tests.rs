@@ -0,0 +1,154 @@
+use super::Circle;
+use super::Sphere;
+use crate::aabb::Aabb3f;
+use crate::rect::Rect2f;
+use crate::vec2::Vec2f;
+use crate::vec3::Vec3f;
+
+mod circle_tests {
+    use super::Circle;
+    use super::Rect2f;
+    use super::Vec2f;
+
+    // Tests that points on the edge are contained and touching circles intersect
+    #[test]
+    fn test_circle_contains_and_intersects() {
+        let circle = Circle::new(Vec2f::new(1.0, 1.0), 2.0);
+        assert!(circle.contains_point(Vec2f::new(1.0, 1.0)));
+        assert!(circle.contains_point(Vec2f::new(3.0, 1.0)));
+        assert!(!circle.contains_point(Vec2f::new(2.5, 2.5)));
+        assert!(circle.intersects_circle(&Circle::new(Vec2f::new(4.0, 1.0), 1.0)));
+        assert!(!circle.intersects_circle(&Circle::new(Vec2f::new(4.5, 1.0), 1.0)));
+    }
+
+    // Tests rectangle intersection near the corners, where the bounding rect overlaps but the
+    // circle does not
+    #[test]
+    fn test_circle_intersects_rect() {
+        let rect = Rect2f::new(Vec2f::ZERO, Vec2f::new(2.0, 2.0));
+        assert!(Circle::new(Vec2f::new(1.0, 1.0), 0.1).intersects_rect(&rect));
+        assert!(Circle::new(Vec2f::new(3.0, 1.0), 1.0).intersects_rect(&rect));
+        assert!(!Circle::new(Vec2f::new(2.8, 2.8), 1.0).intersects_rect(&rect));
+        assert!(Circle::new(Vec2f::new(2.6, 2.6), 1.0).intersects_rect(&rect));
+    }
+
+    // Tests the closest point for points inside and outside the circle
+    #[test]
+    fn test_circle_closest_point_and_bounds() {
+        let circle = Circle::new(Vec2f::new(1.0, 0.0), 2.0);
+        assert_eq!(
+            circle.closest_point(Vec2f::new(1.5, 0.5)),
+            Vec2f::new(1.5, 0.5)
+        );
+        assert_eq!(
+            circle.closest_point(Vec2f::new(1.0, -5.0)),
+            Vec2f::new(1.0, -2.0)
+        );
+        assert_eq!(
+            circle.bounding_rect(),
+            Rect2f::new(Vec2f::new(-1.0, -2.0), Vec2f::new(3.0, 2.0))
+        );
+    }
+
+    // Tests merging disjoint, overlapping and nested circles
+    #[test]
+    fn test_circle_merge() {
+        let big = Circle::new(Vec2f::ZERO, 5.0);
+        let small = Circle::new(Vec2f::new(1.0, 1.0), 1.0);
+        assert_eq!(big.merge(&small), big);
+        assert_eq!(small.merge(&big), big);
+        let a = Circle::new(Vec2f::new(0.0, -3.0), 1.0);
+        let b = Circle::new(Vec2f::new(0.0, 3.0), 1.0);
+        let merged = a.merge(&b);
+        assert_eq!(merged, Circle::new(Vec2f::ZERO, 4.0));
+        assert_eq!(a.merge(&a), a);
+    }
+
+    // Tests that Ritter's circle contains every input point and is reasonably tight
+    #[test]
+    fn test_circle_from_points() {
+        assert_eq!(Circle::from_points(&[]), None);
+        assert_eq!(
+            Circle::from_points(&[Vec2f::new(3.0, 4.0)]),
+            Some(Circle::new(Vec2f::new(3.0, 4.0), 0.0))
+        );
+        let points: Vec<Vec2f> = (0..64)
+            .map(|i| {
+                let angle = i as f32 * 0.7;
+                let radius = 1.0 + (i % 7) as f32 * 0.4;
+                Vec2f::new(angle.cos() * radius + 2.0, angle.sin() * radius - 1.0)
+            })
+            .collect();
+        let circle = Circle::from_points(&points).unwrap();
+        let grown = Circle::new(circle.center, circle.radius * (1.0 + 1e-5));
+        assert!(points.iter().all(|&p| grown.contains_point(p)));
+        assert!(circle.radius < 3.4 * 1.2);
+    }
+}
+
+mod sphere_tests {
+    use super::Aabb3f;
+    use super::Sphere;
+    use super::Vec3f;
+
+    // Tests that points on the surface are contained and touching spheres intersect
+    #[test]
+    fn test_sphere_contains_and_intersects() {
+        let sphere = Sphere::new(Vec3f::new(1.0, 1.0, 1.0), 2.0);
+        assert!(sphere.contains_point(Vec3f::new(1.0, 1.0, 3.0)));
+        assert!(!sphere.contains_point(Vec3f::new(2.5, 2.5, 1.0)));
+        assert!(sphere.intersects_sphere(&Sphere::new(Vec3f::new(1.0, 4.0, 1.0), 1.0)));
+        assert!(!sphere.intersects_sphere(&Sphere::new(Vec3f::new(1.0, 4.5, 1.0), 1.0)));
+    }
+
+    // Tests box intersection near a corner, where the bounding box overlaps but the sphere does
+    // not
+    #[test]
+    fn test_sphere_intersects_aabb() {
+        let aabb = Aabb3f::new(Vec3f::ZERO, Vec3f::ONE);
+        assert!(Sphere::new(Vec3f::splat(0.5), 0.1).intersects_aabb(&aabb));
+        assert!(Sphere::new(Vec3f::new(0.5, 0.5, 2.0), 1.0).intersects_aabb(&aabb));
+        assert!(!Sphere::new(Vec3f::splat(1.6), 1.0).intersects_aabb(&aabb));
+        assert!(Sphere::new(Vec3f::splat(1.5), 1.0).intersects_aabb(&aabb));
+    }
+
+    // Tests the closest point, bounding box and merging
+    #[test]
+    fn test_sphere_closest_point_bounds_and_merge() {
+        let sphere = Sphere::new(Vec3f::ZERO, 2.0);
+        assert_eq!(
+            sphere.closest_point(Vec3f::new(0.0, 0.0, 8.0)),
+            Vec3f::new(0.0, 0.0, 2.0)
+        );
+        assert_eq!(sphere.closest_point(Vec3f::ONE), Vec3f::ONE);
+        assert_eq!(
+            sphere.bounding_aabb(),
+            Aabb3f::new(Vec3f::splat(-2.0), Vec3f::splat(2.0))
+        );
+        let other = Sphere::new(Vec3f::new(6.0, 0.0, 0.0), 2.0);
+        assert_eq!(
+            sphere.merge(&other),
+            Sphere::new(Vec3f::new(3.0, 0.0, 0.0), 5.0)
+        );
+    }
+
+    // Tests that Ritter's sphere contains every input point
+    #[test]
+    fn test_sphere_from_points() {
+        assert_eq!(Sphere::from_points(&[]), None);
+        let points: Vec<Vec3f> = (0..64)
+            .map(|i| {
+                let t = i as f32;
+                Vec3f::new(
+                    (t * 0.7).cos() * 3.0,
+                    (t * 1.3).sin() * 2.0,
+                    (t * 0.4).cos(),
+                )
+            })
+            .collect();
+        let sphere = Sphere::from_points(&points).unwrap();
+        let grown = Sphere::new(sphere.center, sphere.radius * (1.0 + 1e-5));
+        assert!(points.iter().all(|&p| grown.contains_point(p)));
+        assert!(sphere.radius < 4.0);
+    }
+}
@@ -0,0 +1,240 @@
+use core::ops::*;
+
+use crate::mat4::Mat4f;
+use crate::quat::Quatf;
+use crate::vec3::Vec3f;
+use crate::vec4::Vec4f;
+
+/// A dual quaternion of `f32` values representing a rigid transform, a rotation followed by a
+/// translation
+///
+/// Rigid transforms are expected to be unit dual quaternions, whose real part is a unit quaternion
+/// orthogonal to the dual part. `dq` and `-dq` represent the same transform.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DualQuatf {
+    /// The real part, holding the rotation
+    pub real: Quatf,
+    /// The dual part, holding the translation combined with the rotation
+    pub dual: Quatf,
+}
+
+impl DualQuatf {
+    /// The identity transform
+    pub const IDENTITY: Self =
+        Self::from_parts(Quatf::IDENTITY, Quatf::from_xyzw(0.0, 0.0, 0.0, 0.0));
+
+    /// Creates a new dual quaternion from its real and dual parts
+    pub const fn from_parts(real: Quatf, dual: Quatf) -> Self {
+        Self { real, dual }
+    }
+
+    /// Creates a transform that rotates by the unit quaternion `rotation` and then translates by
+    /// `translation`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::dual_quat::DualQuatf;
+    /// use yavml::quat::Quatf;
+    /// use yavml::vec3::Vec3f;
+    /// let rotation = Quatf::from_axis_angle(Vec3f::Z, core::f32::consts::FRAC_PI_2);
+    /// let transform = DualQuatf::from_rotation_translation(rotation, Vec3f::new(1.0, 2.0, 3.0));
+    /// let point = transform.transform_point(Vec3f::X);
+    /// assert!((point - Vec3f::new(1.0, 3.0, 3.0)).length() < 1e-6);
+    /// ```
+    pub fn from_rotation_translation(rotation: Quatf, translation: Vec3f) -> Self {
+        let t = Quatf::from_xyzw(translation.x, translation.y, translation.z, 0.0);
+        Self::from_parts(rotation, t * rotation * 0.5)
+    }
+
+    /// Creates a transform that rotates by the unit quaternion `rotation`
+    pub fn from_rotation(rotation: Quatf) -> Self {
+        Self::from_rotation_translation(rotation, Vec3f::ZERO)
+    }
+
+    /// Creates a transform that translates by `translation`
+    pub fn from_translation(translation: Vec3f) -> Self {
+        Self::from_rotation_translation(Quatf::IDENTITY, translation)
+    }
+
+    /// Creates a transform from the rigid affine transform matrix `mat`
+    ///
+    /// The upper-left 3x3 block must be a rotation, since scale and shear cannot be represented.
+    pub fn from_mat4(mat: &Mat4f) -> Self {
+        let translation = Vec3f::new(mat.w_axis.x, mat.w_axis.y, mat.w_axis.z);
+        Self::from_rotation_translation(Quatf::from_mat4(mat), translation)
+    }
+
+    /// Returns the affine transform matrix of the transform
+    pub fn to_mat4(&self) -> Mat4f {
+        let (rotation, translation) = self.to_rotation_translation();
+        let mut mat = rotation.to_mat4();
+        mat.w_axis = Vec4f::new(translation.x, translation.y, translation.z, 1.0);
+        mat
+    }
+
+    /// Returns the rotation of the transform
+    pub const fn rotation(&self) -> Quatf {
+        self.real
+    }
+
+    /// Returns the translation of the transform
+    pub fn translation(&self) -> Vec3f {
+        (self.dual * self.real.conjugate()).xyz() * 2.0
+    }
+
+    /// Splits the transform into a rotation followed by a translation
+    pub fn to_rotation_translation(&self) -> (Quatf, Vec3f) {
+        (self.rotation(), self.translation())
+    }
+
+    /// Returns `self` scaled to a unit dual quaternion
+    ///
+    /// The real part is scaled to a length of `1` and the dual part is made orthogonal to it, so
+    /// the result is always a rigid transform. The result is non-finite if the real part has a
+    /// length of zero.
+    pub fn normalize(&self) -> Self {
+        let inv_len = 1.0 / self.real.length();
+        let real = self.real * inv_len;
+        let dual = self.dual * inv_len;
+        Self::from_parts(real, dual - real * real.dot(dual))
+    }
+
+    /// Returns the inverse of the unit dual quaternion
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::dual_quat::DualQuatf;
+    /// use yavml::quat::Quatf;
+    /// use yavml::vec3::Vec3f;
+    /// let rotation = Quatf::from_axis_angle(Vec3f::Y, 1.0);
+    /// let transform = DualQuatf::from_rotation_translation(rotation, Vec3f::new(1.0, 2.0, 3.0));
+    /// let identity = transform * transform.inverse();
+    /// assert!(identity.abs_diff_eq(DualQuatf::IDENTITY, 1e-6));
+    /// ```
+    pub fn inverse(&self) -> Self {
+        Self::from_parts(self.real.conjugate(), self.dual.conjugate())
+    }
+
+    /// Returns the composition `self * rhs`, which applies `rhs` first
+    pub fn mul_dual_quat(&self, rhs: &Self) -> Self {
+        Self::from_parts(
+            self.real * rhs.real,
+            self.real * rhs.dual + self.dual * rhs.real,
+        )
+    }
+
+    /// Transforms the point `point`, applying both rotation and translation
+    pub fn transform_point(&self, point: Vec3f) -> Vec3f {
+        self.real * point + self.translation()
+    }
+
+    /// Transforms the vector `vector`, applying only the rotation
+    pub fn transform_vector(&self, vector: Vec3f) -> Vec3f {
+        self.real * vector
+    }
+
+    /// Blends `dual_quats` by `weights` with dual quaternion linear blending (DLB)
+    ///
+    /// Every dual quaternion is flipped onto the same hemisphere as the first before the weighted
+    /// sum is normalized, so blends always take the shorter path. Unlike blending matrices, the
+    /// result is always a rigid transform, which avoids the volume loss of twisting joints.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dual_quats` and `weights` have different lengths.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::dual_quat::DualQuatf;
+    /// use yavml::quat::Quatf;
+    /// use yavml::vec3::Vec3f;
+    /// let twist = DualQuatf::from_rotation(Quatf::from_axis_angle(Vec3f::X, 3.0));
+    /// let blended = DualQuatf::blend(&[DualQuatf::IDENTITY, twist], &[0.5, 0.5]);
+    /// let point = blended.transform_point(Vec3f::Y);
+    /// assert!((point.length() - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn blend(dual_quats: &[Self], weights: &[f32]) -> Self {
+        assert_eq!(
+            dual_quats.len(),
+            weights.len(),
+            "dual quaternion and weight slices must have the same length"
+        );
+        let Some(pivot) = dual_quats.first() else {
+            return Self::IDENTITY;
+        };
+        let zero = Quatf::from_xyzw(0.0, 0.0, 0.0, 0.0);
+        let sum = dual_quats.iter().zip(weights).fold(
+            Self::from_parts(zero, zero),
+            |sum, (dual_quat, weight)| {
+                let weight = if pivot.real.dot(dual_quat.real) < 0.0 {
+                    -weight
+                } else {
+                    *weight
+                };
+                sum + *dual_quat * weight
+            },
+        );
+        sum.normalize()
+    }
+
+    /// Returns `true` if every component of `self` and `rhs` differs by at most `max_abs_diff`
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.real.abs_diff_eq(rhs.real, max_abs_diff)
+            && self.dual.abs_diff_eq(rhs.dual, max_abs_diff)
+    }
+}
+
+impl Default for DualQuatf {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<DualQuatf> for Mat4f {
+    fn from(dual_quat: DualQuatf) -> Self {
+        dual_quat.to_mat4()
+    }
+}
+
+/// Addition of dual quaternions
+impl Add<DualQuatf> for DualQuatf {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_parts(self.real + rhs.real, self.dual + rhs.dual)
+    }
+}
+
+/// Multiplication of dual quaternions
+impl Mul<DualQuatf> for DualQuatf {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_dual_quat(&rhs)
+    }
+}
+
+/// Multiplication assignment of dual quaternions
+impl MulAssign<DualQuatf> for DualQuatf {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_dual_quat(&rhs);
+    }
+}
+
+/// Multiplication of a dual quaternion by an `f32`
+impl Mul<f32> for DualQuatf {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self::from_parts(self.real * rhs, self.dual * rhs)
+    }
+}
+
+/// Negation of a dual quaternion
+impl Neg for DualQuatf {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::from_parts(-self.real, -self.dual)
+    }
+}
@@ -0,0 +1,5 @@
+pub mod dual_quatf;
+pub use dual_quatf::DualQuatf;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,148 @@
+use super::DualQuatf;
+use crate::mat4::Mat4f;
+use crate::quat::Quatf;
+use crate::vec3::Vec3f;
+
+mod dual_quatf_tests {
+    use super::DualQuatf;
+    use super::Mat4f;
+    use super::Quatf;
+    use super::Vec3f;
+    use core::f32::consts::{FRAC_PI_2, PI};
+
+    // Tests that the identity leaves points unchanged
+    #[test]
+    fn test_dual_quatf_identity() {
+        let point = Vec3f::new(1.0, -2.0, 3.0);
+        assert_eq!(DualQuatf::IDENTITY.transform_point(point), point);
+        assert_eq!(DualQuatf::default(), DualQuatf::IDENTITY);
+        assert_eq!(DualQuatf::IDENTITY.to_mat4(), Mat4f::IDENTITY);
+    }
+
+    // Tests that rotation and translation are recovered and applied in order
+    #[test]
+    fn test_dual_quatf_rotation_translation() {
+        let rotation = Quatf::from_axis_angle(Vec3f::new(0.0, 0.6, 0.8), 1.3);
+        let translation = Vec3f::new(4.0, -1.0, 2.5);
+        let transform = DualQuatf::from_rotation_translation(rotation, translation);
+        let (out_rotation, out_translation) = transform.to_rotation_translation();
+        assert!(out_rotation.abs_diff_eq(rotation, 1e-6));
+        assert!((out_translation - translation).length() < 1e-5);
+
+        let point = Vec3f::new(1.0, 2.0, 3.0);
+        let expected = rotation * point + translation;
+        assert!((transform.transform_point(point) - expected).length() < 1e-5);
+        assert!((transform.transform_vector(point) - rotation * point).length() < 1e-5);
+        let moved = DualQuatf::from_translation(translation).transform_point(point);
+        assert_eq!(moved, point + translation);
+    }
+
+    // Tests converting to and from rigid affine transform matrices
+    #[test]
+    fn test_dual_quatf_mat4() {
+        let matrix = Mat4f::from_translation(Vec3f::new(1.0, 2.0, 3.0))
+            * Mat4f::from_axis_angle(Vec3f::X, 0.8);
+        let transform = DualQuatf::from_mat4(&matrix);
+        assert!(transform.to_mat4().abs_diff_eq(matrix, 1e-5));
+        let point = Vec3f::new(-1.0, 0.5, 2.0);
+        let expected = matrix.transform_point3(point);
+        assert!((transform.transform_point(point) - expected).length() < 1e-5);
+        assert_eq!(Mat4f::from(transform), transform.to_mat4());
+    }
+
+    // Tests that composition applies the right-hand transform first and matches matrices
+    #[test]
+    fn test_dual_quatf_mul() {
+        let a = DualQuatf::from_rotation_translation(
+            Quatf::from_axis_angle(Vec3f::Z, FRAC_PI_2),
+            Vec3f::new(1.0, 0.0, 0.0),
+        );
+        let b = DualQuatf::from_rotation_translation(
+            Quatf::from_axis_angle(Vec3f::X, 0.4),
+            Vec3f::new(0.0, 2.0, -1.0),
+        );
+        let point = Vec3f::new(0.5, 1.0, 1.5);
+        let composed = (a * b).transform_point(point);
+        assert!((composed - a.transform_point(b.transform_point(point))).length() < 1e-5);
+        assert!((a * b)
+            .to_mat4()
+            .abs_diff_eq(a.to_mat4() * b.to_mat4(), 1e-5));
+        let mut c = a;
+        c *= b;
+        assert_eq!(c, a * b);
+
+        assert!((a * a.inverse()).abs_diff_eq(DualQuatf::IDENTITY, 1e-6));
+        let back = a.inverse().transform_point(a.transform_point(point));
+        assert!((back - point).length() < 1e-5);
+    }
+
+    // Tests that normalization restores a unit real part orthogonal to the dual part
+    #[test]
+    fn test_dual_quatf_normalize() {
+        let transform = DualQuatf::from_rotation_translation(
+            Quatf::from_axis_angle(Vec3f::Y, 2.0),
+            Vec3f::new(3.0, 1.0, -2.0),
+        );
+        let mut drifted = transform * 1.5;
+        drifted.dual = drifted.dual + drifted.real * 0.1;
+        let normalized = drifted.normalize();
+        assert!((normalized.real.length() - 1.0).abs() < 1e-6);
+        assert!(normalized.real.dot(normalized.dual).abs() < 1e-6);
+        assert!(normalized.real.abs_diff_eq(transform.real, 1e-6));
+        assert!((normalized.translation() - transform.translation()).length() < 1e-5);
+    }
+
+    // Tests that blending a twist keeps points at their distance from the twist axis, while
+    // blending matrices collapses them towards it (the candy-wrapper artifact)
+    #[test]
+    fn test_dual_quatf_blend_candy_wrapper() {
+        let angle = PI * 0.95;
+        let twist = Quatf::from_axis_angle(Vec3f::X, angle);
+        let bones = [DualQuatf::IDENTITY, DualQuatf::from_rotation(twist)];
+        let matrices = [Mat4f::IDENTITY, twist.to_mat4()];
+        let point = Vec3f::new(0.5, 1.0, 0.0);
+
+        for weight in [0.1, 0.25, 0.5, 0.75, 0.9] {
+            let weights = [1.0 - weight, weight];
+            let blended = DualQuatf::blend(&bones, &weights).transform_point(point);
+            assert!((blended.x - point.x).abs() < 1e-6);
+            assert!((blended.y.hypot(blended.z) - 1.0).abs() < 1e-5);
+        }
+        let halfway = DualQuatf::blend(&bones, &[0.5, 0.5]).transform_point(point);
+        let expected = Quatf::from_axis_angle(Vec3f::X, angle * 0.5) * point;
+        assert!((halfway - expected).length() < 1e-5);
+
+        let matrix = matrices[0] * 0.5 + matrices[1] * 0.5;
+        let collapsed = matrix.transform_point3(point);
+        assert!(collapsed.y.hypot(collapsed.z) < 0.1);
+    }
+
+    // Tests that blending chooses the shorter path and handles translations and edge cases
+    #[test]
+    fn test_dual_quatf_blend() {
+        let a = DualQuatf::from_rotation_translation(
+            Quatf::from_axis_angle(Vec3f::Z, 0.2),
+            Vec3f::new(2.0, 0.0, 0.0),
+        );
+        let b = DualQuatf::from_rotation_translation(
+            Quatf::from_axis_angle(Vec3f::Z, 0.6),
+            Vec3f::new(2.0, 0.0, 0.0),
+        );
+        let expected = DualQuatf::from_rotation_translation(
+            Quatf::from_axis_angle(Vec3f::Z, 0.4),
+            Vec3f::new(2.0, 0.0, 0.0),
+        );
+        let blended = DualQuatf::blend(&[a, b], &[0.5, 0.5]);
+        assert!(blended.abs_diff_eq(expected, 1e-5));
+        assert!(DualQuatf::blend(&[a, -b], &[0.5, 0.5]).abs_diff_eq(expected, 1e-5));
+        assert!(DualQuatf::blend(&[a], &[3.0]).abs_diff_eq(a, 1e-6));
+        assert_eq!(DualQuatf::blend(&[], &[]), DualQuatf::IDENTITY);
+    }
+
+    // Tests that blending panics when the slices have different lengths
+    #[test]
+    #[should_panic]
+    fn test_dual_quatf_blend_mismatched() {
+        DualQuatf::blend(&[DualQuatf::IDENTITY], &[]);
+    }
+}
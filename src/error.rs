@@ -0,0 +1,27 @@
+use core::fmt;
+
+/// Errors returned by operations that cannot produce a meaningful result for their input
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The transform collapses at least one axis to zero length
+    Degenerate,
+    /// The transform skews its axes, so it cannot be split into scale and rotation
+    Shear,
+    /// The transform has a projective bottom row, so it is not affine
+    Projective,
+    /// The matrix is singular, so the system has no unique solution
+    Singular,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Degenerate => f.write_str("transform collapses an axis to zero length"),
+            Error::Shear => f.write_str("transform contains shear"),
+            Error::Projective => f.write_str("transform is projective, not affine"),
+            Error::Singular => f.write_str("matrix is singular"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,242 @@
+use super::EulerOrder;
+use crate::angle::Radians;
+use crate::mat3::Mat3f;
+use crate::quat::Quatf;
+
+/// The value of `cos(b)` for Tait-Bryan orders, or `sin(b)` for proper Euler orders, below which a
+/// rotation is treated as gimbal locked
+///
+/// Setting `c` to zero changes the matrix by about this much, so it is kept at rounding level.
+const GIMBAL_LOCK_EPSILON: f32 = 2.0 * f32::EPSILON;
+
+/// Three angles in radians that describe a 3D rotation as successive rotations about the axes of
+/// `order`
+///
+/// # Gimbal lock
+///
+/// When the middle rotation aligns the first and last axes, only `a + c` (or `a - c`) is
+/// determined by the rotation. This happens for Tait-Bryan orders when `b` is `±PI / 2` and for
+/// proper Euler orders when `b` is `0` or `PI`. Conversions back to angles then set `c` to zero
+/// and put the whole rotation about that axis into `a`.
+///
+/// # Ranges
+///
+/// Angles converted from a rotation have `a` and `c` in `[-PI, PI]`. `b` is in
+/// `[-PI / 2, PI / 2]` for Tait-Bryan orders and `[0, PI]` for proper Euler orders.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EulerAngles {
+    /// The angle of the first rotation
+    pub a: f32,
+    /// The angle of the second rotation
+    pub b: f32,
+    /// The angle of the third rotation
+    pub c: f32,
+    /// The axes and frame of the rotations
+    pub order: EulerOrder,
+}
+
+impl EulerAngles {
+    /// Creates new Euler angles
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The angle of the first rotation, in radians or as [`Degrees`](crate::angle::Degrees)
+    ///
+    /// * `b` - The angle of the second rotation, in radians or as [`Degrees`](crate::angle::Degrees)
+    ///
+    /// * `c` - The angle of the third rotation, in radians or as [`Degrees`](crate::angle::Degrees)
+    ///
+    /// * `order` - The axes and frame of the rotations
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::angle::{Degrees, Radians};
+    /// use yavml::euler::{EulerAngles, EulerOrder};
+    /// let angles = EulerAngles::new(Degrees(90.0), Radians(0.5), 0.25, EulerOrder::XYZ);
+    /// assert!((angles.a - core::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    /// assert_eq!((angles.b, angles.c), (0.5, 0.25));
+    /// ```
+    pub fn new(
+        a: impl Into<Radians<f32>>,
+        b: impl Into<Radians<f32>>,
+        c: impl Into<Radians<f32>>,
+        order: EulerOrder,
+    ) -> Self {
+        Self {
+            a: a.into().0,
+            b: b.into().0,
+            c: c.into().0,
+            order,
+        }
+    }
+
+    /// Returns the rotation as a quaternion
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::euler::{EulerAngles, EulerOrder};
+    /// use yavml::quat::Quatf;
+    /// use yavml::vec3::Vec3f;
+    /// let angles = EulerAngles::new(0.5, 0.0, 0.0, EulerOrder::ZYX);
+    /// assert!(angles.to_quat().abs_diff_eq(Quatf::from_axis_angle(Vec3f::Z, 0.5), 1e-6));
+    /// ```
+    pub fn to_quat(&self) -> Quatf {
+        let [axis_a, axis_b, axis_c] = self.order.axes();
+        let a = Quatf::from_axis_angle(axis_a, self.a);
+        let b = Quatf::from_axis_angle(axis_b, self.b);
+        let c = Quatf::from_axis_angle(axis_c, self.c);
+        if self.order.is_extrinsic() {
+            c * b * a
+        } else {
+            a * b * c
+        }
+    }
+
+    /// Returns the rotation as a matrix
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::euler::{EulerAngles, EulerOrder};
+    /// use yavml::mat3::Mat3f;
+    /// let angles = EulerAngles::new(0.1, 0.2, 0.3, EulerOrder::XYZ);
+    /// let expected = Mat3f::from_rotation_x(0.1)
+    ///     * Mat3f::from_rotation_y(0.2)
+    ///     * Mat3f::from_rotation_z(0.3);
+    /// assert!(angles.to_mat3().abs_diff_eq(expected, 1e-6));
+    /// ```
+    pub fn to_mat3(&self) -> Mat3f {
+        self.to_quat().to_mat3()
+    }
+
+    /// Creates Euler angles in `order` from the unit quaternion `quat`
+    ///
+    /// See [`EulerAngles`] for the ranges of the angles and the gimbal lock convention.
+    pub fn from_quat(quat: Quatf, order: EulerOrder) -> Self {
+        Self::from_mat3(&quat.to_mat3(), order)
+    }
+
+    /// Creates Euler angles in `order` from the rotation matrix `mat`
+    ///
+    /// See [`EulerAngles`] for the ranges of the angles and the gimbal lock convention.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::euler::{EulerAngles, EulerOrder};
+    /// let angles = EulerAngles::new(0.4, -0.3, 1.2, EulerOrder::YXZEx);
+    /// let converted = EulerAngles::from_mat3(&angles.to_mat3(), EulerOrder::YXZEx);
+    /// assert!(converted.abs_diff_eq(angles, 1e-5));
+    /// ```
+    pub fn from_mat3(mat: &Mat3f, order: EulerOrder) -> Self {
+        let intrinsic = order.intrinsic_reversed();
+        let [i, j, k] = intrinsic.axis_indices();
+        // Orders whose first two axes are not cyclic (x to y, y to z, z to x) are extracted as
+        // their mirror image, which negates every angle
+        let odd = (j + 3 - i) % 3 == 2;
+        let cols = [
+            mat.x_axis.to_array(),
+            mat.y_axis.to_array(),
+            mat.z_axis.to_array(),
+        ];
+        let m = |row: usize, col: usize| cols[col][row];
+
+        // Only `a` is read directly from the matrix. `c` is read from the matrix with the
+        // rotation by `a` undone, whose elements stay well-conditioned close to gimbal lock.
+        // Gimbal locked rotations keep the last angle of `order` at zero, which is the first
+        // angle of the intrinsic order when `order` is extrinsic
+        let zero_first = order.is_extrinsic();
+        let (a, b, c, locked) = if intrinsic.is_proper_euler() {
+            let k = 3 - i - j;
+            let sin_b = m(i, j).hypot(m(i, k));
+            let b = sin_b.atan2(m(i, i));
+            if sin_b > GIMBAL_LOCK_EPSILON {
+                let a = m(j, i).atan2(-m(k, i));
+                let (sin_a, cos_a) = a.sin_cos();
+                let c =
+                    (-cos_a * m(j, k) - sin_a * m(k, k)).atan2(cos_a * m(j, j) + sin_a * m(k, j));
+                (a, b, c, false)
+            } else if zero_first {
+                (0.0, b, (-m(j, k)).atan2(m(j, j)), true)
+            } else {
+                (m(k, j).atan2(m(j, j)), b, 0.0, true)
+            }
+        } else {
+            let cos_b = m(i, i).hypot(m(i, j));
+            let b = m(i, k).atan2(cos_b);
+            if cos_b > GIMBAL_LOCK_EPSILON {
+                let a = (-m(j, k)).atan2(m(k, k));
+                let (sin_a, cos_a) = a.sin_cos();
+                let c =
+                    (cos_a * m(j, i) + sin_a * m(k, i)).atan2(cos_a * m(j, j) + sin_a * m(k, j));
+                (a, b, c, false)
+            } else if zero_first {
+                (0.0, b, m(j, i).atan2(m(j, j)), true)
+            } else {
+                (m(k, j).atan2(m(j, j)), b, 0.0, true)
+            }
+        };
+        let (a, b, c) = match (odd, intrinsic.is_proper_euler(), locked) {
+            (false, _, _) => (a, b, c),
+            (true, false, _) => (-a, -b, -c),
+            // Negating `b` would leave `[0, PI]`. When locked `b` is `0` or `PI`, where the sign
+            // does not matter, and otherwise `(a + PI, -b, c + PI)` is the same rotation
+            (true, true, true) => (-a, b, -c),
+            (true, true, false) => (wrap_half_turn(-a), b, wrap_half_turn(-c)),
+        };
+        if order.is_extrinsic() {
+            Self::new(c, b, a, order)
+        } else {
+            Self::new(a, b, c, order)
+        }
+    }
+
+    /// Returns the same rotation described in `order`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::euler::{EulerAngles, EulerOrder};
+    /// let angles = EulerAngles::new(0.1, 0.2, 0.3, EulerOrder::XYZ);
+    /// let extrinsic = angles.to_order(EulerOrder::ZYXEx);
+    /// assert!(extrinsic.abs_diff_eq(EulerAngles::new(0.3, 0.2, 0.1, EulerOrder::ZYXEx), 1e-6));
+    /// ```
+    pub fn to_order(&self, order: EulerOrder) -> Self {
+        if order == self.order {
+            return *self;
+        }
+        if order == self.order.intrinsic_reversed() || self.order == order.intrinsic_reversed() {
+            return Self::new(self.c, self.b, self.a, order);
+        }
+        Self::from_quat(self.to_quat(), order)
+    }
+
+    /// Returns `true` if `self` and `rhs` have the same order and every angle differs by at most
+    /// `max_abs_diff`
+    ///
+    /// Different angles that describe the same rotation are not considered equal.
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.order == rhs.order
+            && (self.a - rhs.a).abs() <= max_abs_diff
+            && (self.b - rhs.b).abs() <= max_abs_diff
+            && (self.c - rhs.c).abs() <= max_abs_diff
+    }
+}
+
+impl From<EulerAngles> for Quatf {
+    fn from(angles: EulerAngles) -> Self {
+        angles.to_quat()
+    }
+}
+
+impl From<EulerAngles> for Mat3f {
+    fn from(angles: EulerAngles) -> Self {
+        angles.to_mat3()
+    }
+}
+
+/// Returns `angle + PI` wrapped back into `[-PI, PI]`, for an `angle` already in that range
+fn wrap_half_turn(angle: f32) -> f32 {
+    if angle <= 0.0 {
+        angle + core::f32::consts::PI
+    } else {
+        angle - core::f32::consts::PI
+    }
+}
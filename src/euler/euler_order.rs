@@ -0,0 +1,170 @@
+use crate::vec3::Vec3f;
+
+/// The axes and frame that the three angles of an [`EulerAngles`](super::EulerAngles) rotate
+/// about
+///
+/// Plain names such as `XYZ` are intrinsic: each rotation is about an axis of the frame already
+/// rotated by the previous angles, so `XYZ` is `Rx(a) * Ry(b) * Rz(c)`. Names ending in `Ex` are
+/// extrinsic: each rotation is about a fixed world axis, so `XYZEx` is `Rz(c) * Ry(b) * Rx(a)`.
+/// An intrinsic order therefore equals the reversed extrinsic order with `a` and `c` swapped.
+///
+/// The first six orders of each kind are Tait-Bryan angles, which use three different axes. The
+/// other six are proper Euler angles, which repeat the first axis.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+    /// Intrinsic rotations about X, then Y, then Z
+    XYZ,
+    /// Intrinsic rotations about X, then Z, then Y
+    XZY,
+    /// Intrinsic rotations about Y, then X, then Z
+    YXZ,
+    /// Intrinsic rotations about Y, then Z, then X
+    YZX,
+    /// Intrinsic rotations about Z, then X, then Y
+    ZXY,
+    /// Intrinsic rotations about Z, then Y, then X
+    ZYX,
+    /// Intrinsic rotations about X, then Y, then X again
+    XYX,
+    /// Intrinsic rotations about X, then Z, then X again
+    XZX,
+    /// Intrinsic rotations about Y, then X, then Y again
+    YXY,
+    /// Intrinsic rotations about Y, then Z, then Y again
+    YZY,
+    /// Intrinsic rotations about Z, then X, then Z again
+    ZXZ,
+    /// Intrinsic rotations about Z, then Y, then Z again
+    ZYZ,
+    /// Extrinsic rotations about X, then Y, then Z
+    XYZEx,
+    /// Extrinsic rotations about X, then Z, then Y
+    XZYEx,
+    /// Extrinsic rotations about Y, then X, then Z
+    YXZEx,
+    /// Extrinsic rotations about Y, then Z, then X
+    YZXEx,
+    /// Extrinsic rotations about Z, then X, then Y
+    ZXYEx,
+    /// Extrinsic rotations about Z, then Y, then X
+    ZYXEx,
+    /// Extrinsic rotations about X, then Y, then X again
+    XYXEx,
+    /// Extrinsic rotations about X, then Z, then X again
+    XZXEx,
+    /// Extrinsic rotations about Y, then X, then Y again
+    YXYEx,
+    /// Extrinsic rotations about Y, then Z, then Y again
+    YZYEx,
+    /// Extrinsic rotations about Z, then X, then Z again
+    ZXZEx,
+    /// Extrinsic rotations about Z, then Y, then Z again
+    ZYZEx,
+}
+
+impl EulerOrder {
+    /// Every order, intrinsic orders first
+    pub const ALL: [Self; 24] = [
+        Self::XYZ,
+        Self::XZY,
+        Self::YXZ,
+        Self::YZX,
+        Self::ZXY,
+        Self::ZYX,
+        Self::XYX,
+        Self::XZX,
+        Self::YXY,
+        Self::YZY,
+        Self::ZXZ,
+        Self::ZYZ,
+        Self::XYZEx,
+        Self::XZYEx,
+        Self::YXZEx,
+        Self::YZXEx,
+        Self::ZXYEx,
+        Self::ZYXEx,
+        Self::XYXEx,
+        Self::XZXEx,
+        Self::YXYEx,
+        Self::YZYEx,
+        Self::ZXZEx,
+        Self::ZYZEx,
+    ];
+
+    /// Returns the indices (0 for x, 1 for y, 2 for z) of the axes that `a`, `b` and `c` rotate
+    /// about
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::euler::EulerOrder;
+    /// assert_eq!(EulerOrder::ZXZ.axis_indices(), [2, 0, 2]);
+    /// assert_eq!(EulerOrder::YXZEx.axis_indices(), [1, 0, 2]);
+    /// ```
+    pub const fn axis_indices(&self) -> [usize; 3] {
+        match self {
+            Self::XYZ | Self::XYZEx => [0, 1, 2],
+            Self::XZY | Self::XZYEx => [0, 2, 1],
+            Self::YXZ | Self::YXZEx => [1, 0, 2],
+            Self::YZX | Self::YZXEx => [1, 2, 0],
+            Self::ZXY | Self::ZXYEx => [2, 0, 1],
+            Self::ZYX | Self::ZYXEx => [2, 1, 0],
+            Self::XYX | Self::XYXEx => [0, 1, 0],
+            Self::XZX | Self::XZXEx => [0, 2, 0],
+            Self::YXY | Self::YXYEx => [1, 0, 1],
+            Self::YZY | Self::YZYEx => [1, 2, 1],
+            Self::ZXZ | Self::ZXZEx => [2, 0, 2],
+            Self::ZYZ | Self::ZYZEx => [2, 1, 2],
+        }
+    }
+
+    /// Returns the unit axes that `a`, `b` and `c` rotate about
+    pub const fn axes(&self) -> [Vec3f; 3] {
+        const AXES: [Vec3f; 3] = [Vec3f::X, Vec3f::Y, Vec3f::Z];
+        let [i, j, k] = self.axis_indices();
+        [AXES[i], AXES[j], AXES[k]]
+    }
+
+    /// Returns `true` if each rotation is about a fixed world axis
+    pub const fn is_extrinsic(&self) -> bool {
+        *self as usize >= 12
+    }
+
+    /// Returns `true` for proper Euler orders, whose first and last axes are the same
+    pub const fn is_proper_euler(&self) -> bool {
+        let [i, _, k] = self.axis_indices();
+        i == k
+    }
+
+    /// Returns the order with the same axes rotating in the other kind of frame
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::euler::EulerOrder;
+    /// assert_eq!(EulerOrder::XYZ.to_other_frame(), EulerOrder::XYZEx);
+    /// assert_eq!(EulerOrder::ZXZEx.to_other_frame(), EulerOrder::ZXZ);
+    /// ```
+    pub const fn to_other_frame(&self) -> Self {
+        Self::ALL[(*self as usize + 12) % 24]
+    }
+
+    /// Returns the intrinsic order that describes the same rotations as `self` when `a` and `c`
+    /// are swapped, or `self` if it is already intrinsic
+    pub(crate) const fn intrinsic_reversed(&self) -> Self {
+        match self {
+            Self::XYZEx => Self::ZYX,
+            Self::XZYEx => Self::YZX,
+            Self::YXZEx => Self::ZXY,
+            Self::YZXEx => Self::XZY,
+            Self::ZXYEx => Self::YXZ,
+            Self::ZYXEx => Self::XYZ,
+            Self::XYXEx => Self::XYX,
+            Self::XZXEx => Self::XZX,
+            Self::YXYEx => Self::YXY,
+            Self::YZYEx => Self::YZY,
+            Self::ZXZEx => Self::ZXZ,
+            Self::ZYZEx => Self::ZYZ,
+            intrinsic => *intrinsic,
+        }
+    }
+}
@@ -0,0 +1,8 @@
+pub mod euler_order;
+pub use euler_order::EulerOrder;
+
+pub mod euler_angles;
+pub use euler_angles::EulerAngles;
+
+#[cfg(test)]
+mod tests;
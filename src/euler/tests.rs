@@ -0,0 +1,179 @@
+use super::EulerAngles;
+use super::EulerOrder;
+use crate::mat3::Mat3f;
+use crate::quat::Quatf;
+use crate::vec3::Vec3f;
+
+mod euler_angles_tests {
+    use super::EulerAngles;
+    use super::EulerOrder;
+    use super::Mat3f;
+    use super::Quatf;
+    use super::Vec3f;
+    use core::f32::consts::{FRAC_PI_2, PI};
+
+    /// Returns the rotation about the axis with index `axis` as a matrix
+    fn axis_rotation(axis: usize, angle: f32) -> Mat3f {
+        match axis {
+            0 => Mat3f::from_rotation_x(angle),
+            1 => Mat3f::from_rotation_y(angle),
+            _ => Mat3f::from_rotation_z(angle),
+        }
+    }
+
+    // Tests that every order composes its axis rotations in the documented frame
+    #[test]
+    fn test_euler_angles_to_mat3() {
+        let (a, b, c) = (0.3, -0.7, 1.1);
+        for order in EulerOrder::ALL {
+            let [i, j, k] = order.axis_indices();
+            let (ra, rb, rc) = (
+                axis_rotation(i, a),
+                axis_rotation(j, b),
+                axis_rotation(k, c),
+            );
+            let expected = if order.is_extrinsic() {
+                rc * rb * ra
+            } else {
+                ra * rb * rc
+            };
+            let angles = EulerAngles::new(a, b, c, order);
+            assert!(angles.to_mat3().abs_diff_eq(expected, 1e-6), "{order:?}");
+            assert_eq!(Mat3f::from(angles), angles.to_mat3());
+            assert_eq!(Quatf::from(angles), angles.to_quat());
+        }
+    }
+
+    // Tests that converting to a matrix and back recovers the angles for every order
+    #[test]
+    fn test_euler_angles_round_trip() {
+        for order in EulerOrder::ALL {
+            let b_values: &[f32] = if order.is_proper_euler() {
+                &[0.2, 1.3, 2.9]
+            } else {
+                &[-1.4, 0.2, 1.3]
+            };
+            for &b in b_values {
+                for (a, c) in [(0.4, -2.5), (-3.0, 0.1), (2.0, 1.5)] {
+                    let angles = EulerAngles::new(a, b, c, order);
+                    let from_mat = EulerAngles::from_mat3(&angles.to_mat3(), order);
+                    assert!(from_mat.abs_diff_eq(angles, 1e-4), "{order:?} {from_mat:?}");
+                    let from_quat = EulerAngles::from_quat(angles.to_quat(), order);
+                    assert!(
+                        from_quat.abs_diff_eq(angles, 1e-4),
+                        "{order:?} {from_quat:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    // Tests that matrices close to gimbal lock round-trip to the same matrix for every order
+    #[test]
+    fn test_euler_angles_near_gimbal_lock() {
+        let angles = EulerAngles::new(-2.4156, 1.5707998, 1.7964001, EulerOrder::ZXY);
+        let mat = angles.to_mat3();
+        let converted = EulerAngles::from_mat3(&mat, EulerOrder::ZXY);
+        assert!(converted.to_mat3().abs_diff_eq(mat, 1e-6), "{converted:?}");
+        for order in EulerOrder::ALL {
+            let lock = if order.is_proper_euler() {
+                PI
+            } else {
+                FRAC_PI_2
+            };
+            for offset in [1e-6, 3e-6, 1e-5, -2e-6] {
+                for (a, c) in [(-2.4156, 1.7964), (0.4, -2.5), (3.0, 3.0)] {
+                    let b = lock - offset;
+                    let mat = EulerAngles::new(a, b, c, order).to_mat3();
+                    let converted = EulerAngles::from_mat3(&mat, order);
+                    assert!(
+                        converted.to_mat3().abs_diff_eq(mat, 1e-6),
+                        "{order:?} {b} {converted:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    // Tests that gimbal locked rotations put the free rotation into `a` and set `c` to zero
+    #[test]
+    fn test_euler_angles_gimbal_lock() {
+        for order in EulerOrder::ALL {
+            let locked_b: &[f32] = if order.is_proper_euler() {
+                &[0.0, PI]
+            } else {
+                &[FRAC_PI_2, -FRAC_PI_2]
+            };
+            for &b in locked_b {
+                let matrix = EulerAngles::new(0.5, b, 0.25, order).to_mat3();
+                let angles = EulerAngles::from_mat3(&matrix, order);
+                assert_eq!(angles.c, 0.0, "{order:?}");
+                assert!((angles.b - b).abs() < 1e-3, "{order:?} {angles:?}");
+                assert!(
+                    angles.to_mat3().abs_diff_eq(matrix, 1e-5),
+                    "{order:?} {angles:?}"
+                );
+            }
+        }
+        let angles = EulerAngles::from_quat(Quatf::IDENTITY, EulerOrder::ZXZ);
+        assert_eq!((angles.a, angles.b, angles.c), (0.0, 0.0, 0.0));
+    }
+
+    // Tests converting between orders, including the intrinsic and extrinsic mirror
+    #[test]
+    fn test_euler_angles_to_order() {
+        let angles = EulerAngles::new(0.3, 0.4, -0.5, EulerOrder::XZY);
+        let mirrored = angles.to_order(EulerOrder::YZXEx);
+        assert_eq!(
+            mirrored,
+            EulerAngles::new(-0.5, 0.4, 0.3, EulerOrder::YZXEx)
+        );
+        assert_eq!(mirrored.to_order(EulerOrder::XZY), angles);
+        for order in EulerOrder::ALL {
+            let converted = angles.to_order(order);
+            assert_eq!(converted.order, order);
+            assert!(
+                converted.to_mat3().abs_diff_eq(angles.to_mat3(), 1e-5),
+                "{order:?}"
+            );
+        }
+    }
+
+    // Tests that angles given in degrees build the same rotation as radians
+    #[test]
+    fn test_euler_angles_degrees() {
+        let degrees = EulerAngles::new(
+            crate::angle::Degrees(90.0),
+            crate::angle::Degrees(-45.0),
+            crate::angle::Radians(0.5),
+            EulerOrder::ZXY,
+        );
+        let radians = EulerAngles::new(FRAC_PI_2, -PI / 4.0, 0.5, EulerOrder::ZXY);
+        assert!(degrees.abs_diff_eq(radians, 1e-6));
+    }
+
+    // Tests the axis queries of every order
+    #[test]
+    fn test_euler_order_axes() {
+        assert_eq!(EulerOrder::YXZ.axes(), [Vec3f::Y, Vec3f::X, Vec3f::Z]);
+        assert!(EulerOrder::ZYZEx.is_proper_euler());
+        assert!(!EulerOrder::ZYX.is_proper_euler());
+        for order in EulerOrder::ALL {
+            let other = order.to_other_frame();
+            assert_ne!(order.is_extrinsic(), other.is_extrinsic());
+            assert_eq!(order.axis_indices(), other.axis_indices());
+            assert_eq!(other.to_other_frame(), order);
+        }
+        assert_eq!(
+            EulerOrder::ALL.iter().filter(|o| o.is_extrinsic()).count(),
+            12
+        );
+        assert_eq!(
+            EulerOrder::ALL
+                .iter()
+                .filter(|o| o.is_proper_euler())
+                .count(),
+            12
+        );
+    }
+}
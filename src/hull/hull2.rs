@@ -0,0 +1,195 @@
+use core::cmp::Ordering;
+
+use crate::polygon::polygon2i::orient;
+use crate::polygon::WindingOrder;
+use crate::vec2::{Vec2d, Vec2f, Vec2i};
+
+/// Controls the shape of the vertex list returned by the convex hull functions
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HullConfig {
+    /// The direction the hull vertices run in
+    pub order: WindingOrder,
+    /// Whether points lying on a hull edge between two corners are kept as vertices
+    pub keep_collinear: bool,
+}
+
+impl HullConfig {
+    /// Creates a new config returning only the corners of the hull in `order`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::hull::HullConfig;
+    /// use yavml::polygon::WindingOrder;
+    /// let config = HullConfig::new(WindingOrder::Clockwise);
+    /// assert!(!config.keep_collinear);
+    /// ```
+    pub const fn new(order: WindingOrder) -> Self {
+        Self {
+            order,
+            keep_collinear: false,
+        }
+    }
+
+    /// Returns a copy of the config that keeps or drops the points on hull edges
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::hull::HullConfig;
+    /// let config = HullConfig::default().with_collinear(true);
+    /// assert!(config.keep_collinear);
+    /// ```
+    pub const fn with_collinear(self, keep_collinear: bool) -> Self {
+        Self {
+            keep_collinear,
+            ..self
+        }
+    }
+}
+
+impl Default for HullConfig {
+    /// A counterclockwise hull of only the corners
+    fn default() -> Self {
+        Self::new(WindingOrder::CounterClockwise)
+    }
+}
+
+/// Returns the vertices of the convex hull of `points` using Andrew's monotone chain
+///
+/// The hull starts at the point with the smallest x, breaking ties by the smallest y, and runs
+/// in `config.order`. Duplicate points appear once. If every point lies on one line, the hull
+/// is the two end points, or every distinct point in order along the line when
+/// `config.keep_collinear` is set. The result is unspecified if any coordinate is NaN.
+///
+/// # Arguments
+///
+/// * `points` - The points to wrap, in any order
+///
+/// * `config` - The output order and collinear-point handling
+///
+/// # Examples:
+/// ```
+/// use yavml::hull::{convex_hull2f, HullConfig};
+/// use yavml::vec2::Vec2f;
+/// let points = [
+///     Vec2f::new(2.0, 2.0),
+///     Vec2f::new(0.0, 0.0),
+///     Vec2f::new(1.0, 1.0),
+///     Vec2f::new(2.0, 0.0),
+///     Vec2f::new(0.0, 2.0),
+/// ];
+/// let hull = convex_hull2f(&points, HullConfig::default());
+/// assert_eq!(hull, [points[1], points[3], points[0], points[4]]);
+/// ```
+pub fn convex_hull2f(points: &[Vec2f], config: HullConfig) -> Vec<Vec2f> {
+    monotone_chain(
+        points,
+        config,
+        |a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)),
+        |&a, &b, &c| sign((b - a).cross(c - a)),
+    )
+}
+
+/// Returns the vertices of the convex hull of `points` using Andrew's monotone chain
+///
+/// This behaves like [`convex_hull2f`] for `f64` points.
+///
+/// # Arguments
+///
+/// * `points` - The points to wrap, in any order
+///
+/// * `config` - The output order and collinear-point handling
+pub fn convex_hull2d(points: &[Vec2d], config: HullConfig) -> Vec<Vec2d> {
+    monotone_chain(
+        points,
+        config,
+        |a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)),
+        |&a, &b, &c| sign((b - a).cross(c - a)),
+    )
+}
+
+/// Returns the vertices of the convex hull of `points` using Andrew's monotone chain
+///
+/// This behaves like [`convex_hull2f`], but every orientation test is exact, so collinear points
+/// are always recognized and any coordinates in the `i32` range are handled without overflow.
+///
+/// # Arguments
+///
+/// * `points` - The points to wrap, in any order
+///
+/// * `config` - The output order and collinear-point handling
+///
+/// # Examples:
+/// ```
+/// use yavml::hull::{convex_hull2i, HullConfig};
+/// use yavml::vec2::Vec2i;
+/// let points = [Vec2i::new(0, 0), Vec2i::new(4, 0), Vec2i::new(2, 0), Vec2i::new(2, 3)];
+/// let corners = convex_hull2i(&points, HullConfig::default());
+/// assert_eq!(corners, [points[0], points[1], points[3]]);
+/// let all = convex_hull2i(&points, HullConfig::default().with_collinear(true));
+/// assert_eq!(all, [points[0], points[2], points[1], points[3]]);
+/// ```
+pub fn convex_hull2i(points: &[Vec2i], config: HullConfig) -> Vec<Vec2i> {
+    monotone_chain(
+        points,
+        config,
+        |a, b| a.x.cmp(&b.x).then(a.y.cmp(&b.y)),
+        |a, b, c| orient(*a, *b, *c),
+    )
+}
+
+/// Returns the sign of `value` as `1`, `-1` or `0`
+fn sign<T: Default + PartialOrd>(value: T) -> i32 {
+    let zero = T::default();
+    i32::from(value > zero) - i32::from(value < zero)
+}
+
+/// Builds the hull shared by the typed hull functions
+///
+/// `compare` orders points by x and then y, and `orient` returns the sign of the turn from its
+/// first point through its second to its third, positive for counterclockwise turns.
+fn monotone_chain<P, C, O>(points: &[P], config: HullConfig, compare: C, orient: O) -> Vec<P>
+where
+    P: Copy + PartialEq,
+    C: Fn(&P, &P) -> Ordering,
+    O: Fn(&P, &P, &P) -> i32,
+{
+    let mut sorted = points.to_vec();
+    sorted.sort_unstable_by(&compare);
+    sorted.dedup();
+    let n = sorted.len();
+    if n < 3 {
+        return sorted;
+    }
+    let (first, last) = (&sorted[0], &sorted[n - 1]);
+    if sorted.iter().all(|point| orient(first, last, point) == 0) {
+        if !config.keep_collinear {
+            sorted = vec![*first, *last];
+        }
+        return sorted;
+    }
+    // Pops the middle point on a clockwise turn, and on a straight one unless collinear points
+    // are kept
+    let limit = i32::from(!config.keep_collinear);
+    let mut hull: Vec<P> = Vec::with_capacity(2 * n);
+    for point in &sorted {
+        while hull.len() >= 2 && orient(&hull[hull.len() - 2], &hull[hull.len() - 1], point) < limit
+        {
+            hull.pop();
+        }
+        hull.push(*point);
+    }
+    let floor = hull.len() + 1;
+    for point in sorted.iter().rev().skip(1) {
+        while hull.len() >= floor
+            && orient(&hull[hull.len() - 2], &hull[hull.len() - 1], point) < limit
+        {
+            hull.pop();
+        }
+        hull.push(*point);
+    }
+    hull.pop();
+    if config.order == WindingOrder::Clockwise {
+        hull[1..].reverse();
+    }
+    hull
+}
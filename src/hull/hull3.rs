@@ -0,0 +1,192 @@
+use std::collections::HashSet;
+
+use crate::polygon::WindingOrder;
+use crate::vec3::Vec3f;
+
+/// A triangle of the hull under construction
+struct Face {
+    /// The indices of the corners, counterclockwise when seen from outside
+    corners: [usize; 3],
+    /// The outward unit normal
+    normal: Vec3f,
+    /// The distance of the plane of the face from the origin along `normal`
+    offset: f32,
+    /// The points in front of this face that are not yet inside the hull
+    outside: Vec<usize>,
+    /// Whether the face is still part of the hull
+    alive: bool,
+}
+
+impl Face {
+    fn new(points: &[Vec3f], corners: [usize; 3]) -> Self {
+        let [a, b, c] = corners.map(|index| points[index]);
+        let normal = (b - a).cross(c - a).normalize();
+        Self {
+            corners,
+            normal,
+            offset: normal.dot(a),
+            outside: Vec::new(),
+            alive: true,
+        }
+    }
+
+    /// Returns how far `point` is in front of the face
+    fn distance(&self, point: Vec3f) -> f32 {
+        self.normal.dot(point) - self.offset
+    }
+}
+
+/// Returns the triangular faces of the convex hull of `points` using quickhull, or `None` if the
+/// points do not span a volume
+///
+/// Each face holds three indices into `points`, and its corners run in `order` when the hull is
+/// seen from outside, so counterclockwise faces have outward normals by the right-hand rule.
+/// Points on the surface of the hull but not at a corner, including the middle points of
+/// coplanar faces, never become corners. Points closer than a small tolerance relative to the
+/// extent of the input are treated as coplanar.
+///
+/// # Arguments
+///
+/// * `points` - The points to wrap, in any order
+///
+/// * `order` - The direction the corners of each face run in, seen from outside
+///
+/// # Examples:
+/// ```
+/// use yavml::hull::convex_hull3f;
+/// use yavml::polygon::WindingOrder;
+/// use yavml::vec3::Vec3f;
+/// let points = [
+///     Vec3f::ZERO,
+///     Vec3f::X,
+///     Vec3f::Y,
+///     Vec3f::Z,
+///     Vec3f::splat(0.1),
+/// ];
+/// let faces = convex_hull3f(&points, WindingOrder::CounterClockwise).unwrap();
+/// assert_eq!(faces.len(), 4);
+/// assert!(faces.iter().all(|face| !face.contains(&4)));
+/// let flat = [Vec3f::ZERO, Vec3f::X, Vec3f::Y, Vec3f::new(1.0, 1.0, 0.0)];
+/// assert_eq!(convex_hull3f(&flat, WindingOrder::CounterClockwise), None);
+/// ```
+pub fn convex_hull3f(points: &[Vec3f], order: WindingOrder) -> Option<Vec<[usize; 3]>> {
+    let extent = points.iter().fold(Vec3f::ZERO, |extent, point| {
+        Vec3f::new(
+            extent.x.max(point.x.abs()),
+            extent.y.max(point.y.abs()),
+            extent.z.max(point.z.abs()),
+        )
+    });
+    let epsilon = 3.0 * f32::EPSILON * (extent.x + extent.y + extent.z);
+    let mut faces = initial_simplex(points, epsilon)?;
+
+    let corners: Vec<usize> = faces.iter().flat_map(|face| face.corners).collect();
+    let rest = (0..points.len()).filter(|index| !corners.contains(index));
+    assign(&mut faces, points, 0, rest, epsilon);
+
+    while let Some(current) = faces
+        .iter()
+        .position(|face| face.alive && !face.outside.is_empty())
+    {
+        let face = &faces[current];
+        let apex = face.outside.iter().copied().max_by(|&p, &q| {
+            face.distance(points[p])
+                .total_cmp(&face.distance(points[q]))
+        })?;
+        let visible: Vec<usize> = (0..faces.len())
+            .filter(|&index| faces[index].alive && faces[index].distance(points[apex]) > epsilon)
+            .collect();
+        let boundary: Vec<(usize, usize)> = visible
+            .iter()
+            .flat_map(|&index| edges(faces[index].corners))
+            .collect();
+        let lookup: HashSet<(usize, usize)> = boundary.iter().copied().collect();
+        let mut orphans = Vec::new();
+        for &index in &visible {
+            faces[index].alive = false;
+            orphans.append(&mut faces[index].outside);
+        }
+        let first_new = faces.len();
+        for &(a, b) in &boundary {
+            if !lookup.contains(&(b, a)) {
+                faces.push(Face::new(points, [a, b, apex]));
+            }
+        }
+        let orphans = orphans.into_iter().filter(|&index| index != apex);
+        assign(&mut faces, points, first_new, orphans, epsilon);
+    }
+
+    Some(
+        faces
+            .into_iter()
+            .filter(|face| face.alive)
+            .map(|face| {
+                let [a, b, c] = face.corners;
+                match order {
+                    WindingOrder::CounterClockwise => [a, b, c],
+                    WindingOrder::Clockwise => [a, c, b],
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Returns the four faces of a tetrahedron spanned by extreme points, or `None` if every point
+/// is within `epsilon` of a common plane
+fn initial_simplex(points: &[Vec3f], epsilon: f32) -> Option<Vec<Face>> {
+    let farthest = |distance: &dyn Fn(Vec3f) -> f32| {
+        (0..points.len()).max_by(|&p, &q| distance(points[p]).total_cmp(&distance(points[q])))
+    };
+    let first = *points.first()?;
+    let a = farthest(&|point| (point - first).dot(point - first))?;
+    let b = farthest(&|point| (point - points[a]).dot(point - points[a]))?;
+    let axis = points[b] - points[a];
+    if axis.dot(axis) <= epsilon * epsilon {
+        return None;
+    }
+    let c = farthest(&|point| {
+        let cross = axis.cross(point - points[a]);
+        cross.dot(cross)
+    })?;
+    let normal = axis.cross(points[c] - points[a]);
+    let normal_length = normal.dot(normal).sqrt();
+    if normal_length <= epsilon * axis.dot(axis).sqrt() {
+        return None;
+    }
+    let d = farthest(&|point| normal.dot(point - points[a]).abs())?;
+    if normal.dot(points[d] - points[a]).abs() <= epsilon * normal_length {
+        return None;
+    }
+    let (b, c) = if normal.dot(points[d] - points[a]) > 0.0 {
+        (c, b)
+    } else {
+        (b, c)
+    };
+    Some(vec![
+        Face::new(points, [a, b, c]),
+        Face::new(points, [a, d, b]),
+        Face::new(points, [b, d, c]),
+        Face::new(points, [c, d, a]),
+    ])
+}
+
+/// Moves each of `candidates` into the outside set of the first face from `first_face` on that
+/// it is more than `epsilon` in front of, dropping the points behind every such face
+fn assign<I>(faces: &mut [Face], points: &[Vec3f], first_face: usize, candidates: I, epsilon: f32)
+where
+    I: IntoIterator<Item = usize>,
+{
+    for index in candidates {
+        if let Some(face) = faces[first_face..]
+            .iter_mut()
+            .find(|face| face.alive && face.distance(points[index]) > epsilon)
+        {
+            face.outside.push(index);
+        }
+    }
+}
+
+/// Returns the three directed edges of a face
+fn edges([a, b, c]: [usize; 3]) -> [(usize, usize); 3] {
+    [(a, b), (b, c), (c, a)]
+}
@@ -0,0 +1,8 @@
+pub mod hull2;
+pub use hull2::{convex_hull2d, convex_hull2f, convex_hull2i, HullConfig};
+
+pub mod hull3;
+pub use hull3::convex_hull3f;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,193 @@
+use super::convex_hull2d;
+use super::convex_hull2f;
+use super::convex_hull2i;
+use super::convex_hull3f;
+use super::HullConfig;
+use crate::polygon::WindingOrder;
+use crate::vec2::{Vec2d, Vec2f, Vec2i};
+use crate::vec3::Vec3f;
+
+mod hull2_tests {
+    use super::convex_hull2d;
+    use super::convex_hull2f;
+    use super::convex_hull2i;
+    use super::HullConfig;
+    use super::Vec2d;
+    use super::Vec2f;
+    use super::Vec2i;
+    use super::WindingOrder;
+
+    // Tests that interior, duplicate and edge points are dropped and the corners run
+    // counterclockwise from the lowest-left point
+    #[test]
+    fn test_hull2f_corners() {
+        let points = [
+            Vec2f::new(1.0, 1.0),
+            Vec2f::new(2.0, 2.0),
+            Vec2f::new(0.0, 2.0),
+            Vec2f::new(2.0, 0.0),
+            Vec2f::new(0.0, 0.0),
+            Vec2f::new(1.0, 0.0),
+            Vec2f::new(2.0, 2.0),
+        ];
+        let hull = convex_hull2f(&points, HullConfig::default());
+        let expected = [
+            Vec2f::new(0.0, 0.0),
+            Vec2f::new(2.0, 0.0),
+            Vec2f::new(2.0, 2.0),
+            Vec2f::new(0.0, 2.0),
+        ];
+        assert_eq!(hull, expected);
+    }
+
+    // Tests the clockwise order and keeping collinear points on every side, including the
+    // vertical ones
+    #[test]
+    fn test_hull2d_order_collinear() {
+        let points: Vec<Vec2d> = (0..3)
+            .flat_map(|x| (0..3).map(move |y| Vec2d::new(x as f64, y as f64)))
+            .collect();
+        let config = HullConfig::new(WindingOrder::Clockwise).with_collinear(true);
+        let hull = convex_hull2d(&points, config);
+        let expected = [
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+            (2, 1),
+            (2, 0),
+            (1, 0),
+        ]
+        .map(|(x, y)| Vec2d::new(x as f64, y as f64));
+        assert_eq!(hull, expected);
+        let corners = convex_hull2d(&points, HullConfig::new(WindingOrder::Clockwise));
+        assert_eq!(
+            corners,
+            [expected[0], expected[2], expected[4], expected[6]]
+        );
+    }
+
+    // Tests inputs with fewer than three distinct points and inputs on a single line
+    #[test]
+    fn test_hull2i_degenerate() {
+        let config = HullConfig::default();
+        assert!(convex_hull2i(&[], config).is_empty());
+        let point = Vec2i::new(3, 4);
+        assert_eq!(convex_hull2i(&[point, point], config), [point]);
+        let line = [Vec2i::new(2, 2), Vec2i::new(0, 0), Vec2i::new(1, 1)];
+        assert_eq!(convex_hull2i(&line, config), [line[1], line[0]]);
+        assert_eq!(
+            convex_hull2i(&line, config.with_collinear(true)),
+            [line[1], line[2], line[0]]
+        );
+    }
+
+    // Tests that orientation stays exact for collinear points far apart in the i32 range
+    #[test]
+    fn test_hull2i_exact_orientation() {
+        let (lo, hi) = (i32::MIN, i32::MAX);
+        let triangle = [Vec2i::new(lo, lo), Vec2i::new(hi, hi), Vec2i::new(lo, hi)];
+        let center = Vec2i::new(0, 0);
+        let mut points = vec![triangle[0], triangle[1], triangle[2], center];
+        let config = HullConfig::default();
+        assert_eq!(convex_hull2i(&points, config), triangle);
+        let all = convex_hull2i(&points, config.with_collinear(true));
+        assert_eq!(all, [triangle[0], center, triangle[1], triangle[2]]);
+        let below = Vec2i::new(1, 0);
+        points.push(below);
+        points.push(Vec2i::new(-1, 0));
+        let hull = convex_hull2i(&points, config.with_collinear(true));
+        assert_eq!(hull, [triangle[0], below, triangle[1], triangle[2]]);
+    }
+}
+
+mod hull3_tests {
+    use super::convex_hull3f;
+    use super::Vec3f;
+    use super::WindingOrder;
+
+    /// Returns `count` points scattered over a sphere and its inside by a fixed generator
+    fn scattered(count: usize) -> Vec<Vec3f> {
+        let mut state = 0x2545_f491_u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as f32 / u32::MAX as f32 * 2.0 - 1.0
+        };
+        (0..count)
+            .map(|i| {
+                let point = Vec3f::new(next(), next(), next());
+                if i % 3 == 0 {
+                    point
+                } else {
+                    point.normalize()
+                }
+            })
+            .collect()
+    }
+
+    /// Checks that every point is behind or on every face and that every edge is shared by
+    /// exactly two faces running it in opposite directions
+    fn assert_closed_hull(points: &[Vec3f], faces: &[[usize; 3]]) {
+        for &[a, b, c] in faces {
+            let (a, b, c) = (points[a], points[b], points[c]);
+            let normal = (b - a).cross(c - a).normalize();
+            for &point in points {
+                assert!(normal.dot(point - a) <= 1e-5);
+            }
+        }
+        let edges: Vec<(usize, usize)> = faces
+            .iter()
+            .flat_map(|&[a, b, c]| [(a, b), (b, c), (c, a)])
+            .collect();
+        for &(a, b) in &edges {
+            assert_eq!(edges.iter().filter(|&&edge| edge == (a, b)).count(), 1);
+            assert_eq!(edges.iter().filter(|&&edge| edge == (b, a)).count(), 1);
+        }
+    }
+
+    // Tests that a cube with inside and face-center points gives twelve outward corner faces
+    #[test]
+    fn test_hull3f_cube() {
+        let mut points: Vec<Vec3f> = (0..8)
+            .map(|i| Vec3f::new((i & 1) as f32, (i >> 1 & 1) as f32, (i >> 2) as f32))
+            .collect();
+        points.push(Vec3f::splat(0.5));
+        points.push(Vec3f::new(0.5, 0.5, 1.0));
+        let faces = convex_hull3f(&points, WindingOrder::CounterClockwise).unwrap();
+        assert_eq!(faces.len(), 12);
+        assert!(faces.iter().flatten().all(|&index| index < 8));
+        assert_closed_hull(&points, &faces);
+    }
+
+    // Tests the hull of scattered points and that clockwise faces are the counterclockwise
+    // ones reversed
+    #[test]
+    fn test_hull3f_scattered_order() {
+        let points = scattered(200);
+        let faces = convex_hull3f(&points, WindingOrder::CounterClockwise).unwrap();
+        assert_closed_hull(&points, &faces);
+        let corners = faces
+            .iter()
+            .flatten()
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(faces.len(), 2 * corners.len() - 4);
+        let clockwise = convex_hull3f(&points, WindingOrder::Clockwise).unwrap();
+        let flipped: Vec<[usize; 3]> = faces.iter().map(|&[a, b, c]| [a, c, b]).collect();
+        assert_eq!(clockwise, flipped);
+    }
+
+    // Tests that inputs without volume give no hull
+    #[test]
+    fn test_hull3f_degenerate() {
+        let order = WindingOrder::CounterClockwise;
+        assert_eq!(convex_hull3f(&[], order), None);
+        assert_eq!(convex_hull3f(&[Vec3f::ONE; 5], order), None);
+        let line: Vec<Vec3f> = (0..5).map(|i| Vec3f::splat(i as f32)).collect();
+        assert_eq!(convex_hull3f(&line, order), None);
+        let plane = [Vec3f::ZERO, Vec3f::X, Vec3f::Y, Vec3f::new(1.0, 1.0, 0.0)];
+        assert_eq!(convex_hull3f(&plane, order), None);
+    }
+}
@@ -0,0 +1,185 @@
+use core::ops::*;
+
+use crate::affine::Affine2;
+use crate::angle::Radians;
+use crate::mat3::Mat3f;
+use crate::rot2::Rot2f;
+use crate::vec2::Vec2f;
+
+/// A 2D rigid transform made of a rotation followed by a translation
+///
+/// The fields are private so that the rotation always has unit length. Without a scale the
+/// inverse is the transposed rotation and a rotated negative translation, with no matrix
+/// inversion.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Isometry2 {
+    rotation: Rot2f,
+    translation: Vec2f,
+}
+
+impl Isometry2 {
+    /// The identity transform
+    pub const IDENTITY: Self = Self {
+        rotation: Rot2f::IDENTITY,
+        translation: Vec2f::ZERO,
+    };
+
+    /// Creates a transform that applies `rotation` and then translates by `translation`
+    ///
+    /// The rotation is normalized, so it only needs a non-zero length.
+    ///
+    /// # Arguments
+    ///
+    /// * `rotation` - The rotation applied first
+    ///
+    /// * `translation` - The translation applied after the rotation
+    pub fn new(rotation: Rot2f, translation: Vec2f) -> Self {
+        Self {
+            rotation: rotation.normalize(),
+            translation,
+        }
+    }
+
+    /// Creates a transform that rotates counter-clockwise by `angle` and then translates by
+    /// `translation`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::isometry::Isometry2;
+    /// use yavml::vec2::Vec2f;
+    /// let isometry = Isometry2::from_angle_translation(core::f32::consts::FRAC_PI_2, Vec2f::new(1.0, 0.0));
+    /// let point = isometry.transform_point(Vec2f::new(1.0, 0.0));
+    /// assert!((point - Vec2f::new(1.0, 1.0)).length() < 1e-6);
+    /// ```
+    pub fn from_angle_translation(angle: impl Into<Radians<f32>>, translation: Vec2f) -> Self {
+        Self {
+            rotation: Rot2f::from_angle(angle),
+            translation,
+        }
+    }
+
+    /// Creates a transform that translates by `translation`
+    pub const fn from_translation(translation: Vec2f) -> Self {
+        Self {
+            rotation: Rot2f::IDENTITY,
+            translation,
+        }
+    }
+
+    /// Creates a transform that applies `rotation`, which is normalized first
+    pub fn from_rotation(rotation: Rot2f) -> Self {
+        Self::new(rotation, Vec2f::ZERO)
+    }
+
+    /// Returns the unit rotation of the transform
+    pub const fn rotation(&self) -> Rot2f {
+        self.rotation
+    }
+
+    /// Returns the translation of the transform
+    pub const fn translation(&self) -> Vec2f {
+        self.translation
+    }
+
+    /// Transforms `point`, applying the rotation and the translation
+    pub fn transform_point(&self, point: Vec2f) -> Vec2f {
+        self.rotation * point + self.translation
+    }
+
+    /// Transforms `vector`, applying only the rotation
+    pub fn transform_vector(&self, vector: Vec2f) -> Vec2f {
+        self.rotation * vector
+    }
+
+    /// Transforms `point` by the inverse transform without computing the inverse
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::isometry::Isometry2;
+    /// use yavml::vec2::Vec2f;
+    /// let isometry = Isometry2::from_angle_translation(0.8, Vec2f::new(3.0, -1.0));
+    /// let point = Vec2f::new(2.0, 5.0);
+    /// let back = isometry.inverse_transform_point(isometry.transform_point(point));
+    /// assert!((back - point).length() < 1e-5);
+    /// ```
+    pub fn inverse_transform_point(&self, point: Vec2f) -> Vec2f {
+        self.rotation.inverse() * (point - self.translation)
+    }
+
+    /// Transforms `vector` by the inverse rotation
+    pub fn inverse_transform_vector(&self, vector: Vec2f) -> Vec2f {
+        self.rotation.inverse() * vector
+    }
+
+    /// Returns the inverse transform
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.inverse();
+        Self {
+            rotation,
+            translation: rotation * -self.translation,
+        }
+    }
+
+    /// Returns the composition `self * rhs`, which applies `rhs` first
+    ///
+    /// The combined rotation is pulled back towards unit length, so long chains of compositions
+    /// do not drift.
+    pub fn mul_isometry(&self, rhs: &Self) -> Self {
+        Self {
+            rotation: (self.rotation * rhs.rotation).renormalize_fast(),
+            translation: self.transform_point(rhs.translation),
+        }
+    }
+
+    /// Returns the transform as an [`Affine2`]
+    pub fn to_affine2(&self) -> Affine2 {
+        Affine2::from_mat2_translation(self.rotation.to_mat2(), self.translation)
+    }
+
+    /// Returns the transform as a 2D affine `Mat3f`
+    pub fn to_mat3(&self) -> Mat3f {
+        self.to_affine2().to_mat3()
+    }
+
+    /// Returns `true` if the rotations and translations of `self` and `rhs` differ by at most
+    /// `max_abs_diff`
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.rotation.abs_diff_eq(rhs.rotation, max_abs_diff)
+            && (self.translation.x - rhs.translation.x).abs() <= max_abs_diff
+            && (self.translation.y - rhs.translation.y).abs() <= max_abs_diff
+    }
+}
+
+impl Default for Isometry2 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Isometry2> for Affine2 {
+    fn from(isometry: Isometry2) -> Self {
+        isometry.to_affine2()
+    }
+}
+
+impl From<Isometry2> for Mat3f {
+    fn from(isometry: Isometry2) -> Self {
+        isometry.to_mat3()
+    }
+}
+
+/// Composition of transforms, applying `rhs` first
+impl Mul<Isometry2> for Isometry2 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_isometry(&rhs)
+    }
+}
+
+/// Composition assignment of transforms, applying `rhs` first
+impl MulAssign<Isometry2> for Isometry2 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_isometry(&rhs);
+    }
+}
@@ -0,0 +1,181 @@
+use core::ops::*;
+
+use crate::affine::Affine3;
+use crate::mat4::Mat4f;
+use crate::quat::Quatf;
+use crate::vec3::Vec3f;
+
+/// A 3D rigid transform made of a rotation followed by a translation
+///
+/// The fields are private so that the rotation is always a unit quaternion. Without a scale the
+/// inverse is the conjugate rotation and a rotated negative translation, with no matrix
+/// inversion.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Isometry3 {
+    rotation: Quatf,
+    translation: Vec3f,
+}
+
+impl Isometry3 {
+    /// The identity transform
+    pub const IDENTITY: Self = Self {
+        rotation: Quatf::IDENTITY,
+        translation: Vec3f::ZERO,
+    };
+
+    /// Creates a transform that applies `rotation` and then translates by `translation`
+    ///
+    /// The rotation is normalized, so it only needs a non-zero length.
+    ///
+    /// # Arguments
+    ///
+    /// * `rotation` - The rotation applied first
+    ///
+    /// * `translation` - The translation applied after the rotation
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::isometry::Isometry3;
+    /// use yavml::quat::Quatf;
+    /// use yavml::vec3::Vec3f;
+    /// let isometry = Isometry3::new(Quatf::from_xyzw(0.0, 0.0, 2.0, 2.0), Vec3f::ZERO);
+    /// assert!(isometry.rotation().is_normalized());
+    /// ```
+    pub fn new(rotation: Quatf, translation: Vec3f) -> Self {
+        Self {
+            rotation: rotation.normalize(),
+            translation,
+        }
+    }
+
+    /// Creates a transform that translates by `translation`
+    pub const fn from_translation(translation: Vec3f) -> Self {
+        Self {
+            rotation: Quatf::IDENTITY,
+            translation,
+        }
+    }
+
+    /// Creates a transform that applies `rotation`, which is normalized first
+    pub fn from_rotation(rotation: Quatf) -> Self {
+        Self::new(rotation, Vec3f::ZERO)
+    }
+
+    /// Returns the unit quaternion rotation of the transform
+    pub const fn rotation(&self) -> Quatf {
+        self.rotation
+    }
+
+    /// Returns the translation of the transform
+    pub const fn translation(&self) -> Vec3f {
+        self.translation
+    }
+
+    /// Transforms `point`, applying the rotation and the translation
+    pub fn transform_point(&self, point: Vec3f) -> Vec3f {
+        self.rotation * point + self.translation
+    }
+
+    /// Transforms `vector`, applying only the rotation
+    pub fn transform_vector(&self, vector: Vec3f) -> Vec3f {
+        self.rotation * vector
+    }
+
+    /// Transforms `point` by the inverse transform without computing the inverse
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::isometry::Isometry3;
+    /// use yavml::quat::Quatf;
+    /// use yavml::vec3::Vec3f;
+    /// let rotation = Quatf::from_axis_angle(Vec3f::Y, 1.2);
+    /// let isometry = Isometry3::new(rotation, Vec3f::new(3.0, -1.0, 2.0));
+    /// let point = Vec3f::new(2.0, 5.0, -4.0);
+    /// let back = isometry.inverse_transform_point(isometry.transform_point(point));
+    /// assert!((back - point).length() < 1e-5);
+    /// ```
+    pub fn inverse_transform_point(&self, point: Vec3f) -> Vec3f {
+        self.rotation.conjugate() * (point - self.translation)
+    }
+
+    /// Transforms `vector` by the inverse rotation
+    pub fn inverse_transform_vector(&self, vector: Vec3f) -> Vec3f {
+        self.rotation.conjugate() * vector
+    }
+
+    /// Returns the inverse transform
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.conjugate();
+        Self {
+            rotation,
+            translation: rotation * -self.translation,
+        }
+    }
+
+    /// Returns the composition `self * rhs`, which applies `rhs` first
+    ///
+    /// The combined rotation is pulled back towards unit length with one Newton step, so long
+    /// chains of compositions do not drift.
+    pub fn mul_isometry(&self, rhs: &Self) -> Self {
+        let rotation = self.rotation * rhs.rotation;
+        Self {
+            rotation: rotation * ((3.0 - rotation.dot(rotation)) * 0.5),
+            translation: self.transform_point(rhs.translation),
+        }
+    }
+
+    /// Returns the transform as an [`Affine3`]
+    pub fn to_affine3(&self) -> Affine3 {
+        Affine3::from_mat3_translation(self.rotation.to_mat3(), self.translation)
+    }
+
+    /// Returns the transform as an affine `Mat4f`
+    pub fn to_mat4(&self) -> Mat4f {
+        self.to_affine3().to_mat4()
+    }
+
+    /// Returns `true` if the rotations and translations of `self` and `rhs` differ by at most
+    /// `max_abs_diff`
+    ///
+    /// The quaternions `q` and `-q` describe the same rotation but are not considered equal.
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.rotation.abs_diff_eq(rhs.rotation, max_abs_diff)
+            && (self.translation.x - rhs.translation.x).abs() <= max_abs_diff
+            && (self.translation.y - rhs.translation.y).abs() <= max_abs_diff
+            && (self.translation.z - rhs.translation.z).abs() <= max_abs_diff
+    }
+}
+
+impl Default for Isometry3 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Isometry3> for Affine3 {
+    fn from(isometry: Isometry3) -> Self {
+        isometry.to_affine3()
+    }
+}
+
+impl From<Isometry3> for Mat4f {
+    fn from(isometry: Isometry3) -> Self {
+        isometry.to_mat4()
+    }
+}
+
+/// Composition of transforms, applying `rhs` first
+impl Mul<Isometry3> for Isometry3 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_isometry(&rhs)
+    }
+}
+
+/// Composition assignment of transforms, applying `rhs` first
+impl MulAssign<Isometry3> for Isometry3 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_isometry(&rhs);
+    }
+}
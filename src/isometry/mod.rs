@@ -0,0 +1,8 @@
+pub mod isometry2;
+pub use isometry2::Isometry2;
+
+pub mod isometry3;
+pub use isometry3::Isometry3;
+
+#[cfg(test)]
+mod tests;
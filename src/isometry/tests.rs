@@ -0,0 +1,134 @@
+use super::Isometry2;
+use super::Isometry3;
+use crate::affine::Affine2;
+use crate::affine::Affine3;
+use crate::mat3::Mat3f;
+use crate::mat4::Mat4f;
+use crate::quat::Quatf;
+use crate::rot2::Rot2f;
+use crate::vec2::Vec2f;
+use crate::vec3::Vec3f;
+
+mod isometry2_tests {
+    use super::Affine2;
+    use super::Isometry2;
+    use super::Mat3f;
+    use super::Rot2f;
+    use super::Vec2f;
+
+    fn sample() -> Isometry2 {
+        Isometry2::from_angle_translation(0.9, Vec2f::new(3.0, -4.0))
+    }
+
+    // Tests that construction normalizes the rotation
+    #[test]
+    fn test_isometry2_new() {
+        let isometry = Isometry2::new(Rot2f::from_cos_sin(0.0, 3.0), Vec2f::ONE);
+        assert_eq!(isometry.rotation(), Rot2f::from_cos_sin(0.0, 1.0));
+        assert_eq!(isometry.translation(), Vec2f::ONE);
+        assert_eq!(Isometry2::default(), Isometry2::IDENTITY);
+        assert_eq!(
+            Isometry2::from_translation(Vec2f::ONE).transform_vector(Vec2f::ONE),
+            Vec2f::ONE
+        );
+    }
+
+    // Tests that the inverse and inverse transforms undo the transform and match the affine inverse
+    #[test]
+    fn test_isometry2_inverse() {
+        let isometry = sample();
+        let point = Vec2f::new(-2.0, 7.5);
+        let moved = isometry.transform_point(point);
+        assert!((isometry.inverse_transform_point(moved) - point).length() < 1e-5);
+        assert!((isometry.inverse().transform_point(moved) - point).length() < 1e-5);
+        let vector = isometry.transform_vector(point);
+        assert!((isometry.inverse_transform_vector(vector) - point).length() < 1e-5);
+        assert!((isometry * isometry.inverse()).abs_diff_eq(Isometry2::IDENTITY, 1e-6));
+        assert!(isometry
+            .inverse()
+            .to_affine2()
+            .abs_diff_eq(isometry.to_affine2().inverse(), 1e-5));
+    }
+
+    // Tests that composition matches matrices and keeps the rotation normalized
+    #[test]
+    fn test_isometry2_composition() {
+        let a = sample();
+        let b = Isometry2::from_angle_translation(-2.1, Vec2f::new(0.5, 1.0));
+        assert!((a * b)
+            .to_mat3()
+            .abs_diff_eq(a.to_mat3() * b.to_mat3(), 1e-5));
+        assert_eq!(Mat3f::from(a), a.to_mat3());
+        assert_eq!(Affine2::from(a), a.to_affine2());
+
+        let mut chain = Isometry2::IDENTITY;
+        for _ in 0..10000 {
+            chain *= b;
+        }
+        assert!((chain.rotation().length_squared() - 1.0).abs() < 1e-6);
+    }
+}
+
+mod isometry3_tests {
+    use super::Affine3;
+    use super::Isometry3;
+    use super::Mat4f;
+    use super::Quatf;
+    use super::Vec3f;
+
+    fn sample() -> Isometry3 {
+        Isometry3::new(
+            Quatf::from_axis_angle(Vec3f::new(0.0, 0.6, 0.8), 1.3),
+            Vec3f::new(3.0, -4.0, 1.0),
+        )
+    }
+
+    // Tests that construction normalizes the rotation
+    #[test]
+    fn test_isometry3_new() {
+        let isometry = Isometry3::from_rotation(Quatf::from_xyzw(0.0, 2.0, 0.0, 0.0));
+        assert_eq!(isometry.rotation(), Quatf::from_xyzw(0.0, 1.0, 0.0, 0.0));
+        assert_eq!(isometry.translation(), Vec3f::ZERO);
+        assert_eq!(Isometry3::default(), Isometry3::IDENTITY);
+        let moved = Isometry3::from_translation(Vec3f::ONE).transform_point(Vec3f::ONE);
+        assert_eq!(moved, Vec3f::splat(2.0));
+    }
+
+    // Tests that the inverse and inverse transforms undo the transform and match the affine inverse
+    #[test]
+    fn test_isometry3_inverse() {
+        let isometry = sample();
+        let point = Vec3f::new(-2.0, 7.5, 0.5);
+        let moved = isometry.transform_point(point);
+        assert!((isometry.inverse_transform_point(moved) - point).length() < 1e-5);
+        assert!((isometry.inverse().transform_point(moved) - point).length() < 1e-5);
+        let vector = isometry.transform_vector(point);
+        assert!((isometry.inverse_transform_vector(vector) - point).length() < 1e-5);
+        assert!((isometry * isometry.inverse()).abs_diff_eq(Isometry3::IDENTITY, 1e-6));
+        assert!(isometry
+            .inverse()
+            .to_affine3()
+            .abs_diff_eq(isometry.to_affine3().inverse(), 1e-5));
+    }
+
+    // Tests that composition matches matrices and keeps the rotation normalized
+    #[test]
+    fn test_isometry3_composition() {
+        let a = sample();
+        let b = Isometry3::new(
+            Quatf::from_axis_angle(Vec3f::X, -0.7),
+            Vec3f::new(0.5, 1.0, 2.0),
+        );
+        assert!((a * b)
+            .to_mat4()
+            .abs_diff_eq(a.to_mat4() * b.to_mat4(), 1e-5));
+        assert_eq!(Mat4f::from(a), a.to_mat4());
+        assert_eq!(Affine3::from(a), a.to_affine3());
+
+        let mut chain = Isometry3::IDENTITY;
+        for _ in 0..10000 {
+            chain *= b;
+        }
+        assert!((chain.rotation().length() - 1.0).abs() < 1e-6);
+    }
+}
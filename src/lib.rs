@@ -1,3 +1,51 @@
+pub mod error;
+pub use error::Error;
+
+pub mod scalar;
+
+pub mod angle;
+
 pub mod vec2;
 pub mod vec3;
 pub mod vec4;
+
+pub mod mat2;
+pub mod mat3;
+pub mod mat4;
+
+pub mod quat;
+
+pub mod euler;
+
+pub mod dual_quat;
+
+pub mod rot2;
+
+pub mod affine;
+
+pub mod transform;
+
+pub mod isometry;
+
+pub mod rect;
+
+pub mod aabb;
+
+pub mod circle;
+
+pub mod ray;
+
+pub mod segment;
+
+pub mod plane;
+
+pub mod triangle;
+
+pub mod polygon;
+
+pub mod hull;
+
+pub mod matrix;
+
+#[cfg(feature = "parallel")]
+pub mod parallel;
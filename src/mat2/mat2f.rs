@@ -0,0 +1,372 @@
+use core::ops::*;
+
+use crate::angle::Radians;
+use crate::error::Error;
+use crate::vec2::Vec2f;
+
+/// The largest cosine allowed between two axes before they are considered sheared
+const SHEAR_EPSILON: f32 = 1e-4;
+
+/// A 2x2 column-major matrix of `f32` values
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat2f {
+    /// The first column of the matrix
+    pub x_axis: Vec2f,
+    /// The second column of the matrix
+    pub y_axis: Vec2f,
+}
+
+impl Mat2f {
+    /// Matrix of all zeros
+    pub const ZERO: Self = Self::from_cols(Vec2f::ZERO, Vec2f::ZERO);
+    /// The identity matrix
+    pub const IDENTITY: Self = Self::from_cols(Vec2f::new(1.0, 0.0), Vec2f::new(0.0, 1.0));
+
+    /// Creates a new matrix from its two columns
+    ///
+    /// # Arguments
+    ///
+    /// * `x_axis` - A `Vec2f` holding the first column of the matrix
+    ///
+    /// * `y_axis` - A `Vec2f` holding the second column of the matrix
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat2::Mat2f;
+    /// use yavml::vec2::Vec2f;
+    /// let matrix = Mat2f::from_cols(Vec2f::new(1.0, 0.0), Vec2f::new(0.0, 1.0));
+    /// assert_eq!(matrix, Mat2f::IDENTITY);
+    /// ```
+    pub const fn from_cols(x_axis: Vec2f, y_axis: Vec2f) -> Self {
+        Self { x_axis, y_axis }
+    }
+
+    /// Creates a new matrix from a column-major array
+    pub const fn from_cols_array(arr: [f32; 4]) -> Self {
+        Self::from_cols(Vec2f::new(arr[0], arr[1]), Vec2f::new(arr[2], arr[3]))
+    }
+
+    /// Create a column-major array from the matrix
+    pub const fn to_cols_array(&self) -> [f32; 4] {
+        [self.x_axis.x, self.x_axis.y, self.y_axis.x, self.y_axis.y]
+    }
+
+    /// Creates a matrix that rotates counter-clockwise by `angle` radians
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat2::Mat2f;
+    /// use yavml::vec2::Vec2f;
+    /// let rotated = Mat2f::from_angle(core::f32::consts::FRAC_PI_2) * Vec2f::new(1.0, 0.0);
+    /// assert!((rotated - Vec2f::new(0.0, 1.0)).length() < 1e-6);
+    /// ```
+    pub fn from_angle(angle: impl Into<Radians<f32>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        Self::from_cols(Vec2f::new(cos, sin), Vec2f::new(-sin, cos))
+    }
+
+    /// Creates a matrix that scales along the x and y axes by `scale`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat2::Mat2f;
+    /// use yavml::vec2::Vec2f;
+    /// let scaled = Mat2f::from_scale(Vec2f::new(2.0, 3.0)) * Vec2f::ONE;
+    /// assert_eq!(scaled, Vec2f::new(2.0, 3.0));
+    /// ```
+    pub const fn from_scale(scale: Vec2f) -> Self {
+        Self::from_cols(Vec2f::new(scale.x, 0.0), Vec2f::new(0.0, scale.y))
+    }
+
+    /// Returns the first row of the matrix
+    pub const fn row0(&self) -> Vec2f {
+        Vec2f::new(self.x_axis.x, self.y_axis.x)
+    }
+
+    /// Returns the second row of the matrix
+    pub const fn row1(&self) -> Vec2f {
+        Vec2f::new(self.x_axis.y, self.y_axis.y)
+    }
+
+    /// Returns the determinant of the matrix
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat2::Mat2f;
+    /// assert_eq!(Mat2f::from_cols_array([1.0, 3.0, 2.0, 4.0]).determinant(), -2.0);
+    /// ```
+    pub fn determinant(&self) -> f32 {
+        self.x_axis.cross(self.y_axis)
+    }
+
+    /// Returns the transpose of the matrix
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat2::Mat2f;
+    /// let matrix = Mat2f::from_cols_array([1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(matrix.transpose(), Mat2f::from_cols_array([1.0, 3.0, 2.0, 4.0]));
+    /// ```
+    pub const fn transpose(&self) -> Self {
+        Self::from_cols(self.row0(), self.row1())
+    }
+
+    /// Returns the inverse of the matrix, or `None` if the matrix is not invertible
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat2::Mat2f;
+    /// let matrix = Mat2f::from_cols_array([2.0, 0.0, 0.0, 4.0]);
+    /// assert_eq!(matrix.try_inverse(), Some(Mat2f::from_cols_array([0.5, 0.0, 0.0, 0.25])));
+    /// assert_eq!(Mat2f::ZERO.try_inverse(), None);
+    /// ```
+    pub fn try_inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let inv_det = 1.0 / det;
+        Some(Self::from_cols(
+            Vec2f::new(self.y_axis.y, -self.x_axis.y) * inv_det,
+            Vec2f::new(-self.y_axis.x, self.x_axis.x) * inv_det,
+        ))
+    }
+
+    /// Returns the inverse of the matrix
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not invertible. Use [`Mat2f::try_inverse`] when that can happen.
+    pub fn inverse(&self) -> Self {
+        self.try_inverse().expect("matrix is not invertible")
+    }
+
+    /// Multiplies the vector `rhs` by the matrix
+    pub fn mul_vec2(&self, rhs: Vec2f) -> Vec2f {
+        self.x_axis * rhs.x + self.y_axis * rhs.y
+    }
+
+    /// Multiplies the matrix `rhs` by `self`
+    pub fn mul_mat2(&self, rhs: &Self) -> Self {
+        Self::from_cols(self.mul_vec2(rhs.x_axis), self.mul_vec2(rhs.y_axis))
+    }
+
+    /// Creates a matrix that scales by `scale` and then rotates counter-clockwise by `angle`
+    /// radians
+    pub fn from_scale_angle(scale: Vec2f, angle: impl Into<Radians<f32>>) -> Self {
+        Self::from_angle(angle) * Self::from_scale(scale)
+    }
+
+    /// Splits the matrix into a scale followed by a counter-clockwise rotation in radians, the
+    /// inverse of [`Mat2f::from_scale_angle`]
+    ///
+    /// A matrix with a negative determinant is reported with a negative x scale.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Degenerate`] if a column has zero length and [`Error::Shear`] if the
+    /// columns are not perpendicular.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat2::Mat2f;
+    /// use yavml::vec2::Vec2f;
+    /// let matrix = Mat2f::from_scale_angle(Vec2f::new(-2.0, 3.0), 0.5);
+    /// let (scale, angle) = matrix.to_scale_angle().unwrap();
+    /// assert!((scale - Vec2f::new(-2.0, 3.0)).length() < 1e-5);
+    /// assert!((angle - 0.5).abs() < 1e-5);
+    /// ```
+    pub fn to_scale_angle(&self) -> Result<(Vec2f, f32), Error> {
+        let mut scale = Vec2f::new(self.x_axis.length() as f32, self.y_axis.length() as f32);
+        if !(scale.x > 0.0 && scale.y > 0.0 && scale.x.is_finite() && scale.y.is_finite()) {
+            return Err(Error::Degenerate);
+        }
+        if (self.x_axis.dot(self.y_axis) / (scale.x * scale.y)).abs() > SHEAR_EPSILON {
+            return Err(Error::Shear);
+        }
+        if self.determinant() < 0.0 {
+            scale.x = -scale.x;
+        }
+        let x_axis = self.x_axis / scale.x;
+        Ok((scale, x_axis.y.atan2(x_axis.x)))
+    }
+
+    /// Returns the eigenvalues and eigenvectors of the symmetric matrix
+    ///
+    /// The eigenvalues are sorted in descending order. The eigenvectors are the matching columns
+    /// of the returned matrix, which is always a rotation. Only the symmetric part of the matrix is
+    /// used, so the off-diagonal elements are averaged.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat2::Mat2f;
+    /// use yavml::vec2::Vec2f;
+    /// let matrix = Mat2f::from_cols_array([2.0, 1.0, 1.0, 2.0]);
+    /// let (values, vectors) = matrix.symmetric_eigen();
+    /// assert!((values - Vec2f::new(3.0, 1.0)).length() < 1e-5);
+    /// assert!((matrix * vectors.x_axis - vectors.x_axis * values.x).length() < 1e-5);
+    /// ```
+    pub fn symmetric_eigen(&self) -> (Vec2f, Self) {
+        let (a, b, d) = (
+            self.x_axis.x,
+            (self.x_axis.y + self.y_axis.x) * 0.5,
+            self.y_axis.y,
+        );
+        let (mut values, mut vectors) = if b == 0.0 {
+            (Vec2f::new(a, d), Self::IDENTITY)
+        } else {
+            let (cos, sin, tan) = jacobi_rotation(a, d, b);
+            (
+                Vec2f::new(a - tan * b, d + tan * b),
+                Self::from_cols(Vec2f::new(cos, -sin), Vec2f::new(sin, cos)),
+            )
+        };
+        if values.x < values.y {
+            values = Vec2f::new(values.y, values.x);
+            vectors = Self::from_cols(vectors.y_axis, -vectors.x_axis);
+        }
+        (values, vectors)
+    }
+
+    /// Splits the matrix into a rotation `Q` and an upper triangular matrix `R` such that
+    /// `self == Q * R`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat2::Mat2f;
+    /// let matrix = Mat2f::from_cols_array([3.0, 4.0, 1.0, 2.0]);
+    /// let (q, r) = matrix.qr();
+    /// assert_eq!(r.x_axis.y, 0.0);
+    /// assert!((q * r).abs_diff_eq(matrix, 1e-5));
+    /// ```
+    pub fn qr(&self) -> (Self, Self) {
+        let len = self.x_axis.x.hypot(self.x_axis.y);
+        if len == 0.0 {
+            return (Self::IDENTITY, *self);
+        }
+        let (cos, sin) = (self.x_axis.x / len, self.x_axis.y / len);
+        let q = Self::from_cols(Vec2f::new(cos, sin), Vec2f::new(-sin, cos));
+        let mut r = q.transpose() * *self;
+        r.x_axis = Vec2f::new(len, 0.0);
+        (q, r)
+    }
+
+    /// Returns the singular value decomposition `(U, S, V)` of the matrix, such that
+    /// `self == U * Mat2f::from_scale(S) * V.transpose()`
+    ///
+    /// The singular values are non-negative and sorted in descending order. `U` and `V` are
+    /// orthogonal, and `U` holds a reflection when the determinant is negative. The closed form
+    /// keeps the smallest singular value accurate relative to its own size.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat2::Mat2f;
+    /// let matrix = Mat2f::from_cols_array([1.0, 0.0, 1e3, 1.0]);
+    /// let (u, s, v) = matrix.svd();
+    /// assert!((s.x * s.y - 1.0).abs() < 1e-5);
+    /// assert!((u * Mat2f::from_scale(s) * v.transpose()).abs_diff_eq(matrix, 1e-3));
+    /// ```
+    pub fn svd(&self) -> (Self, Vec2f, Self) {
+        let e = (self.x_axis.x + self.y_axis.y) * 0.5;
+        let f = (self.x_axis.x - self.y_axis.y) * 0.5;
+        let g = (self.x_axis.y + self.y_axis.x) * 0.5;
+        let h = (self.x_axis.y - self.y_axis.x) * 0.5;
+        let (q, r) = (e.hypot(h), f.hypot(g));
+        let s0 = q + r;
+        let mut s1 = if s0 == 0.0 {
+            0.0
+        } else {
+            self.determinant() / s0
+        };
+        let (a1, a2) = (g.atan2(f), h.atan2(e));
+        let mut u = Self::from_angle((a2 + a1) * 0.5);
+        let v = Self::from_angle((a1 - a2) * 0.5);
+        if s1 < 0.0 {
+            s1 = -s1;
+            u.y_axis = -u.y_axis;
+        }
+        (u, Vec2f::new(s0, s1), v)
+    }
+
+    /// Returns `true` if every element of `self` and `rhs` differs by at most `max_abs_diff`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat2::Mat2f;
+    /// let matrix = Mat2f::from_angle(1.0) * Mat2f::from_angle(-1.0);
+    /// assert!(matrix.abs_diff_eq(Mat2f::IDENTITY, 1e-6));
+    /// ```
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.to_cols_array()
+            .iter()
+            .zip(rhs.to_cols_array().iter())
+            .all(|(a, b)| (a - b).abs() <= max_abs_diff)
+    }
+}
+
+impl Default for Mat2f {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Addition of matrices
+impl Add<Mat2f> for Mat2f {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_cols(self.x_axis + rhs.x_axis, self.y_axis + rhs.y_axis)
+    }
+}
+
+/// Subtraction of matrices
+impl Sub<Mat2f> for Mat2f {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_cols(self.x_axis - rhs.x_axis, self.y_axis - rhs.y_axis)
+    }
+}
+
+/// Multiplication of matrices
+impl Mul<Mat2f> for Mat2f {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_mat2(&rhs)
+    }
+}
+
+/// Multiplication assignment of matrices
+impl MulAssign<Mat2f> for Mat2f {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_mat2(&rhs);
+    }
+}
+
+/// Multiplication of a vector by a matrix
+impl Mul<Vec2f> for Mat2f {
+    type Output = Vec2f;
+
+    fn mul(self, rhs: Vec2f) -> Self::Output {
+        self.mul_vec2(rhs)
+    }
+}
+
+/// Multiplication of a matrix by an `f32`
+impl Mul<f32> for Mat2f {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self::from_cols(self.x_axis * rhs, self.y_axis * rhs)
+    }
+}
+
+/// Returns the cosine, sine and tangent of the Jacobi rotation that zeroes the off-diagonal
+/// element `b` of the symmetric matrix with diagonal `a` and `d`
+fn jacobi_rotation(a: f32, d: f32, b: f32) -> (f32, f32, f32) {
+    let theta = (d - a) / (2.0 * b);
+    let tan = 1.0_f32.copysign(theta) / (theta.abs() + theta.hypot(1.0));
+    let cos = 1.0 / tan.hypot(1.0);
+    (cos, tan * cos, tan)
+}
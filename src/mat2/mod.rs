@@ -0,0 +1,8 @@
+pub mod mat2f;
+pub use mat2f::Mat2f;
+
+pub mod mat2d;
+pub use mat2d::Mat2d;
+
+#[cfg(test)]
+mod tests;
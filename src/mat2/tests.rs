@@ -0,0 +1,252 @@
+use super::Mat2d;
+use super::Mat2f;
+use crate::vec2::Vec2d;
+use crate::vec2::Vec2f;
+
+mod mat2f_tests {
+    use super::Mat2f;
+    use super::Vec2f;
+    use crate::Error;
+    use core::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    // Tests that the identity matrix leaves vectors unchanged
+    #[test]
+    fn test_mat2f_identity() {
+        let vec = Vec2f::new(3.0, -4.0);
+        assert_eq!(Mat2f::IDENTITY * vec, vec);
+        assert_eq!(Mat2f::default(), Mat2f::IDENTITY);
+    }
+
+    // Tests converting a `Mat2f` to and from a column-major array
+    #[test]
+    fn test_mat2f_cols_array() {
+        let arr = [1.0, 2.0, 3.0, 4.0];
+        let matrix = Mat2f::from_cols_array(arr);
+        assert_eq!(matrix.x_axis, Vec2f::new(1.0, 2.0));
+        assert_eq!(matrix.y_axis, Vec2f::new(3.0, 4.0));
+        assert_eq!(matrix.to_cols_array(), arr);
+    }
+
+    // Tests rotating a vector by a quarter turn
+    #[test]
+    fn test_mat2f_from_angle() {
+        let rotated = Mat2f::from_angle(FRAC_PI_2) * Vec2f::new(2.0, 0.0);
+        assert!((rotated - Vec2f::new(0.0, 2.0)).length() < 1e-6);
+        assert!((Mat2f::from_angle(FRAC_PI_4).determinant() - 1.0).abs() < 1e-6);
+    }
+
+    // Tests the determinant of a shear and a scale
+    #[test]
+    fn test_mat2f_determinant() {
+        let shear = Mat2f::from_cols(Vec2f::new(1.0, 0.0), Vec2f::new(5.0, 1.0));
+        assert_eq!(shear.determinant(), 1.0);
+        assert_eq!(Mat2f::from_scale(Vec2f::new(2.0, -3.0)).determinant(), -6.0);
+    }
+
+    // Tests that a matrix times its inverse is the identity
+    #[test]
+    fn test_mat2f_inverse() {
+        let matrix = Mat2f::from_cols_array([4.0, 2.0, 7.0, 6.0]);
+        assert!((matrix * matrix.inverse()).abs_diff_eq(Mat2f::IDENTITY, 1e-6));
+        assert!((matrix.inverse() * matrix).abs_diff_eq(Mat2f::IDENTITY, 1e-6));
+    }
+
+    // Tests that singular matrices have no inverse
+    #[test]
+    fn test_mat2f_try_inverse_singular() {
+        let singular = Mat2f::from_cols(Vec2f::new(1.0, 2.0), Vec2f::new(2.0, 4.0));
+        assert_eq!(singular.try_inverse(), None);
+        assert_eq!(Mat2f::ZERO.try_inverse(), None);
+    }
+
+    // Tests that the inverse of a rotation is its transpose
+    #[test]
+    fn test_mat2f_rotation_inverse_is_transpose() {
+        let rotation = Mat2f::from_angle(0.7);
+        assert!(rotation.inverse().abs_diff_eq(rotation.transpose(), 1e-6));
+    }
+
+    // Tests that matrix multiplication composes transformations right to left
+    #[test]
+    fn test_mat2f_multiplication() {
+        let scale = Mat2f::from_scale(Vec2f::new(2.0, 1.0));
+        let rotation = Mat2f::from_angle(FRAC_PI_2);
+        let vec = Vec2f::new(1.0, 0.0);
+        let composed = (scale * rotation) * vec;
+        assert!((composed - scale * (rotation * vec)).length() < 1e-6);
+        assert!((composed - Vec2f::new(0.0, 1.0)).length() < 1e-6);
+
+        let mut assigned = scale;
+        assigned *= rotation;
+        assert_eq!(assigned, scale * rotation);
+    }
+
+    // Tests addition, subtraction and scalar multiplication of matrices
+    #[test]
+    fn test_mat2f_arithmetic() {
+        let a = Mat2f::from_cols_array([1.0, 2.0, 3.0, 4.0]);
+        let b = Mat2f::from_cols_array([4.0, 3.0, 2.0, 1.0]);
+        assert_eq!(a + b, Mat2f::from_cols_array([5.0; 4]));
+        assert_eq!(a - a, Mat2f::ZERO);
+        assert_eq!(a * 2.0, a + a);
+    }
+
+    // Tests approximate equality of matrices
+    #[test]
+    fn test_mat2f_abs_diff_eq() {
+        let a = Mat2f::IDENTITY;
+        let b = Mat2f::from_cols_array([1.0, 0.0, 0.001, 1.0]);
+        assert!(a.abs_diff_eq(b, 0.01));
+        assert!(!a.abs_diff_eq(b, 0.0001));
+    }
+
+    // Tests splitting a matrix into scale and angle, including a reflection
+    #[test]
+    fn test_mat2f_to_scale_angle() {
+        for (scale, angle) in [(Vec2f::new(2.0, 3.0), 0.4), (Vec2f::new(-1.5, 0.5), -2.0)] {
+            let matrix = Mat2f::from_scale_angle(scale, angle);
+            let (out_scale, out_angle) = matrix.to_scale_angle().unwrap();
+            assert!((out_scale - scale).length() < 1e-5);
+            assert!((out_angle - angle).abs() < 1e-5);
+            assert!(Mat2f::from_scale_angle(out_scale, out_angle).abs_diff_eq(matrix, 1e-5));
+        }
+    }
+
+    // Tests that sheared and degenerate matrices are reported
+    #[test]
+    fn test_mat2f_to_scale_angle_errors() {
+        let shear = Mat2f::from_cols(Vec2f::new(1.0, 0.0), Vec2f::new(0.5, 1.0));
+        assert_eq!(shear.to_scale_angle(), Err(Error::Shear));
+        let degenerate = Mat2f::from_scale(Vec2f::new(1.0, 0.0));
+        assert_eq!(degenerate.to_scale_angle(), Err(Error::Degenerate));
+    }
+
+    // Tests the eigen, QR and singular value decompositions in single precision
+    #[test]
+    fn test_mat2f_decompositions() {
+        let matrix = Mat2f::from_cols_array([4.0, 1.0, 1.0, -2.0]);
+        let (values, vectors) = matrix.symmetric_eigen();
+        assert!((matrix * vectors.x_axis - vectors.x_axis * values.x).length() < 1e-5);
+        assert!((matrix * vectors.y_axis - vectors.y_axis * values.y).length() < 1e-5);
+        let (q, r) = matrix.qr();
+        assert!((q * r).abs_diff_eq(matrix, 1e-5));
+        let (u, s, v) = matrix.svd();
+        assert!((u * Mat2f::from_scale(s) * v.transpose()).abs_diff_eq(matrix, 1e-5));
+    }
+}
+
+mod mat2d_tests {
+    use super::Mat2d;
+    use super::Vec2d;
+    use core::f64::consts::FRAC_PI_2;
+
+    // Tests rotating a vector by a quarter turn
+    #[test]
+    fn test_mat2d_from_angle() {
+        let rotated = Mat2d::from_angle(FRAC_PI_2) * Vec2d::new(2.0, 0.0);
+        assert!((rotated - Vec2d::new(0.0, 2.0)).length() < 1e-12);
+    }
+
+    // Tests that a matrix times its inverse is the identity
+    #[test]
+    fn test_mat2d_inverse() {
+        let matrix = Mat2d::from_cols_array([4.0, 2.0, 7.0, 6.0]);
+        assert!((matrix * matrix.inverse()).abs_diff_eq(Mat2d::IDENTITY, 1e-12));
+        assert_eq!(Mat2d::ZERO.try_inverse(), None);
+    }
+
+    // Tests the transpose and determinant of a `Mat2d`
+    #[test]
+    fn test_mat2d_transpose_determinant() {
+        let matrix = Mat2d::from_cols_array([1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(
+            matrix.transpose(),
+            Mat2d::from_cols_array([1.0, 3.0, 2.0, 4.0])
+        );
+        assert_eq!(matrix.determinant(), matrix.transpose().determinant());
+    }
+
+    /// Asserts that `matrix` has orthonormal columns
+    fn assert_orthonormal(matrix: Mat2d) {
+        assert!((matrix.transpose() * matrix).abs_diff_eq(Mat2d::IDENTITY, 1e-14));
+    }
+
+    // Tests that eigenvectors satisfy `A * v = l * v` and are returned as a rotation
+    #[test]
+    fn test_mat2d_symmetric_eigen() {
+        let matrix = Mat2d::from_cols_array([4.0, -1.5, -1.5, -2.0]);
+        let (values, vectors) = matrix.symmetric_eigen();
+        assert!(values.x >= values.y);
+        assert!((matrix * vectors.x_axis - vectors.x_axis * values.x).length() < 1e-14);
+        assert!((matrix * vectors.y_axis - vectors.y_axis * values.y).length() < 1e-14);
+        assert!((vectors.determinant() - 1.0).abs() < 1e-14);
+        assert_orthonormal(vectors);
+        assert!((values.x + values.y - 2.0).abs() < 1e-14);
+    }
+
+    // Tests that diagonal input is sorted and nearly equal eigenvalues stay distinct
+    #[test]
+    fn test_mat2d_symmetric_eigen_ill_conditioned() {
+        let (values, vectors) = Mat2d::from_scale(Vec2d::new(-1.0, 3.0)).symmetric_eigen();
+        assert_eq!(values, Vec2d::new(3.0, -1.0));
+        assert_eq!(vectors.x_axis, Vec2d::new(0.0, 1.0));
+        assert_eq!(vectors.determinant(), 1.0);
+
+        let matrix = Mat2d::from_cols_array([1.0, 1e-12, 1e-12, 1.0]);
+        let (values, vectors) = matrix.symmetric_eigen();
+        assert!(((values.x - values.y) / 2e-12 - 1.0).abs() < 1e-3);
+        assert!((vectors.x_axis.x.abs() - core::f64::consts::FRAC_1_SQRT_2).abs() < 1e-14);
+    }
+
+    // Tests that QR factors reproduce the input, including a zero first column
+    #[test]
+    fn test_mat2d_qr() {
+        let matrix = Mat2d::from_cols_array([3.0, -4.0, 2.0, 7.0]);
+        let (q, r) = matrix.qr();
+        assert_eq!(r.x_axis, Vec2d::new(5.0, 0.0));
+        assert!((q * r).abs_diff_eq(matrix, 1e-14));
+        assert_orthonormal(q);
+
+        let matrix = Mat2d::from_cols_array([0.0, 0.0, 1.0, 2.0]);
+        assert_eq!(matrix.qr(), (Mat2d::IDENTITY, matrix));
+    }
+
+    // Tests that the singular value decomposition reproduces general and reflecting input
+    #[test]
+    fn test_mat2d_svd() {
+        for matrix in [
+            Mat2d::from_cols_array([3.0, -4.0, 2.0, 7.0]),
+            Mat2d::from_cols_array([1.0, 2.0, 3.0, -1.0]),
+            Mat2d::from_scale(Vec2d::new(1.0, -1.0)),
+            Mat2d::from_angle(2.5),
+        ] {
+            let (u, s, v) = matrix.svd();
+            assert!(s.x >= s.y && s.y >= 0.0);
+            assert!((u * Mat2d::from_scale(s) * v.transpose()).abs_diff_eq(matrix, 1e-14));
+            assert_orthonormal(u);
+            assert_orthonormal(v);
+            assert!((s.x * s.y - matrix.determinant().abs()).abs() < 1e-13);
+        }
+    }
+
+    // Tests that the smallest singular value of ill-conditioned and singular input is accurate
+    #[test]
+    fn test_mat2d_svd_ill_conditioned() {
+        let matrix = Mat2d::from_cols_array([1.0, 0.0, 1e8, 1.0]);
+        let (u, s, v) = matrix.svd();
+        assert!((s.x / 1e8 - 1.0).abs() < 1e-14);
+        assert!((s.x * s.y - 1.0).abs() < 1e-14);
+        assert!((u * Mat2d::from_scale(s) * v.transpose()).abs_diff_eq(matrix, 1e-7));
+
+        let (u, s, v) = Mat2d::from_cols_array([1.0, 2.0, 2.0, 4.0]).svd();
+        assert!((s.x - 5.0).abs() < 1e-14);
+        assert_eq!(s.y, 0.0);
+        assert_orthonormal(u);
+        assert_orthonormal(v);
+
+        let (u, s, v) = Mat2d::ZERO.svd();
+        assert_eq!(s, Vec2d::ZERO);
+        assert_orthonormal(u);
+        assert_orthonormal(v);
+    }
+}
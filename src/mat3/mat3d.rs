@@ -0,0 +1,652 @@
+use core::ops::*;
+
+use crate::angle::Radians;
+use crate::error::Error;
+use crate::mat2::Mat2d;
+use crate::vec2::Vec2d;
+use crate::vec3::Vec3d;
+
+/// The largest cosine allowed between two axes before they are considered sheared
+const SHEAR_EPSILON: f64 = 1e-8;
+
+/// The pairs of axes visited in each sweep of a Jacobi iteration
+const JACOBI_PAIRS: [(usize, usize); 3] = [(0, 1), (0, 2), (1, 2)];
+
+/// The number of sweeps after which a Jacobi iteration stops even if it has not converged
+const JACOBI_MAX_SWEEPS: usize = 32;
+
+/// A 3x3 column-major matrix of `f64` values
+///
+/// Besides general 3x3 linear algebra, the matrix doubles as a 2D affine transform where
+/// `x_axis` and `y_axis` hold the linear part and `z_axis` holds the translation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat3d {
+    /// The first column of the matrix
+    pub x_axis: Vec3d,
+    /// The second column of the matrix
+    pub y_axis: Vec3d,
+    /// The third column of the matrix
+    pub z_axis: Vec3d,
+}
+
+impl Mat3d {
+    /// Matrix of all zeros
+    pub const ZERO: Self = Self::from_cols(Vec3d::ZERO, Vec3d::ZERO, Vec3d::ZERO);
+    /// The identity matrix
+    pub const IDENTITY: Self = Self::from_cols(Vec3d::X, Vec3d::Y, Vec3d::Z);
+
+    /// Creates a new matrix from its three columns
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat3::Mat3d;
+    /// use yavml::vec3::Vec3d;
+    /// let matrix = Mat3d::from_cols(Vec3d::X, Vec3d::Y, Vec3d::Z);
+    /// assert_eq!(matrix, Mat3d::IDENTITY);
+    /// ```
+    pub const fn from_cols(x_axis: Vec3d, y_axis: Vec3d, z_axis: Vec3d) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+        }
+    }
+
+    /// Creates a new matrix from a column-major array
+    pub const fn from_cols_array(arr: [f64; 9]) -> Self {
+        Self::from_cols(
+            Vec3d::new(arr[0], arr[1], arr[2]),
+            Vec3d::new(arr[3], arr[4], arr[5]),
+            Vec3d::new(arr[6], arr[7], arr[8]),
+        )
+    }
+
+    /// Create a column-major array from the matrix
+    pub const fn to_cols_array(&self) -> [f64; 9] {
+        [
+            self.x_axis.x,
+            self.x_axis.y,
+            self.x_axis.z,
+            self.y_axis.x,
+            self.y_axis.y,
+            self.y_axis.z,
+            self.z_axis.x,
+            self.z_axis.y,
+            self.z_axis.z,
+        ]
+    }
+
+    /// Creates a matrix with `diagonal` along its diagonal and zeros everywhere else
+    pub const fn from_diagonal(diagonal: Vec3d) -> Self {
+        Self::from_cols(
+            Vec3d::new(diagonal.x, 0.0, 0.0),
+            Vec3d::new(0.0, diagonal.y, 0.0),
+            Vec3d::new(0.0, 0.0, diagonal.z),
+        )
+    }
+
+    /// Creates a matrix whose upper-left 2x2 block is `mat` and is otherwise the identity
+    pub const fn from_mat2(mat: Mat2d) -> Self {
+        Self::from_cols(
+            Vec3d::new(mat.x_axis.x, mat.x_axis.y, 0.0),
+            Vec3d::new(mat.y_axis.x, mat.y_axis.y, 0.0),
+            Vec3d::Z,
+        )
+    }
+
+    /// Returns the upper-left 2x2 block of the matrix
+    pub const fn to_mat2(&self) -> Mat2d {
+        Mat2d::from_cols(
+            Vec2d::new(self.x_axis.x, self.x_axis.y),
+            Vec2d::new(self.y_axis.x, self.y_axis.y),
+        )
+    }
+
+    /// Creates a 2D affine transform that translates by `translation`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat3::Mat3d;
+    /// use yavml::vec2::Vec2d;
+    /// let matrix = Mat3d::from_translation(Vec2d::new(1.0, 2.0));
+    /// assert_eq!(matrix.transform_point2(Vec2d::ONE), Vec2d::new(2.0, 3.0));
+    /// assert_eq!(matrix.transform_vector2(Vec2d::ONE), Vec2d::ONE);
+    /// ```
+    pub const fn from_translation(translation: Vec2d) -> Self {
+        Self::from_cols(
+            Vec3d::X,
+            Vec3d::Y,
+            Vec3d::new(translation.x, translation.y, 1.0),
+        )
+    }
+
+    /// Creates a 2D affine transform that rotates counter-clockwise by `angle` radians
+    pub fn from_rotation(angle: impl Into<Radians<f64>>) -> Self {
+        Self::from_mat2(Mat2d::from_angle(angle))
+    }
+
+    /// Creates a 2D affine transform that scales along the x and y axes by `scale`
+    pub const fn from_scale(scale: Vec2d) -> Self {
+        Self::from_diagonal(Vec3d::new(scale.x, scale.y, 1.0))
+    }
+
+    /// Creates a 2D affine transform that scales by `scale`, then rotates by `angle` radians and
+    /// then translates by `translation`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat3::Mat3d;
+    /// use yavml::vec2::Vec2d;
+    /// let matrix = Mat3d::from_scale_angle_translation(
+    ///     Vec2d::splat(2.0),
+    ///     core::f64::consts::FRAC_PI_2,
+    ///     Vec2d::new(10.0, 0.0),
+    /// );
+    /// let point = matrix.transform_point2(Vec2d::new(1.0, 0.0));
+    /// assert!((point - Vec2d::new(10.0, 2.0)).length() < 1e-6);
+    /// ```
+    pub fn from_scale_angle_translation(
+        scale: Vec2d,
+        angle: impl Into<Radians<f64>>,
+        translation: Vec2d,
+    ) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        Self::from_cols(
+            Vec3d::new(cos * scale.x, sin * scale.x, 0.0),
+            Vec3d::new(-sin * scale.y, cos * scale.y, 0.0),
+            Vec3d::new(translation.x, translation.y, 1.0),
+        )
+    }
+
+    /// Creates a 3D rotation of `angle` radians around the normalized `axis`
+    ///
+    /// The rotation is counter-clockwise when looking down `axis` towards the origin.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat3::Mat3d;
+    /// use yavml::vec3::Vec3d;
+    /// let matrix = Mat3d::from_axis_angle(Vec3d::Z, core::f64::consts::FRAC_PI_2);
+    /// assert!((matrix * Vec3d::X - Vec3d::Y).length() < 1e-6);
+    /// ```
+    pub fn from_axis_angle(axis: Vec3d, angle: impl Into<Radians<f64>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        let Vec3d { x, y, z } = axis;
+        let t = 1.0 - cos;
+        Self::from_cols(
+            Vec3d::new(t * x * x + cos, t * x * y + sin * z, t * x * z - sin * y),
+            Vec3d::new(t * x * y - sin * z, t * y * y + cos, t * y * z + sin * x),
+            Vec3d::new(t * x * z + sin * y, t * y * z - sin * x, t * z * z + cos),
+        )
+    }
+
+    /// Creates a 3D rotation of `angle` radians around the x axis
+    pub fn from_rotation_x(angle: impl Into<Radians<f64>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        Self::from_cols(
+            Vec3d::X,
+            Vec3d::new(0.0, cos, sin),
+            Vec3d::new(0.0, -sin, cos),
+        )
+    }
+
+    /// Creates a 3D rotation of `angle` radians around the y axis
+    pub fn from_rotation_y(angle: impl Into<Radians<f64>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        Self::from_cols(
+            Vec3d::new(cos, 0.0, -sin),
+            Vec3d::Y,
+            Vec3d::new(sin, 0.0, cos),
+        )
+    }
+
+    /// Creates a 3D rotation of `angle` radians around the z axis
+    pub fn from_rotation_z(angle: impl Into<Radians<f64>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        Self::from_cols(
+            Vec3d::new(cos, sin, 0.0),
+            Vec3d::new(-sin, cos, 0.0),
+            Vec3d::Z,
+        )
+    }
+
+    /// Returns the first row of the matrix
+    pub const fn row0(&self) -> Vec3d {
+        Vec3d::new(self.x_axis.x, self.y_axis.x, self.z_axis.x)
+    }
+
+    /// Returns the second row of the matrix
+    pub const fn row1(&self) -> Vec3d {
+        Vec3d::new(self.x_axis.y, self.y_axis.y, self.z_axis.y)
+    }
+
+    /// Returns the third row of the matrix
+    pub const fn row2(&self) -> Vec3d {
+        Vec3d::new(self.x_axis.z, self.y_axis.z, self.z_axis.z)
+    }
+
+    /// Returns the determinant of the matrix
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat3::Mat3d;
+    /// use yavml::vec3::Vec3d;
+    /// assert_eq!(Mat3d::from_diagonal(Vec3d::new(2.0, 3.0, 4.0)).determinant(), 24.0);
+    /// ```
+    pub fn determinant(&self) -> f64 {
+        self.x_axis.dot(self.y_axis.cross(self.z_axis))
+    }
+
+    /// Returns the transpose of the matrix
+    pub const fn transpose(&self) -> Self {
+        Self::from_cols(self.row0(), self.row1(), self.row2())
+    }
+
+    /// Returns the inverse of the matrix, or `None` if the matrix is not invertible
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat3::Mat3d;
+    /// use yavml::vec2::Vec2d;
+    /// let matrix = Mat3d::from_translation(Vec2d::new(1.0, 2.0));
+    /// assert_eq!(matrix.try_inverse(), Some(Mat3d::from_translation(Vec2d::new(-1.0, -2.0))));
+    /// assert_eq!(Mat3d::ZERO.try_inverse(), None);
+    /// ```
+    pub fn try_inverse(&self) -> Option<Self> {
+        let cross_yz = self.y_axis.cross(self.z_axis);
+        let det = self.x_axis.dot(cross_yz);
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let inv_det = 1.0 / det;
+        let cross_zx = self.z_axis.cross(self.x_axis);
+        let cross_xy = self.x_axis.cross(self.y_axis);
+        Some(
+            Self::from_cols(cross_yz * inv_det, cross_zx * inv_det, cross_xy * inv_det).transpose(),
+        )
+    }
+
+    /// Returns the inverse of the matrix
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not invertible. Use [`Mat3d::try_inverse`] when that can happen.
+    pub fn inverse(&self) -> Self {
+        self.try_inverse().expect("matrix is not invertible")
+    }
+
+    /// Transforms `point` as a 2D point, treating it as having a `z` of `1`
+    ///
+    /// The matrix is assumed to be a 2D affine transform, so the bottom row is ignored.
+    pub fn transform_point2(&self, point: Vec2d) -> Vec2d {
+        Vec2d::new(
+            self.x_axis.x * point.x + self.y_axis.x * point.y + self.z_axis.x,
+            self.x_axis.y * point.x + self.y_axis.y * point.y + self.z_axis.y,
+        )
+    }
+
+    /// Transforms `vector` as a 2D direction, treating it as having a `z` of `0`
+    ///
+    /// Translation is not applied to directions.
+    pub fn transform_vector2(&self, vector: Vec2d) -> Vec2d {
+        Vec2d::new(
+            self.x_axis.x * vector.x + self.y_axis.x * vector.y,
+            self.x_axis.y * vector.x + self.y_axis.y * vector.y,
+        )
+    }
+
+    /// Multiplies the vector `rhs` by the matrix
+    pub fn mul_vec3(&self, rhs: Vec3d) -> Vec3d {
+        self.x_axis * rhs.x + self.y_axis * rhs.y + self.z_axis * rhs.z
+    }
+
+    /// Multiplies the matrix `rhs` by `self`
+    pub fn mul_mat3(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.mul_vec3(rhs.x_axis),
+            self.mul_vec3(rhs.y_axis),
+            self.mul_vec3(rhs.z_axis),
+        )
+    }
+
+    /// Splits the matrix, treated as a 2D affine transform, into the arguments of
+    /// [`Mat3d::from_scale_angle_translation`]
+    ///
+    /// A matrix with a negative determinant is reported with a negative x scale.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Projective`] if the bottom row is not `(0, 0, 1)`, [`Error::Degenerate`]
+    /// if an axis has zero length and [`Error::Shear`] if the axes are not perpendicular.
+    pub fn to_scale_angle_translation(&self) -> Result<(Vec2d, f64, Vec2d), Error> {
+        if self.row2() != Vec3d::Z {
+            return Err(Error::Projective);
+        }
+        let (scale, angle) = self.to_mat2().to_scale_angle()?;
+        Ok((scale, angle, Vec2d::new(self.z_axis.x, self.z_axis.y)))
+    }
+
+    /// Creates a 3D linear transform that scales by `scale` and then applies the pure rotation
+    /// `rotation`
+    pub fn from_scale_rotation(scale: Vec3d, rotation: Mat3d) -> Self {
+        Self::from_cols(
+            rotation.x_axis * scale.x,
+            rotation.y_axis * scale.y,
+            rotation.z_axis * scale.z,
+        )
+    }
+
+    /// Splits the matrix, treated as a 3D linear transform, into a scale followed by a pure
+    /// rotation, the inverse of [`Mat3d::from_scale_rotation`]
+    ///
+    /// A matrix with a negative determinant is reported with a negative x scale, so the returned
+    /// rotation never contains a reflection.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Degenerate`] if a column has zero length and [`Error::Shear`] if the
+    /// columns are not perpendicular.
+    pub fn to_scale_rotation(&self) -> Result<(Vec3d, Self), Error> {
+        let mut scale = Vec3d::new(
+            self.x_axis.length(),
+            self.y_axis.length(),
+            self.z_axis.length(),
+        );
+        if !scale.to_array().iter().all(|s| *s > 0.0 && s.is_finite()) {
+            return Err(Error::Degenerate);
+        }
+        let x_axis = self.x_axis / scale.x;
+        let y_axis = self.y_axis / scale.y;
+        let z_axis = self.z_axis / scale.z;
+        if x_axis.dot(y_axis).abs() > SHEAR_EPSILON
+            || y_axis.dot(z_axis).abs() > SHEAR_EPSILON
+            || z_axis.dot(x_axis).abs() > SHEAR_EPSILON
+        {
+            return Err(Error::Shear);
+        }
+        if x_axis.dot(y_axis.cross(z_axis)) < 0.0 {
+            scale.x = -scale.x;
+            return Ok((scale, Self::from_cols(-x_axis, y_axis, z_axis)));
+        }
+        Ok((scale, Self::from_cols(x_axis, y_axis, z_axis)))
+    }
+
+    /// Returns the eigenvalues and eigenvectors of the symmetric matrix
+    ///
+    /// The eigenvalues are sorted in descending order. The eigenvectors are the matching columns
+    /// of the returned matrix, which is always a rotation. The decomposition uses cyclic Jacobi
+    /// iteration, which keeps small eigenvalues accurate. Only the symmetric part of the matrix is
+    /// used, so the off-diagonal elements are averaged.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat3::Mat3d;
+    /// use yavml::vec3::Vec3d;
+    /// let matrix = Mat3d::from_cols_array([2.0, 1.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 5.0]);
+    /// let (values, vectors) = matrix.symmetric_eigen();
+    /// assert!((values - Vec3d::new(5.0, 3.0, 1.0)).length() < 1e-12);
+    /// assert!((matrix * vectors.y_axis - vectors.y_axis * values.y).length() < 1e-12);
+    /// ```
+    pub fn symmetric_eigen(&self) -> (Vec3d, Self) {
+        let mut a = self.to_cols();
+        for (p, q) in JACOBI_PAIRS {
+            let off = (a[p][q] + a[q][p]) * 0.5;
+            a[p][q] = off;
+            a[q][p] = off;
+        }
+        let mut v = Self::IDENTITY.to_cols();
+        for _ in 0..JACOBI_MAX_SWEEPS {
+            let mut rotated = false;
+            for (p, q) in JACOBI_PAIRS {
+                if a[q][p].abs() <= f64::EPSILON * (a[p][p] * a[q][q]).abs().sqrt() {
+                    a[q][p] = 0.0;
+                    a[p][q] = 0.0;
+                }
+                if a[q][p] == 0.0 {
+                    continue;
+                }
+                rotated = true;
+                let (cos, sin, _) = jacobi_rotation(a[p][p], a[q][q], a[q][p]);
+                rotate_cols(&mut a, p, q, cos, sin);
+                for col in a.iter_mut() {
+                    let (x, y) = (col[p], col[q]);
+                    col[p] = cos * x - sin * y;
+                    col[q] = sin * x + cos * y;
+                }
+                a[q][p] = 0.0;
+                a[p][q] = 0.0;
+                rotate_cols(&mut v, p, q, cos, sin);
+            }
+            if !rotated {
+                break;
+            }
+        }
+        let values = [a[0][0], a[1][1], a[2][2]];
+        let mut order = [0, 1, 2];
+        order.sort_by(|i, j| values[*j].total_cmp(&values[*i]));
+        let mut vectors = Self::from_cols(
+            Vec3d::from_arr(v[order[0]]),
+            Vec3d::from_arr(v[order[1]]),
+            Vec3d::from_arr(v[order[2]]),
+        );
+        if vectors.determinant() < 0.0 {
+            vectors.z_axis = -vectors.z_axis;
+        }
+        (Vec3d::from_arr(order.map(|i| values[i])), vectors)
+    }
+
+    /// Splits the matrix into a rotation `Q` and an upper triangular matrix `R` such that
+    /// `self == Q * R`
+    ///
+    /// The decomposition uses Givens rotations, so `Q` is orthogonal to working precision even
+    /// for nearly singular matrices.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat3::Mat3d;
+    /// let matrix = Mat3d::from_cols_array([1.0, 2.0, 2.0, 0.0, 1.0, 3.0, 4.0, 1.0, 0.0]);
+    /// let (q, r) = matrix.qr();
+    /// assert_eq!([r.x_axis.y, r.x_axis.z, r.y_axis.z], [0.0; 3]);
+    /// assert!((q * r).abs_diff_eq(matrix, 1e-12));
+    /// ```
+    pub fn qr(&self) -> (Self, Self) {
+        let mut r = self.to_cols();
+        let mut q = Self::IDENTITY.to_cols();
+        for (p, i) in JACOBI_PAIRS {
+            let len = r[p][p].hypot(r[p][i]);
+            if r[p][i] == 0.0 || len == 0.0 {
+                continue;
+            }
+            let (cos, sin) = (r[p][p] / len, r[p][i] / len);
+            for col in r.iter_mut() {
+                let (x, y) = (col[p], col[i]);
+                col[p] = cos * x + sin * y;
+                col[i] = cos * y - sin * x;
+            }
+            r[p][i] = 0.0;
+            rotate_cols(&mut q, p, i, cos, -sin);
+        }
+        (Self::from_cols_nested(q), Self::from_cols_nested(r))
+    }
+
+    /// Returns the singular value decomposition `(U, S, V)` of the matrix, such that
+    /// `self == U * Mat3d::from_diagonal(S) * V.transpose()`
+    ///
+    /// The singular values are non-negative and sorted in descending order, and `U` and `V` are
+    /// orthogonal. The decomposition uses one-sided Jacobi iteration, which keeps small singular
+    /// values accurate relative to their own size.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat3::Mat3d;
+    /// use yavml::vec3::Vec3d;
+    /// let matrix = Mat3d::from_cols_array([0.0, 0.0, 3.0, 2.0, 0.0, 0.0, 0.0, -1.0, 0.0]);
+    /// let (u, s, v) = matrix.svd();
+    /// assert!((s - Vec3d::new(3.0, 2.0, 1.0)).length() < 1e-12);
+    /// assert!((u * Mat3d::from_diagonal(s) * v.transpose()).abs_diff_eq(matrix, 1e-12));
+    /// ```
+    pub fn svd(&self) -> (Self, Vec3d, Self) {
+        let mut u = self.to_cols();
+        let mut v = Self::IDENTITY.to_cols();
+        for _ in 0..JACOBI_MAX_SWEEPS {
+            let mut rotated = false;
+            for (p, q) in JACOBI_PAIRS {
+                let (up, uq) = (Vec3d::from_arr(u[p]), Vec3d::from_arr(u[q]));
+                let (alpha, beta, gamma) = (up.dot(up), uq.dot(uq), up.dot(uq));
+                if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+                let (cos, sin, _) = jacobi_rotation(alpha, beta, gamma);
+                rotate_cols(&mut u, p, q, cos, sin);
+                rotate_cols(&mut v, p, q, cos, sin);
+            }
+            if !rotated {
+                break;
+            }
+        }
+        let values = u.map(|col| Vec3d::from_arr(col).dot(Vec3d::from_arr(col)).sqrt());
+        let mut order = [0, 1, 2];
+        order.sort_by(|i, j| values[*j].total_cmp(&values[*i]));
+        let mut cols = order.map(|i| {
+            if values[i] == 0.0 {
+                Vec3d::ZERO
+            } else {
+                Vec3d::from_arr(u[i]) / values[i]
+            }
+        });
+        let values = order.map(|i| values[i]);
+        if values[0] == 0.0 {
+            cols = [Vec3d::X, Vec3d::Y, Vec3d::Z];
+        } else if values[1] == 0.0 {
+            let axis = if cols[0].x.abs() < 0.5 {
+                Vec3d::X
+            } else {
+                Vec3d::Y
+            };
+            cols[1] = cols[0].cross(axis).normalize();
+            cols[2] = cols[0].cross(cols[1]);
+        } else if values[2] == 0.0 {
+            cols[2] = cols[0].cross(cols[1]).normalize();
+        }
+        (
+            Self::from_cols(cols[0], cols[1], cols[2]),
+            Vec3d::from_arr(values),
+            Self::from_cols(
+                Vec3d::from_arr(v[order[0]]),
+                Vec3d::from_arr(v[order[1]]),
+                Vec3d::from_arr(v[order[2]]),
+            ),
+        )
+    }
+
+    /// Returns the columns of the matrix as nested arrays
+    fn to_cols(self) -> [[f64; 3]; 3] {
+        [
+            self.x_axis.to_array(),
+            self.y_axis.to_array(),
+            self.z_axis.to_array(),
+        ]
+    }
+
+    /// Creates a matrix from columns stored as nested arrays
+    fn from_cols_nested(cols: [[f64; 3]; 3]) -> Self {
+        Self::from_cols(
+            Vec3d::from_arr(cols[0]),
+            Vec3d::from_arr(cols[1]),
+            Vec3d::from_arr(cols[2]),
+        )
+    }
+
+    /// Returns `true` if every element of `self` and `rhs` differs by at most `max_abs_diff`
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f64) -> bool {
+        self.to_cols_array()
+            .iter()
+            .zip(rhs.to_cols_array().iter())
+            .all(|(a, b)| (a - b).abs() <= max_abs_diff)
+    }
+}
+
+impl Default for Mat3d {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Addition of matrices
+impl Add<Mat3d> for Mat3d {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_cols(
+            self.x_axis + rhs.x_axis,
+            self.y_axis + rhs.y_axis,
+            self.z_axis + rhs.z_axis,
+        )
+    }
+}
+
+/// Subtraction of matrices
+impl Sub<Mat3d> for Mat3d {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_cols(
+            self.x_axis - rhs.x_axis,
+            self.y_axis - rhs.y_axis,
+            self.z_axis - rhs.z_axis,
+        )
+    }
+}
+
+/// Multiplication of matrices
+impl Mul<Mat3d> for Mat3d {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_mat3(&rhs)
+    }
+}
+
+/// Multiplication assignment of matrices
+impl MulAssign<Mat3d> for Mat3d {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_mat3(&rhs);
+    }
+}
+
+/// Multiplication of a vector by a matrix
+impl Mul<Vec3d> for Mat3d {
+    type Output = Vec3d;
+
+    fn mul(self, rhs: Vec3d) -> Self::Output {
+        self.mul_vec3(rhs)
+    }
+}
+
+/// Multiplication of a matrix by an `f64`
+impl Mul<f64> for Mat3d {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::from_cols(self.x_axis * rhs, self.y_axis * rhs, self.z_axis * rhs)
+    }
+}
+
+/// Returns the cosine, sine and tangent of the Jacobi rotation that zeroes the off-diagonal
+/// element `b` of the symmetric matrix with diagonal `a` and `d`
+fn jacobi_rotation(a: f64, d: f64, b: f64) -> (f64, f64, f64) {
+    let theta = (d - a) / (2.0 * b);
+    let tan = 1.0_f64.copysign(theta) / (theta.abs() + theta.hypot(1.0));
+    let cos = 1.0 / tan.hypot(1.0);
+    (cos, tan * cos, tan)
+}
+
+/// Replaces columns `p` and `q` of `cols` with `cos * p - sin * q` and `sin * p + cos * q`
+fn rotate_cols(cols: &mut [[f64; 3]; 3], p: usize, q: usize, cos: f64, sin: f64) {
+    let (x, y) = (cols[p], cols[q]);
+    for k in 0..3 {
+        cols[p][k] = cos * x[k] - sin * y[k];
+        cols[q][k] = sin * x[k] + cos * y[k];
+    }
+}
@@ -0,0 +1,8 @@
+pub mod mat3f;
+pub use mat3f::Mat3f;
+
+pub mod mat3d;
+pub use mat3d::Mat3d;
+
+#[cfg(test)]
+mod tests;
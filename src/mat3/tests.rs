@@ -0,0 +1,375 @@
+use super::Mat3d;
+use super::Mat3f;
+use crate::mat2::Mat2f;
+use crate::vec2::Vec2d;
+use crate::vec2::Vec2f;
+use crate::vec3::Vec3d;
+use crate::vec3::Vec3f;
+
+mod mat3f_tests {
+    use super::Mat2f;
+    use super::Mat3f;
+    use super::Vec2f;
+    use super::Vec3f;
+    use crate::Error;
+    use core::f32::consts::{FRAC_PI_2, PI};
+
+    fn vec2_close(a: Vec2f, b: Vec2f) -> bool {
+        (a - b).length() < 1e-5
+    }
+
+    fn vec3_close(a: Vec3f, b: Vec3f) -> bool {
+        (a - b).length() < 1e-5
+    }
+
+    // Tests converting a `Mat3f` to and from a column-major array
+    #[test]
+    fn test_mat3f_cols_array() {
+        let arr = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+        let matrix = Mat3f::from_cols_array(arr);
+        assert_eq!(matrix.z_axis, Vec3f::new(7.0, 8.0, 9.0));
+        assert_eq!(matrix.row0(), Vec3f::new(1.0, 4.0, 7.0));
+        assert_eq!(matrix.to_cols_array(), arr);
+    }
+
+    // Tests that points are translated but directions are not
+    #[test]
+    fn test_mat3f_point_vs_vector() {
+        let matrix = Mat3f::from_translation(Vec2f::new(5.0, -1.0));
+        assert_eq!(
+            matrix.transform_point2(Vec2f::new(1.0, 1.0)),
+            Vec2f::new(6.0, 0.0)
+        );
+        assert_eq!(
+            matrix.transform_vector2(Vec2f::new(1.0, 1.0)),
+            Vec2f::new(1.0, 1.0)
+        );
+    }
+
+    // Tests the 2D rotation and scale builders
+    #[test]
+    fn test_mat3f_rotation_scale() {
+        let rotation = Mat3f::from_rotation(FRAC_PI_2);
+        assert!(vec2_close(
+            rotation.transform_point2(Vec2f::new(1.0, 0.0)),
+            Vec2f::new(0.0, 1.0)
+        ));
+        let scale = Mat3f::from_scale(Vec2f::new(2.0, 3.0));
+        assert_eq!(scale.transform_vector2(Vec2f::ONE), Vec2f::new(2.0, 3.0));
+        assert_eq!(scale.determinant(), 6.0);
+    }
+
+    // Tests that the combined builder matches composing the individual transforms
+    #[test]
+    fn test_mat3f_scale_angle_translation() {
+        let scale = Vec2f::new(2.0, 0.5);
+        let translation = Vec2f::new(-3.0, 4.0);
+        let combined = Mat3f::from_scale_angle_translation(scale, 0.3, translation);
+        let composed = Mat3f::from_translation(translation)
+            * Mat3f::from_rotation(0.3)
+            * Mat3f::from_scale(scale);
+        assert!(combined.abs_diff_eq(composed, 1e-6));
+    }
+
+    // Tests that a matrix times its inverse is the identity
+    #[test]
+    fn test_mat3f_inverse() {
+        let matrix = Mat3f::from_cols_array([2.0, 0.0, 1.0, 1.0, 3.0, 0.0, 0.0, 1.0, 4.0]);
+        assert!((matrix * matrix.inverse()).abs_diff_eq(Mat3f::IDENTITY, 1e-6));
+        let affine = Mat3f::from_scale_angle_translation(Vec2f::new(3.0, 2.0), 1.2, Vec2f::ONE);
+        let point = Vec2f::new(7.0, -2.0);
+        assert!(vec2_close(
+            affine
+                .inverse()
+                .transform_point2(affine.transform_point2(point)),
+            point
+        ));
+    }
+
+    // Tests that singular matrices have no inverse
+    #[test]
+    fn test_mat3f_try_inverse_singular() {
+        let singular = Mat3f::from_cols(Vec3f::X, Vec3f::Y, Vec3f::X + Vec3f::Y);
+        assert_eq!(singular.determinant(), 0.0);
+        assert_eq!(singular.try_inverse(), None);
+    }
+
+    // Tests the transpose of a `Mat3f`
+    #[test]
+    fn test_mat3f_transpose() {
+        let matrix = Mat3f::from_cols_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        assert_eq!(
+            matrix.transpose().to_cols_array(),
+            [1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0]
+        );
+        assert_eq!(matrix.transpose().transpose(), matrix);
+    }
+
+    // Tests rotations around an arbitrary axis
+    #[test]
+    fn test_mat3f_from_axis_angle() {
+        let axis = Vec3f::new(1.0, 1.0, 1.0) / 3.0f32.sqrt();
+        let rotation = Mat3f::from_axis_angle(axis, 2.0 * PI / 3.0);
+        assert!(vec3_close(rotation * Vec3f::X, Vec3f::Y));
+        assert!(vec3_close(rotation * Vec3f::Y, Vec3f::Z));
+        assert!(vec3_close(rotation * axis, axis));
+        assert!((rotation.determinant() - 1.0).abs() < 1e-6);
+        assert!(rotation.inverse().abs_diff_eq(rotation.transpose(), 1e-6));
+    }
+
+    // Tests that the axis rotations match the general axis-angle rotation
+    #[test]
+    fn test_mat3f_from_rotation_axes() {
+        let angle = 0.8;
+        assert!(Mat3f::from_rotation_x(angle)
+            .abs_diff_eq(Mat3f::from_axis_angle(Vec3f::X, angle), 1e-6));
+        assert!(Mat3f::from_rotation_y(angle)
+            .abs_diff_eq(Mat3f::from_axis_angle(Vec3f::Y, angle), 1e-6));
+        assert!(Mat3f::from_rotation_z(angle)
+            .abs_diff_eq(Mat3f::from_axis_angle(Vec3f::Z, angle), 1e-6));
+    }
+
+    // Tests converting a `Mat3f` to and from a `Mat2f`
+    #[test]
+    fn test_mat3f_mat2() {
+        let mat2 = Mat2f::from_cols_array([1.0, 2.0, 3.0, 4.0]);
+        let mat3 = Mat3f::from_mat2(mat2);
+        assert_eq!(mat3.to_mat2(), mat2);
+        assert_eq!(mat3.z_axis, Vec3f::Z);
+    }
+
+    // Tests splitting a 2D affine transform into scale, angle and translation
+    #[test]
+    fn test_mat3f_to_scale_angle_translation() {
+        let scale = Vec2f::new(-2.0, 0.5);
+        let translation = Vec2f::new(3.0, -7.0);
+        let matrix = Mat3f::from_scale_angle_translation(scale, 1.0, translation);
+        let (out_scale, angle, out_translation) = matrix.to_scale_angle_translation().unwrap();
+        assert!((out_scale - scale).length() < 1e-5);
+        assert!((angle - 1.0).abs() < 1e-5);
+        assert_eq!(out_translation, translation);
+    }
+
+    // Tests that projective, sheared and degenerate 2D transforms are reported
+    #[test]
+    fn test_mat3f_to_scale_angle_translation_errors() {
+        let mut projective = Mat3f::IDENTITY;
+        projective.x_axis.z = 0.1;
+        assert_eq!(
+            projective.to_scale_angle_translation(),
+            Err(Error::Projective)
+        );
+        let shear = Mat3f::from_mat2(Mat2f::from_cols_array([1.0, 0.2, 0.0, 1.0]));
+        assert_eq!(shear.to_scale_angle_translation(), Err(Error::Shear));
+        let degenerate = Mat3f::from_scale(Vec2f::ZERO);
+        assert_eq!(
+            degenerate.to_scale_angle_translation(),
+            Err(Error::Degenerate)
+        );
+    }
+
+    // Tests splitting a 3D linear transform into scale and rotation, including a reflection
+    #[test]
+    fn test_mat3f_to_scale_rotation() {
+        let rotation = Mat3f::from_axis_angle(Vec3f::new(0.0, 0.6, 0.8), 2.5);
+        for scale in [Vec3f::new(1.0, 2.0, 3.0), Vec3f::new(-4.0, 0.5, 1.0)] {
+            let matrix = Mat3f::from_scale_rotation(scale, rotation);
+            let (out_scale, out_rotation) = matrix.to_scale_rotation().unwrap();
+            assert!(vec3_close(out_scale, scale));
+            assert!(out_rotation.abs_diff_eq(rotation, 1e-5));
+        }
+    }
+
+    // Tests that a reflection along any axis is reported as a negative x scale
+    #[test]
+    fn test_mat3f_to_scale_rotation_reflection() {
+        let matrix = Mat3f::from_diagonal(Vec3f::new(2.0, 3.0, -4.0));
+        let (scale, rotation) = matrix.to_scale_rotation().unwrap();
+        assert!(scale.x < 0.0);
+        assert!((rotation.determinant() - 1.0).abs() < 1e-6);
+        assert!(Mat3f::from_scale_rotation(scale, rotation).abs_diff_eq(matrix, 1e-5));
+    }
+
+    // Tests that sheared and degenerate 3D transforms are reported
+    #[test]
+    fn test_mat3f_to_scale_rotation_errors() {
+        let shear = Mat3f::from_cols(Vec3f::X, Vec3f::Y, Vec3f::new(0.0, 0.3, 1.0));
+        assert_eq!(shear.to_scale_rotation(), Err(Error::Shear));
+        let degenerate = Mat3f::from_cols(Vec3f::X, Vec3f::ZERO, Vec3f::Z);
+        assert_eq!(degenerate.to_scale_rotation(), Err(Error::Degenerate));
+    }
+
+    // Tests the eigen, QR and singular value decompositions in single precision
+    #[test]
+    fn test_mat3f_decompositions() {
+        let matrix = Mat3f::from_cols_array([4.0, 1.0, -2.0, 1.0, 3.0, 0.5, -2.0, 0.5, 1.0]);
+        let (values, vectors) = matrix.symmetric_eigen();
+        assert!(values.x >= values.y && values.y >= values.z);
+        assert!((matrix * vectors.z_axis - vectors.z_axis * values.z).length() < 1e-5);
+        let (q, r) = matrix.qr();
+        assert!((q * r).abs_diff_eq(matrix, 1e-5));
+        let (u, s, v) = matrix.svd();
+        assert!((u * Mat3f::from_diagonal(s) * v.transpose()).abs_diff_eq(matrix, 1e-5));
+    }
+}
+
+mod mat3d_tests {
+    use super::Mat3d;
+    use super::Vec2d;
+    use super::Vec3d;
+
+    // Tests that a matrix times its inverse is the identity
+    #[test]
+    fn test_mat3d_inverse() {
+        let matrix = Mat3d::from_cols_array([2.0, 0.0, 1.0, 1.0, 3.0, 0.0, 0.0, 1.0, 4.0]);
+        assert!((matrix * matrix.inverse()).abs_diff_eq(Mat3d::IDENTITY, 1e-12));
+        assert_eq!(Mat3d::ZERO.try_inverse(), None);
+    }
+
+    // Tests the 2D affine transform builders of a `Mat3d`
+    #[test]
+    fn test_mat3d_affine() {
+        let matrix = Mat3d::from_scale_angle_translation(Vec2d::ONE, 0.0, Vec2d::new(1.0, 2.0));
+        assert_eq!(matrix.transform_point2(Vec2d::ZERO), Vec2d::new(1.0, 2.0));
+        assert_eq!(matrix.transform_vector2(Vec2d::ONE), Vec2d::ONE);
+    }
+
+    // Tests the determinant of a rotation
+    #[test]
+    fn test_mat3d_rotation_determinant() {
+        let axis = Vec3d::new(0.0, 0.6, 0.8);
+        assert!((Mat3d::from_axis_angle(axis, 1.1).determinant() - 1.0).abs() < 1e-12);
+    }
+
+    /// Asserts that `matrix` has orthonormal columns
+    fn assert_orthonormal(matrix: Mat3d) {
+        assert!((matrix.transpose() * matrix).abs_diff_eq(Mat3d::IDENTITY, 1e-14));
+    }
+
+    // Tests that eigenvectors satisfy `A * v = l * v` and are returned as a rotation
+    #[test]
+    fn test_mat3d_symmetric_eigen() {
+        let matrix = Mat3d::from_cols_array([4.0, 1.0, -2.0, 1.0, 3.0, 0.5, -2.0, 0.5, 1.0]);
+        let (values, vectors) = matrix.symmetric_eigen();
+        assert!(values.x >= values.y && values.y >= values.z);
+        for (value, vector) in [
+            (values.x, vectors.x_axis),
+            (values.y, vectors.y_axis),
+            (values.z, vectors.z_axis),
+        ] {
+            assert!((matrix * vector - vector * value).length() < 1e-13);
+        }
+        assert_orthonormal(vectors);
+        assert!((vectors.determinant() - 1.0).abs() < 1e-14);
+        assert!((values.x + values.y + values.z - 8.0).abs() < 1e-13);
+    }
+
+    // Tests that repeated and graded eigenvalues are found to high relative accuracy
+    #[test]
+    fn test_mat3d_symmetric_eigen_ill_conditioned() {
+        let (values, vectors) = Mat3d::from_diagonal(Vec3d::new(1.0, 1.0, 2.0)).symmetric_eigen();
+        assert_eq!(values, Vec3d::new(2.0, 1.0, 1.0));
+        assert_orthonormal(vectors);
+        assert_eq!(vectors.determinant(), 1.0);
+
+        let rotation = Mat3d::from_axis_angle(Vec3d::new(0.6, 0.0, 0.8), 0.7);
+        let matrix =
+            rotation * Mat3d::from_diagonal(Vec3d::new(5.0, 5.0, -1.0)) * rotation.transpose();
+        let (values, vectors) = matrix.symmetric_eigen();
+        assert!((values - Vec3d::new(5.0, 5.0, -1.0)).length() < 1e-13);
+        assert!((vectors.z_axis.dot(rotation.z_axis).abs() - 1.0).abs() < 1e-13);
+
+        // A graded matrix `D * H * D` whose eigenvalues span 24 orders of magnitude
+        let h = [[1.0, 0.5, 0.25], [0.5, 1.0, 0.5], [0.25, 0.5, 1.0]];
+        let d = [1.0, 1e-6, 1e-12];
+        let matrix = Mat3d::from_cols(
+            Vec3d::new(
+                h[0][0] * d[0] * d[0],
+                h[0][1] * d[0] * d[1],
+                h[0][2] * d[0] * d[2],
+            ),
+            Vec3d::new(
+                h[1][0] * d[1] * d[0],
+                h[1][1] * d[1] * d[1],
+                h[1][2] * d[1] * d[2],
+            ),
+            Vec3d::new(
+                h[2][0] * d[2] * d[0],
+                h[2][1] * d[2] * d[1],
+                h[2][2] * d[2] * d[2],
+            ),
+        );
+        let (values, _) = matrix.symmetric_eigen();
+        assert!((values.x * values.y * values.z / 0.5625e-36 - 1.0).abs() < 1e-10);
+    }
+
+    // Tests that QR factors reproduce the input with an orthonormal `Q`
+    #[test]
+    fn test_mat3d_qr() {
+        let matrix = Mat3d::from_cols_array([1.0, 2.0, 2.0, 0.0, 1.0, 3.0, 4.0, 1.0, 0.0]);
+        let (q, r) = matrix.qr();
+        assert_eq!([r.x_axis.y, r.x_axis.z, r.y_axis.z], [0.0; 3]);
+        assert!((r.x_axis.x.abs() - 3.0).abs() < 1e-14);
+        assert!((q * r).abs_diff_eq(matrix, 1e-14));
+        assert_orthonormal(q);
+        assert!((q.determinant() - 1.0).abs() < 1e-14);
+
+        // Nearly parallel columns
+        let matrix = Mat3d::from_cols_array([1.0, 1.0, 1.0, 1.0, 1.0, 1.0 + 1e-10, 0.0, 1.0, 2.0]);
+        let (q, r) = matrix.qr();
+        assert!((q * r).abs_diff_eq(matrix, 1e-14));
+        assert_orthonormal(q);
+
+        assert_eq!(Mat3d::ZERO.qr(), (Mat3d::IDENTITY, Mat3d::ZERO));
+    }
+
+    // Tests that the singular value decomposition reproduces general input
+    #[test]
+    fn test_mat3d_svd() {
+        for matrix in [
+            Mat3d::from_cols_array([1.0, 2.0, 2.0, 0.0, 1.0, 3.0, 4.0, 1.0, 0.0]),
+            Mat3d::from_cols_array([4.0, 1.0, -2.0, 1.0, 3.0, 0.5, -2.0, 0.5, 1.0]),
+            Mat3d::from_axis_angle(Vec3d::new(0.0, 0.6, 0.8), 2.0),
+            Mat3d::from_diagonal(Vec3d::new(-1.0, 2.0, 0.5)),
+        ] {
+            let (u, s, v) = matrix.svd();
+            assert!(s.x >= s.y && s.y >= s.z && s.z >= 0.0);
+            assert!((u * Mat3d::from_diagonal(s) * v.transpose()).abs_diff_eq(matrix, 1e-13));
+            assert_orthonormal(u);
+            assert_orthonormal(v);
+            assert!((s.x * s.y * s.z - matrix.determinant().abs()).abs() < 1e-12);
+        }
+    }
+
+    // Tests that small singular values of graded and singular input are accurate
+    #[test]
+    fn test_mat3d_svd_ill_conditioned() {
+        // Columns of a matrix with determinant 18 scaled down to 1e-8 and 1e-16
+        let matrix = Mat3d::from_cols(
+            Vec3d::new(2.0, 1.0, 0.0),
+            Vec3d::new(1.0, 3.0, 1.0) * 1e-8,
+            Vec3d::new(0.0, 1.0, 4.0) * 1e-16,
+        );
+        let (u, s, v) = matrix.svd();
+        assert!((s.x * s.y * s.z / 18e-24 - 1.0).abs() < 1e-12);
+        assert!((u * Mat3d::from_diagonal(s) * v.transpose()).abs_diff_eq(matrix, 1e-15));
+        assert_orthonormal(u);
+        assert_orthonormal(v);
+
+        let matrix = Mat3d::from_cols_array([1.0, 2.0, 1.0, 2.0, 4.0, 1.0, 3.0, 6.0, 1.0]);
+        let (u, s, v) = matrix.svd();
+        assert!(s.z < 1e-14);
+        assert!((u * Mat3d::from_diagonal(s) * v.transpose()).abs_diff_eq(matrix, 1e-14));
+        assert_orthonormal(u);
+        assert_orthonormal(v);
+
+        let (u, s, v) = Mat3d::from_diagonal(Vec3d::new(0.0, 3.0, 0.0)).svd();
+        assert_eq!(s, Vec3d::new(3.0, 0.0, 0.0));
+        assert_orthonormal(u);
+        assert_orthonormal(v);
+
+        let (u, s, v) = Mat3d::ZERO.svd();
+        assert_eq!(s, Vec3d::ZERO);
+        assert_eq!((u, v), (Mat3d::IDENTITY, Mat3d::IDENTITY));
+    }
+}
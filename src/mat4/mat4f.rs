@@ -0,0 +1,729 @@
+use core::ops::*;
+
+use crate::angle::Radians;
+use crate::error::Error;
+use crate::mat3::Mat3f;
+use crate::quat::Quatf;
+use crate::vec3::Vec3f;
+use crate::vec4::Vec4f;
+
+/// A 4x4 column-major matrix of `f32` values
+///
+/// Projection matrices map view space to clip space. Right-handed (`_rh`) builders expect the
+/// camera to look down negative z and left-handed (`_lh`) builders expect it to look down
+/// positive z. Builders without a suffix produce a depth range of `[0, 1]` as used by Vulkan,
+/// Metal and Direct3D, while `_gl` builders produce the `[-1, 1]` range used by OpenGL.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mat4f {
+    /// The first column of the matrix
+    pub x_axis: Vec4f,
+    /// The second column of the matrix
+    pub y_axis: Vec4f,
+    /// The third column of the matrix
+    pub z_axis: Vec4f,
+    /// The fourth column of the matrix
+    pub w_axis: Vec4f,
+}
+
+impl Mat4f {
+    /// Matrix of all zeros
+    pub const ZERO: Self = Self::from_cols(Vec4f::ZERO, Vec4f::ZERO, Vec4f::ZERO, Vec4f::ZERO);
+    /// The identity matrix
+    pub const IDENTITY: Self = Self::from_cols(Vec4f::X, Vec4f::Y, Vec4f::Z, Vec4f::W);
+
+    /// Creates a new matrix from its four columns
+    pub const fn from_cols(x_axis: Vec4f, y_axis: Vec4f, z_axis: Vec4f, w_axis: Vec4f) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+            w_axis,
+        }
+    }
+
+    /// Creates a new matrix from a column-major array
+    pub const fn from_cols_array(arr: [f32; 16]) -> Self {
+        Self::from_cols(
+            Vec4f::new(arr[0], arr[1], arr[2], arr[3]),
+            Vec4f::new(arr[4], arr[5], arr[6], arr[7]),
+            Vec4f::new(arr[8], arr[9], arr[10], arr[11]),
+            Vec4f::new(arr[12], arr[13], arr[14], arr[15]),
+        )
+    }
+
+    /// Create a column-major array from the matrix
+    pub const fn to_cols_array(&self) -> [f32; 16] {
+        let [a, b, c, d] = [self.x_axis, self.y_axis, self.z_axis, self.w_axis];
+        [
+            a.x, a.y, a.z, a.w, b.x, b.y, b.z, b.w, c.x, c.y, c.z, c.w, d.x, d.y, d.z, d.w,
+        ]
+    }
+
+    /// Creates a matrix with `diagonal` along its diagonal and zeros everywhere else
+    pub const fn from_diagonal(diagonal: Vec4f) -> Self {
+        Self::from_cols(
+            Vec4f::new(diagonal.x, 0.0, 0.0, 0.0),
+            Vec4f::new(0.0, diagonal.y, 0.0, 0.0),
+            Vec4f::new(0.0, 0.0, diagonal.z, 0.0),
+            Vec4f::new(0.0, 0.0, 0.0, diagonal.w),
+        )
+    }
+
+    /// Creates a matrix whose upper-left 3x3 block is `mat` and is otherwise the identity
+    pub const fn from_mat3(mat: Mat3f) -> Self {
+        Self::from_cols(
+            Vec4f::new(mat.x_axis.x, mat.x_axis.y, mat.x_axis.z, 0.0),
+            Vec4f::new(mat.y_axis.x, mat.y_axis.y, mat.y_axis.z, 0.0),
+            Vec4f::new(mat.z_axis.x, mat.z_axis.y, mat.z_axis.z, 0.0),
+            Vec4f::W,
+        )
+    }
+
+    /// Returns the upper-left 3x3 block of the matrix
+    pub const fn to_mat3(&self) -> Mat3f {
+        Mat3f::from_cols(
+            Vec3f::new(self.x_axis.x, self.x_axis.y, self.x_axis.z),
+            Vec3f::new(self.y_axis.x, self.y_axis.y, self.y_axis.z),
+            Vec3f::new(self.z_axis.x, self.z_axis.y, self.z_axis.z),
+        )
+    }
+
+    /// Creates an affine transform that translates by `translation`
+    pub const fn from_translation(translation: Vec3f) -> Self {
+        Self::from_cols(
+            Vec4f::X,
+            Vec4f::Y,
+            Vec4f::Z,
+            Vec4f::new(translation.x, translation.y, translation.z, 1.0),
+        )
+    }
+
+    /// Creates an affine transform that scales along the x, y and z axes by `scale`
+    pub const fn from_scale(scale: Vec3f) -> Self {
+        Self::from_diagonal(Vec4f::new(scale.x, scale.y, scale.z, 1.0))
+    }
+
+    /// Creates an affine transform that rotates by `angle` radians around the normalized `axis`
+    pub fn from_axis_angle(axis: Vec3f, angle: impl Into<Radians<f32>>) -> Self {
+        Self::from_mat3(Mat3f::from_axis_angle(axis, angle))
+    }
+
+    /// Creates a right-handed perspective projection with a depth range of `[0, 1]`
+    ///
+    /// # Arguments
+    ///
+    /// * `fov_y` - The vertical field of view in radians
+    ///
+    /// * `aspect_ratio` - The width of the view divided by its height
+    ///
+    /// * `z_near` - The distance to the near plane, which is mapped to a depth of `0`
+    ///
+    /// * `z_far` - The distance to the far plane, which is mapped to a depth of `1`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat4::Mat4f;
+    /// use yavml::vec3::Vec3f;
+    /// let projection = Mat4f::perspective_rh(core::f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0);
+    /// assert!(projection.project_point3(Vec3f::new(0.0, 0.0, -1.0)).z.abs() < 1e-6);
+    /// assert!((projection.project_point3(Vec3f::new(0.0, 0.0, -10.0)).z - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn perspective_rh(
+        fov_y: impl Into<Radians<f32>>,
+        aspect_ratio: f32,
+        z_near: f32,
+        z_far: f32,
+    ) -> Self {
+        let (w, h) = Self::perspective_scale(fov_y.into().0, aspect_ratio);
+        let r = z_far / (z_near - z_far);
+        Self::perspective_from_depth(w, h, r, -1.0, r * z_near)
+    }
+
+    /// Creates a left-handed perspective projection with a depth range of `[0, 1]`
+    ///
+    /// See [`Mat4f::perspective_rh`] for a description of the arguments.
+    pub fn perspective_lh(
+        fov_y: impl Into<Radians<f32>>,
+        aspect_ratio: f32,
+        z_near: f32,
+        z_far: f32,
+    ) -> Self {
+        let (w, h) = Self::perspective_scale(fov_y.into().0, aspect_ratio);
+        let r = z_far / (z_far - z_near);
+        Self::perspective_from_depth(w, h, r, 1.0, -r * z_near)
+    }
+
+    /// Creates a right-handed perspective projection with a depth range of `[-1, 1]`
+    ///
+    /// See [`Mat4f::perspective_rh`] for a description of the arguments.
+    pub fn perspective_rh_gl(
+        fov_y: impl Into<Radians<f32>>,
+        aspect_ratio: f32,
+        z_near: f32,
+        z_far: f32,
+    ) -> Self {
+        let (w, h) = Self::perspective_scale(fov_y.into().0, aspect_ratio);
+        let r = 1.0 / (z_near - z_far);
+        Self::perspective_from_depth(w, h, (z_far + z_near) * r, -1.0, 2.0 * z_far * z_near * r)
+    }
+
+    /// Creates a left-handed perspective projection with a depth range of `[-1, 1]`
+    ///
+    /// See [`Mat4f::perspective_rh`] for a description of the arguments.
+    pub fn perspective_lh_gl(
+        fov_y: impl Into<Radians<f32>>,
+        aspect_ratio: f32,
+        z_near: f32,
+        z_far: f32,
+    ) -> Self {
+        let (w, h) = Self::perspective_scale(fov_y.into().0, aspect_ratio);
+        let r = 1.0 / (z_far - z_near);
+        Self::perspective_from_depth(w, h, (z_far + z_near) * r, 1.0, -2.0 * z_far * z_near * r)
+    }
+
+    /// Creates a right-handed perspective projection with the far plane at infinity
+    ///
+    /// The near plane is mapped to a depth of `0` and infinity to a depth of `1`.
+    pub fn perspective_infinite_rh(
+        fov_y: impl Into<Radians<f32>>,
+        aspect_ratio: f32,
+        z_near: f32,
+    ) -> Self {
+        let (w, h) = Self::perspective_scale(fov_y.into().0, aspect_ratio);
+        Self::perspective_from_depth(w, h, -1.0, -1.0, -z_near)
+    }
+
+    /// Creates a left-handed perspective projection with the far plane at infinity
+    ///
+    /// The near plane is mapped to a depth of `0` and infinity to a depth of `1`.
+    pub fn perspective_infinite_lh(
+        fov_y: impl Into<Radians<f32>>,
+        aspect_ratio: f32,
+        z_near: f32,
+    ) -> Self {
+        let (w, h) = Self::perspective_scale(fov_y.into().0, aspect_ratio);
+        Self::perspective_from_depth(w, h, 1.0, 1.0, -z_near)
+    }
+
+    /// Creates a right-handed perspective projection with reversed depth
+    ///
+    /// The near plane is mapped to a depth of `1` and the far plane to a depth of `0`, which
+    /// spreads floating-point depth precision more evenly over the view.
+    pub fn perspective_reverse_rh(
+        fov_y: impl Into<Radians<f32>>,
+        aspect_ratio: f32,
+        z_near: f32,
+        z_far: f32,
+    ) -> Self {
+        let (w, h) = Self::perspective_scale(fov_y.into().0, aspect_ratio);
+        let r = z_near / (z_far - z_near);
+        Self::perspective_from_depth(w, h, r, -1.0, r * z_far)
+    }
+
+    /// Creates a left-handed perspective projection with reversed depth
+    ///
+    /// The near plane is mapped to a depth of `1` and the far plane to a depth of `0`.
+    pub fn perspective_reverse_lh(
+        fov_y: impl Into<Radians<f32>>,
+        aspect_ratio: f32,
+        z_near: f32,
+        z_far: f32,
+    ) -> Self {
+        let (w, h) = Self::perspective_scale(fov_y.into().0, aspect_ratio);
+        let r = z_near / (z_far - z_near);
+        Self::perspective_from_depth(w, h, -r, 1.0, r * z_far)
+    }
+
+    /// Creates a right-handed perspective projection with reversed depth and the far plane at
+    /// infinity
+    ///
+    /// The near plane is mapped to a depth of `1` and infinity to a depth of `0`.
+    pub fn perspective_infinite_reverse_rh(
+        fov_y: impl Into<Radians<f32>>,
+        aspect_ratio: f32,
+        z_near: f32,
+    ) -> Self {
+        let (w, h) = Self::perspective_scale(fov_y.into().0, aspect_ratio);
+        Self::perspective_from_depth(w, h, 0.0, -1.0, z_near)
+    }
+
+    /// Creates a left-handed perspective projection with reversed depth and the far plane at
+    /// infinity
+    ///
+    /// The near plane is mapped to a depth of `1` and infinity to a depth of `0`.
+    pub fn perspective_infinite_reverse_lh(
+        fov_y: impl Into<Radians<f32>>,
+        aspect_ratio: f32,
+        z_near: f32,
+    ) -> Self {
+        let (w, h) = Self::perspective_scale(fov_y.into().0, aspect_ratio);
+        Self::perspective_from_depth(w, h, 0.0, 1.0, z_near)
+    }
+
+    /// Returns the x and y scale of a perspective projection
+    fn perspective_scale(fov_y: f32, aspect_ratio: f32) -> (f32, f32) {
+        let h = 1.0 / (0.5 * fov_y).tan();
+        (h / aspect_ratio, h)
+    }
+
+    /// Builds a perspective projection where clip-space `z = depth * z + offset` and `w = w_z * z`
+    fn perspective_from_depth(w: f32, h: f32, depth: f32, w_z: f32, offset: f32) -> Self {
+        Self::from_cols(
+            Vec4f::new(w, 0.0, 0.0, 0.0),
+            Vec4f::new(0.0, h, 0.0, 0.0),
+            Vec4f::new(0.0, 0.0, depth, w_z),
+            Vec4f::new(0.0, 0.0, offset, 0.0),
+        )
+    }
+
+    /// Creates a right-handed orthographic projection with a depth range of `[0, 1]`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat4::Mat4f;
+    /// use yavml::vec3::Vec3f;
+    /// let projection = Mat4f::orthographic_rh(-2.0, 2.0, -1.0, 1.0, 0.0, 10.0);
+    /// let projected = projection.project_point3(Vec3f::new(2.0, -1.0, -10.0));
+    /// assert_eq!(projected, Vec3f::new(1.0, -1.0, 1.0));
+    /// ```
+    pub fn orthographic_rh(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+    ) -> Self {
+        let r = 1.0 / (near - far);
+        Self::orthographic_from_depth(left, right, bottom, top, r, r * near)
+    }
+
+    /// Creates a left-handed orthographic projection with a depth range of `[0, 1]`
+    pub fn orthographic_lh(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+    ) -> Self {
+        let r = 1.0 / (far - near);
+        Self::orthographic_from_depth(left, right, bottom, top, r, -r * near)
+    }
+
+    /// Creates a right-handed orthographic projection with a depth range of `[-1, 1]`
+    pub fn orthographic_rh_gl(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+    ) -> Self {
+        let r = 1.0 / (far - near);
+        Self::orthographic_from_depth(left, right, bottom, top, -2.0 * r, -(far + near) * r)
+    }
+
+    /// Creates a left-handed orthographic projection with a depth range of `[-1, 1]`
+    pub fn orthographic_lh_gl(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+    ) -> Self {
+        let r = 1.0 / (far - near);
+        Self::orthographic_from_depth(left, right, bottom, top, 2.0 * r, -(far + near) * r)
+    }
+
+    /// Builds an orthographic projection where clip-space `z = depth * z + offset`
+    fn orthographic_from_depth(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        depth: f32,
+        offset: f32,
+    ) -> Self {
+        let rcp_width = 1.0 / (right - left);
+        let rcp_height = 1.0 / (top - bottom);
+        Self::from_cols(
+            Vec4f::new(2.0 * rcp_width, 0.0, 0.0, 0.0),
+            Vec4f::new(0.0, 2.0 * rcp_height, 0.0, 0.0),
+            Vec4f::new(0.0, 0.0, depth, 0.0),
+            Vec4f::new(
+                -(left + right) * rcp_width,
+                -(top + bottom) * rcp_height,
+                offset,
+                1.0,
+            ),
+        )
+    }
+
+    /// Creates a right-handed view matrix for a camera at `eye` looking along `dir`
+    ///
+    /// `dir` and `up` do not need to be normalized but must not be parallel.
+    pub fn look_to_rh(eye: Vec3f, dir: Vec3f, up: Vec3f) -> Self {
+        let f = dir.normalize();
+        let s = f.cross(up).normalize();
+        let u = s.cross(f);
+        Self::from_cols(
+            Vec4f::new(s.x, u.x, -f.x, 0.0),
+            Vec4f::new(s.y, u.y, -f.y, 0.0),
+            Vec4f::new(s.z, u.z, -f.z, 0.0),
+            Vec4f::new(-s.dot(eye), -u.dot(eye), f.dot(eye), 1.0),
+        )
+    }
+
+    /// Creates a left-handed view matrix for a camera at `eye` looking along `dir`
+    ///
+    /// `dir` and `up` do not need to be normalized but must not be parallel.
+    pub fn look_to_lh(eye: Vec3f, dir: Vec3f, up: Vec3f) -> Self {
+        let f = dir.normalize();
+        let s = up.cross(f).normalize();
+        let u = f.cross(s);
+        Self::from_cols(
+            Vec4f::new(s.x, u.x, f.x, 0.0),
+            Vec4f::new(s.y, u.y, f.y, 0.0),
+            Vec4f::new(s.z, u.z, f.z, 0.0),
+            Vec4f::new(-s.dot(eye), -u.dot(eye), -f.dot(eye), 1.0),
+        )
+    }
+
+    /// Creates a right-handed view matrix for a camera at `eye` looking at `center`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat4::Mat4f;
+    /// use yavml::vec3::Vec3f;
+    /// let view = Mat4f::look_at_rh(Vec3f::new(0.0, 0.0, 5.0), Vec3f::ZERO, Vec3f::Y);
+    /// assert_eq!(view.transform_point3(Vec3f::ZERO), Vec3f::new(0.0, 0.0, -5.0));
+    /// ```
+    pub fn look_at_rh(eye: Vec3f, center: Vec3f, up: Vec3f) -> Self {
+        Self::look_to_rh(eye, center - eye, up)
+    }
+
+    /// Creates a left-handed view matrix for a camera at `eye` looking at `center`
+    pub fn look_at_lh(eye: Vec3f, center: Vec3f, up: Vec3f) -> Self {
+        Self::look_to_lh(eye, center - eye, up)
+    }
+
+    /// Returns the first row of the matrix
+    pub const fn row0(&self) -> Vec4f {
+        Vec4f::new(self.x_axis.x, self.y_axis.x, self.z_axis.x, self.w_axis.x)
+    }
+
+    /// Returns the second row of the matrix
+    pub const fn row1(&self) -> Vec4f {
+        Vec4f::new(self.x_axis.y, self.y_axis.y, self.z_axis.y, self.w_axis.y)
+    }
+
+    /// Returns the third row of the matrix
+    pub const fn row2(&self) -> Vec4f {
+        Vec4f::new(self.x_axis.z, self.y_axis.z, self.z_axis.z, self.w_axis.z)
+    }
+
+    /// Returns the fourth row of the matrix
+    pub const fn row3(&self) -> Vec4f {
+        Vec4f::new(self.x_axis.w, self.y_axis.w, self.z_axis.w, self.w_axis.w)
+    }
+
+    /// Returns the transpose of the matrix
+    pub const fn transpose(&self) -> Self {
+        Self::from_cols(self.row0(), self.row1(), self.row2(), self.row3())
+    }
+
+    /// Returns the determinant of the matrix
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat4::Mat4f;
+    /// use yavml::vec4::Vec4f;
+    /// assert_eq!(Mat4f::from_diagonal(Vec4f::new(1.0, 2.0, 3.0, 4.0)).determinant(), 24.0);
+    /// ```
+    pub fn determinant(&self) -> f32 {
+        let [m00, m01, m02, m03] = self.x_axis.to_array();
+        let [m10, m11, m12, m13] = self.y_axis.to_array();
+        let [m20, m21, m22, m23] = self.z_axis.to_array();
+        let [m30, m31, m32, m33] = self.w_axis.to_array();
+
+        let a2323 = m22 * m33 - m23 * m32;
+        let a1323 = m21 * m33 - m23 * m31;
+        let a1223 = m21 * m32 - m22 * m31;
+        let a0323 = m20 * m33 - m23 * m30;
+        let a0223 = m20 * m32 - m22 * m30;
+        let a0123 = m20 * m31 - m21 * m30;
+
+        m00 * (m11 * a2323 - m12 * a1323 + m13 * a1223)
+            - m01 * (m10 * a2323 - m12 * a0323 + m13 * a0223)
+            + m02 * (m10 * a1323 - m11 * a0323 + m13 * a0123)
+            - m03 * (m10 * a1223 - m11 * a0223 + m12 * a0123)
+    }
+
+    /// Returns the inverse of the matrix, or `None` if the matrix is not invertible
+    ///
+    /// For matrices that are known to be affine, `Affine3::try_inverse` is faster and more precise.
+    pub fn try_inverse(&self) -> Option<Self> {
+        let [m00, m01, m02, m03] = self.x_axis.to_array();
+        let [m10, m11, m12, m13] = self.y_axis.to_array();
+        let [m20, m21, m22, m23] = self.z_axis.to_array();
+        let [m30, m31, m32, m33] = self.w_axis.to_array();
+
+        let coef00 = m22 * m33 - m32 * m23;
+        let coef02 = m12 * m33 - m32 * m13;
+        let coef03 = m12 * m23 - m22 * m13;
+        let coef04 = m21 * m33 - m31 * m23;
+        let coef06 = m11 * m33 - m31 * m13;
+        let coef07 = m11 * m23 - m21 * m13;
+        let coef08 = m21 * m32 - m31 * m22;
+        let coef10 = m11 * m32 - m31 * m12;
+        let coef11 = m11 * m22 - m21 * m12;
+        let coef12 = m20 * m33 - m30 * m23;
+        let coef14 = m10 * m33 - m30 * m13;
+        let coef15 = m10 * m23 - m20 * m13;
+        let coef16 = m20 * m32 - m30 * m22;
+        let coef18 = m10 * m32 - m30 * m12;
+        let coef19 = m10 * m22 - m20 * m12;
+        let coef20 = m20 * m31 - m30 * m21;
+        let coef22 = m10 * m31 - m30 * m11;
+        let coef23 = m10 * m21 - m20 * m11;
+
+        let fac0 = Vec4f::new(coef00, coef00, coef02, coef03);
+        let fac1 = Vec4f::new(coef04, coef04, coef06, coef07);
+        let fac2 = Vec4f::new(coef08, coef08, coef10, coef11);
+        let fac3 = Vec4f::new(coef12, coef12, coef14, coef15);
+        let fac4 = Vec4f::new(coef16, coef16, coef18, coef19);
+        let fac5 = Vec4f::new(coef20, coef20, coef22, coef23);
+
+        let vec0 = Vec4f::new(m10, m00, m00, m00);
+        let vec1 = Vec4f::new(m11, m01, m01, m01);
+        let vec2 = Vec4f::new(m12, m02, m02, m02);
+        let vec3 = Vec4f::new(m13, m03, m03, m03);
+
+        let inv0 = vec1 * fac0 - vec2 * fac1 + vec3 * fac2;
+        let inv1 = vec0 * fac0 - vec2 * fac3 + vec3 * fac4;
+        let inv2 = vec0 * fac1 - vec1 * fac3 + vec3 * fac5;
+        let inv3 = vec0 * fac2 - vec1 * fac4 + vec2 * fac5;
+
+        let sign_a = Vec4f::new(1.0, -1.0, 1.0, -1.0);
+        let sign_b = Vec4f::new(-1.0, 1.0, -1.0, 1.0);
+        let adjugate = Self::from_cols(inv0 * sign_a, inv1 * sign_b, inv2 * sign_a, inv3 * sign_b);
+
+        let det = self.x_axis.dot(adjugate.row0());
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        Some(adjugate * (1.0 / det))
+    }
+
+    /// Returns the inverse of the matrix
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not invertible. Use [`Mat4f::try_inverse`] when that can happen.
+    pub fn inverse(&self) -> Self {
+        self.try_inverse().expect("matrix is not invertible")
+    }
+
+    /// Transforms `point` as a 3D point, treating it as having a `w` of `1`
+    ///
+    /// The matrix is assumed to be an affine transform, so the bottom row is ignored. Use
+    /// [`Mat4f::project_point3`] for projection matrices.
+    pub fn transform_point3(&self, point: Vec3f) -> Vec3f {
+        let res =
+            self.x_axis * point.x + self.y_axis * point.y + self.z_axis * point.z + self.w_axis;
+        Vec3f::new(res.x, res.y, res.z)
+    }
+
+    /// Transforms `vector` as a 3D direction, treating it as having a `w` of `0`
+    pub fn transform_vector3(&self, vector: Vec3f) -> Vec3f {
+        let res = self.x_axis * vector.x + self.y_axis * vector.y + self.z_axis * vector.z;
+        Vec3f::new(res.x, res.y, res.z)
+    }
+
+    /// Transforms `point` as a 3D point with a `w` of `1` and divides the result by its `w`
+    pub fn project_point3(&self, point: Vec3f) -> Vec3f {
+        let res = self.mul_vec4(Vec4f::new(point.x, point.y, point.z, 1.0));
+        Vec3f::new(res.x, res.y, res.z) / res.w
+    }
+
+    /// Multiplies the vector `rhs` by the matrix
+    pub fn mul_vec4(&self, rhs: Vec4f) -> Vec4f {
+        self.x_axis * rhs.x + self.y_axis * rhs.y + self.z_axis * rhs.z + self.w_axis * rhs.w
+    }
+
+    /// Multiplies the matrix `rhs` by `self`
+    pub fn mul_mat4(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.mul_vec4(rhs.x_axis),
+            self.mul_vec4(rhs.y_axis),
+            self.mul_vec4(rhs.z_axis),
+            self.mul_vec4(rhs.w_axis),
+        )
+    }
+
+    /// Creates an affine transform that scales by `scale`, then applies the pure rotation
+    /// `rotation` and then translates by `translation`
+    pub fn from_scale_rotation_translation(
+        scale: Vec3f,
+        rotation: Mat3f,
+        translation: Vec3f,
+    ) -> Self {
+        let mut res = Self::from_mat3(Mat3f::from_scale_rotation(scale, rotation));
+        res.w_axis = Vec4f::new(translation.x, translation.y, translation.z, 1.0);
+        res
+    }
+
+    /// Splits the matrix into the arguments of [`Mat4f::from_scale_rotation_translation`]
+    ///
+    /// A matrix with a negative determinant is reported with a negative x scale, so the returned
+    /// rotation never contains a reflection.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Projective`] if the bottom row is not `(0, 0, 0, 1)`,
+    /// [`Error::Degenerate`] if an axis has zero length and [`Error::Shear`] if the axes are not
+    /// perpendicular.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat3::Mat3f;
+    /// use yavml::mat4::Mat4f;
+    /// use yavml::vec3::Vec3f;
+    /// let rotation = Mat3f::from_axis_angle(Vec3f::Z, 0.5);
+    /// let matrix = Mat4f::from_scale_rotation_translation(Vec3f::new(1.0, 2.0, 3.0), rotation, Vec3f::ONE);
+    /// let (scale, decomposed, translation) = matrix.to_scale_rotation_translation().unwrap();
+    /// assert!((scale - Vec3f::new(1.0, 2.0, 3.0)).length() < 1e-5);
+    /// assert!(decomposed.abs_diff_eq(rotation, 1e-5));
+    /// assert_eq!(translation, Vec3f::ONE);
+    /// ```
+    pub fn to_scale_rotation_translation(&self) -> Result<(Vec3f, Mat3f, Vec3f), Error> {
+        if self.row3() != Vec4f::W {
+            return Err(Error::Projective);
+        }
+        let (scale, rotation) = self.to_mat3().to_scale_rotation()?;
+        Ok((
+            scale,
+            rotation,
+            Vec3f::new(self.w_axis.x, self.w_axis.y, self.w_axis.z),
+        ))
+    }
+
+    /// Creates an affine transform that scales by `scale`, then rotates by the unit quaternion
+    /// `rotation` and then translates by `translation`
+    ///
+    /// This is the layout of the translation, rotation and scale of a glTF node.
+    pub fn from_scale_quat_translation(scale: Vec3f, rotation: Quatf, translation: Vec3f) -> Self {
+        Self::from_scale_rotation_translation(scale, rotation.to_mat3(), translation)
+    }
+
+    /// Splits the matrix into the arguments of [`Mat4f::from_scale_quat_translation`]
+    ///
+    /// This is [`Mat4f::to_scale_rotation_translation`] with the rotation converted to a unit
+    /// quaternion, so a matrix with a negative determinant is reported with a negative x scale.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Projective`] if the bottom row is not `(0, 0, 0, 1)`,
+    /// [`Error::Degenerate`] if an axis has zero length and [`Error::Shear`] if the axes are not
+    /// perpendicular.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat4::Mat4f;
+    /// use yavml::quat::Quatf;
+    /// use yavml::vec3::Vec3f;
+    /// let rotation = Quatf::from_axis_angle(Vec3f::Y, 0.5);
+    /// let matrix = Mat4f::from_scale_quat_translation(Vec3f::new(1.0, 2.0, 3.0), rotation, Vec3f::ONE);
+    /// let (scale, decomposed, translation) = matrix.to_scale_quat_translation().unwrap();
+    /// assert!((scale - Vec3f::new(1.0, 2.0, 3.0)).length() < 1e-5);
+    /// assert!(decomposed.abs_diff_eq(rotation, 1e-5));
+    /// assert_eq!(translation, Vec3f::ONE);
+    /// ```
+    pub fn to_scale_quat_translation(&self) -> Result<(Vec3f, Quatf, Vec3f), Error> {
+        let (scale, rotation, translation) = self.to_scale_rotation_translation()?;
+        Ok((scale, Quatf::from_mat3(&rotation), translation))
+    }
+
+    /// Returns `true` if every element of `self` and `rhs` differs by at most `max_abs_diff`
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.to_cols_array()
+            .iter()
+            .zip(rhs.to_cols_array().iter())
+            .all(|(a, b)| (a - b).abs() <= max_abs_diff)
+    }
+}
+
+impl Default for Mat4f {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Addition of matrices
+impl Add<Mat4f> for Mat4f {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_cols(
+            self.x_axis + rhs.x_axis,
+            self.y_axis + rhs.y_axis,
+            self.z_axis + rhs.z_axis,
+            self.w_axis + rhs.w_axis,
+        )
+    }
+}
+
+/// Subtraction of matrices
+impl Sub<Mat4f> for Mat4f {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_cols(
+            self.x_axis - rhs.x_axis,
+            self.y_axis - rhs.y_axis,
+            self.z_axis - rhs.z_axis,
+            self.w_axis - rhs.w_axis,
+        )
+    }
+}
+
+/// Multiplication of matrices
+impl Mul<Mat4f> for Mat4f {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_mat4(&rhs)
+    }
+}
+
+/// Multiplication assignment of matrices
+impl MulAssign<Mat4f> for Mat4f {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_mat4(&rhs);
+    }
+}
+
+/// Multiplication of a vector by a matrix
+impl Mul<Vec4f> for Mat4f {
+    type Output = Vec4f;
+
+    fn mul(self, rhs: Vec4f) -> Self::Output {
+        self.mul_vec4(rhs)
+    }
+}
+
+/// Multiplication of a matrix by an `f32`
+impl Mul<f32> for Mat4f {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self::from_cols(
+            self.x_axis * rhs,
+            self.y_axis * rhs,
+            self.z_axis * rhs,
+            self.w_axis * rhs,
+        )
+    }
+}
@@ -0,0 +1,8 @@
+pub mod mat4f;
+pub use mat4f::Mat4f;
+
+pub mod mat4d;
+pub use mat4d::Mat4d;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,415 @@
+use super::Mat4d;
+use super::Mat4f;
+use crate::affine::Affine3;
+use crate::mat3::Mat3f;
+use crate::quat::{Quatd, Quatf};
+use crate::vec3::Vec3d;
+use crate::vec3::Vec3f;
+use crate::vec4::Vec4f;
+
+mod mat4f_tests {
+    use super::Affine3;
+    use super::Mat3f;
+    use super::Mat4f;
+    use super::Quatf;
+    use super::Vec3f;
+    use super::Vec4f;
+    use crate::Error;
+    use core::f32::consts::FRAC_PI_2;
+
+    const EPSILON: f32 = 1e-5;
+
+    fn vec3_close(a: Vec3f, b: Vec3f) -> bool {
+        (a - b).length() < EPSILON as f64
+    }
+
+    fn sample() -> Mat4f {
+        Mat4f::from_cols_array([
+            2.0, 1.0, 0.0, 0.5, -1.0, 3.0, 1.0, 0.0, 0.0, 2.0, 4.0, 1.0, 1.0, 0.0, -2.0, 3.0,
+        ])
+    }
+
+    // Tests converting a `Mat4f` to and from a column-major array
+    #[test]
+    fn test_mat4f_cols_array() {
+        let arr: [f32; 16] = core::array::from_fn(|i| i as f32);
+        let matrix = Mat4f::from_cols_array(arr);
+        assert_eq!(matrix.w_axis, Vec4f::new(12.0, 13.0, 14.0, 15.0));
+        assert_eq!(matrix.row1(), Vec4f::new(1.0, 5.0, 9.0, 13.0));
+        assert_eq!(matrix.to_cols_array(), arr);
+        assert_eq!(matrix.transpose().transpose(), matrix);
+    }
+
+    // Tests the determinant against a value computed by hand
+    #[test]
+    fn test_mat4f_determinant() {
+        assert_eq!(sample().determinant(), 78.0);
+        assert_eq!(sample().transpose().determinant(), 78.0);
+        assert_eq!(Mat4f::IDENTITY.determinant(), 1.0);
+    }
+
+    // Tests that a matrix times its inverse is the identity
+    #[test]
+    fn test_mat4f_inverse() {
+        let matrix = sample();
+        assert!((matrix * matrix.inverse()).abs_diff_eq(Mat4f::IDENTITY, EPSILON));
+        assert!((matrix.inverse() * matrix).abs_diff_eq(Mat4f::IDENTITY, EPSILON));
+        let singular = Mat4f::from_cols(Vec4f::X, Vec4f::Y, Vec4f::X, Vec4f::W);
+        assert_eq!(singular.try_inverse(), None);
+    }
+
+    // Tests that points are translated but directions are not
+    #[test]
+    fn test_mat4f_point_vs_vector() {
+        let matrix = Mat4f::from_translation(Vec3f::new(1.0, 2.0, 3.0));
+        assert_eq!(
+            matrix.transform_point3(Vec3f::ONE),
+            Vec3f::new(2.0, 3.0, 4.0)
+        );
+        assert_eq!(matrix.transform_vector3(Vec3f::ONE), Vec3f::ONE);
+        let scale = Mat4f::from_scale(Vec3f::new(2.0, 3.0, 4.0));
+        assert_eq!(
+            scale.transform_vector3(Vec3f::ONE),
+            Vec3f::new(2.0, 3.0, 4.0)
+        );
+    }
+
+    // Tests converting to and from `Mat3f` and `Affine3`
+    #[test]
+    fn test_mat4f_conversions() {
+        let rotation = Mat3f::from_axis_angle(Vec3f::Y, 0.5);
+        assert_eq!(Mat4f::from_mat3(rotation).to_mat3(), rotation);
+        let affine = Affine3::from_mat3_translation(rotation, Vec3f::new(1.0, -2.0, 3.0));
+        let matrix: Mat4f = affine.into();
+        assert_eq!(Affine3::from(matrix), affine);
+        let point = Vec3f::new(0.3, 0.2, 0.1);
+        assert!(vec3_close(
+            matrix.transform_point3(point),
+            affine.transform_point(point)
+        ));
+        assert!(matrix
+            .inverse()
+            .abs_diff_eq(affine.inverse().to_mat4(), EPSILON));
+    }
+
+    // Tests the right-handed perspective projection against a reference matrix
+    #[test]
+    fn test_mat4f_perspective_rh_reference() {
+        let projection = Mat4f::perspective_rh(FRAC_PI_2, 2.0, 1.0, 11.0);
+        let reference = Mat4f::from_cols_array([
+            0.5, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, -1.1, -1.0, 0.0, 0.0, -1.1, 0.0,
+        ]);
+        assert!(projection.abs_diff_eq(reference, EPSILON));
+    }
+
+    // Tests the left-handed perspective projection against a reference matrix
+    #[test]
+    fn test_mat4f_perspective_lh_reference() {
+        let projection = Mat4f::perspective_lh(FRAC_PI_2, 2.0, 1.0, 11.0);
+        let reference = Mat4f::from_cols_array([
+            0.5, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.1, 1.0, 0.0, 0.0, -1.1, 0.0,
+        ]);
+        assert!(projection.abs_diff_eq(reference, EPSILON));
+    }
+
+    // Tests the OpenGL perspective projections against a reference matrix
+    #[test]
+    fn test_mat4f_perspective_gl_reference() {
+        let projection = Mat4f::perspective_rh_gl(FRAC_PI_2, 1.0, 1.0, 3.0);
+        let reference = Mat4f::from_cols_array([
+            1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, -2.0, -1.0, 0.0, 0.0, -3.0, 0.0,
+        ]);
+        assert!(projection.abs_diff_eq(reference, EPSILON));
+        let projection = Mat4f::perspective_lh_gl(FRAC_PI_2, 1.0, 1.0, 3.0);
+        let reference = Mat4f::from_cols_array([
+            1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 2.0, 1.0, 0.0, 0.0, -3.0, 0.0,
+        ]);
+        assert!(projection.abs_diff_eq(reference, EPSILON));
+    }
+
+    // Tests that every perspective projection maps the near and far planes to the right depth
+    #[test]
+    fn test_mat4f_perspective_depth_ranges() {
+        let (near, far) = (0.5, 100.0);
+        let cases = [
+            (Mat4f::perspective_rh(1.0, 1.5, near, far), -1.0, 0.0, 1.0),
+            (Mat4f::perspective_lh(1.0, 1.5, near, far), 1.0, 0.0, 1.0),
+            (
+                Mat4f::perspective_rh_gl(1.0, 1.5, near, far),
+                -1.0,
+                -1.0,
+                1.0,
+            ),
+            (
+                Mat4f::perspective_lh_gl(1.0, 1.5, near, far),
+                1.0,
+                -1.0,
+                1.0,
+            ),
+            (
+                Mat4f::perspective_reverse_rh(1.0, 1.5, near, far),
+                -1.0,
+                1.0,
+                0.0,
+            ),
+            (
+                Mat4f::perspective_reverse_lh(1.0, 1.5, near, far),
+                1.0,
+                1.0,
+                0.0,
+            ),
+        ];
+        for (projection, forward, near_depth, far_depth) in cases {
+            let projected_near = projection.project_point3(Vec3f::new(0.0, 0.0, forward * near));
+            let projected_far = projection.project_point3(Vec3f::new(0.0, 0.0, forward * far));
+            assert!((projected_near.z - near_depth).abs() < EPSILON);
+            assert!((projected_far.z - far_depth).abs() < 1e-4);
+        }
+    }
+
+    // Tests that the infinite projections approach their far depth at great distances
+    #[test]
+    fn test_mat4f_perspective_infinite() {
+        let cases = [
+            (
+                Mat4f::perspective_infinite_rh(1.0, 1.0, 0.1),
+                -1.0,
+                0.0,
+                1.0,
+            ),
+            (Mat4f::perspective_infinite_lh(1.0, 1.0, 0.1), 1.0, 0.0, 1.0),
+            (
+                Mat4f::perspective_infinite_reverse_rh(1.0, 1.0, 0.1),
+                -1.0,
+                1.0,
+                0.0,
+            ),
+            (
+                Mat4f::perspective_infinite_reverse_lh(1.0, 1.0, 0.1),
+                1.0,
+                1.0,
+                0.0,
+            ),
+        ];
+        for (projection, forward, near_depth, far_depth) in cases {
+            let projected_near = projection.project_point3(Vec3f::new(0.0, 0.0, forward * 0.1));
+            let projected_far = projection.project_point3(Vec3f::new(0.0, 0.0, forward * 1e6));
+            assert!((projected_near.z - near_depth).abs() < EPSILON);
+            assert!((projected_far.z - far_depth).abs() < 1e-4);
+        }
+    }
+
+    // Tests that a perspective projection maps the frustum corners to the edges of clip space
+    #[test]
+    fn test_mat4f_perspective_frustum_edges() {
+        let projection = Mat4f::perspective_rh(FRAC_PI_2, 2.0, 1.0, 10.0);
+        let corner = projection.project_point3(Vec3f::new(-4.0, 2.0, -2.0));
+        assert!(vec3_close(corner, Vec3f::new(-1.0, 1.0, corner.z)));
+    }
+
+    // Tests the orthographic projections against reference matrices
+    #[test]
+    fn test_mat4f_orthographic_reference() {
+        let rh = Mat4f::orthographic_rh(-1.0, 3.0, -2.0, 2.0, 1.0, 5.0);
+        let reference = Mat4f::from_cols_array([
+            0.5, 0.0, 0.0, 0.0, 0.0, 0.5, 0.0, 0.0, 0.0, 0.0, -0.25, 0.0, -0.5, 0.0, -0.25, 1.0,
+        ]);
+        assert!(rh.abs_diff_eq(reference, EPSILON));
+        let lh = Mat4f::orthographic_lh(-1.0, 3.0, -2.0, 2.0, 1.0, 5.0);
+        let reference = Mat4f::from_cols_array([
+            0.5, 0.0, 0.0, 0.0, 0.0, 0.5, 0.0, 0.0, 0.0, 0.0, 0.25, 0.0, -0.5, 0.0, -0.25, 1.0,
+        ]);
+        assert!(lh.abs_diff_eq(reference, EPSILON));
+        let rh_gl = Mat4f::orthographic_rh_gl(-1.0, 3.0, -2.0, 2.0, 1.0, 5.0);
+        let reference = Mat4f::from_cols_array([
+            0.5, 0.0, 0.0, 0.0, 0.0, 0.5, 0.0, 0.0, 0.0, 0.0, -0.5, 0.0, -0.5, 0.0, -1.5, 1.0,
+        ]);
+        assert!(rh_gl.abs_diff_eq(reference, EPSILON));
+    }
+
+    // Tests that the orthographic projections map the view box to the right depth ranges
+    #[test]
+    fn test_mat4f_orthographic_depth_ranges() {
+        let cases = [
+            (
+                Mat4f::orthographic_rh(-1.0, 1.0, -1.0, 1.0, 2.0, 8.0),
+                -1.0,
+                0.0,
+            ),
+            (
+                Mat4f::orthographic_lh(-1.0, 1.0, -1.0, 1.0, 2.0, 8.0),
+                1.0,
+                0.0,
+            ),
+            (
+                Mat4f::orthographic_rh_gl(-1.0, 1.0, -1.0, 1.0, 2.0, 8.0),
+                -1.0,
+                -1.0,
+            ),
+            (
+                Mat4f::orthographic_lh_gl(-1.0, 1.0, -1.0, 1.0, 2.0, 8.0),
+                1.0,
+                -1.0,
+            ),
+        ];
+        for (projection, forward, near_depth) in cases {
+            let near = projection.project_point3(Vec3f::new(1.0, -1.0, forward * 2.0));
+            let far = projection.project_point3(Vec3f::new(-1.0, 1.0, forward * 8.0));
+            assert!(vec3_close(near, Vec3f::new(1.0, -1.0, near_depth)));
+            assert!(vec3_close(far, Vec3f::new(-1.0, 1.0, 1.0)));
+        }
+    }
+
+    // Tests the right-handed view matrix against a reference matrix
+    #[test]
+    fn test_mat4f_look_at_rh_reference() {
+        let view = Mat4f::look_at_rh(
+            Vec3f::new(1.0, 2.0, 3.0),
+            Vec3f::new(1.0, 2.0, 0.0),
+            Vec3f::Y,
+        );
+        assert!(view.abs_diff_eq(
+            Mat4f::from_translation(Vec3f::new(-1.0, -2.0, -3.0)),
+            EPSILON
+        ));
+
+        let view = Mat4f::look_at_rh(Vec3f::ZERO, Vec3f::X, Vec3f::Y);
+        let reference = Mat4f::from_cols_array([
+            0.0, 0.0, -1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+        ]);
+        assert!(view.abs_diff_eq(reference, EPSILON));
+    }
+
+    // Tests the left-handed view matrix against a reference matrix
+    #[test]
+    fn test_mat4f_look_at_lh_reference() {
+        let view = Mat4f::look_at_lh(
+            Vec3f::new(1.0, 2.0, -3.0),
+            Vec3f::new(1.0, 2.0, 0.0),
+            Vec3f::Y,
+        );
+        assert!(view.abs_diff_eq(
+            Mat4f::from_translation(Vec3f::new(-1.0, -2.0, 3.0)),
+            EPSILON
+        ));
+
+        let view = Mat4f::look_at_lh(Vec3f::ZERO, Vec3f::X, Vec3f::Y);
+        let reference = Mat4f::from_cols_array([
+            0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+        ]);
+        assert!(view.abs_diff_eq(reference, EPSILON));
+    }
+
+    // Tests that the view matrix puts the target in front of the camera
+    #[test]
+    fn test_mat4f_look_at_target_in_front() {
+        let eye = Vec3f::new(4.0, 3.0, -2.0);
+        let target = Vec3f::new(-1.0, 0.5, 6.0);
+        let distance = (target - eye).length() as f32;
+        let rh = Mat4f::look_at_rh(eye, target, Vec3f::Y).transform_point3(target);
+        let lh = Mat4f::look_at_lh(eye, target, Vec3f::Y).transform_point3(target);
+        assert!(vec3_close(rh, Vec3f::new(0.0, 0.0, -distance)));
+        assert!(vec3_close(lh, Vec3f::new(0.0, 0.0, distance)));
+    }
+
+    // Tests that decomposing and recomposing a transform round-trips, including negative scale
+    #[test]
+    fn test_mat4f_scale_rotation_translation_round_trip() {
+        let rotation = Mat3f::from_axis_angle(Vec3f::new(2.0, 3.0, 6.0) / 7.0, -0.9);
+        let translation = Vec3f::new(10.0, -20.0, 30.0);
+        for scale in [Vec3f::new(1.0, 1.0, 1.0), Vec3f::new(-2.0, 0.25, 8.0)] {
+            let matrix = Mat4f::from_scale_rotation_translation(scale, rotation, translation);
+            let (out_scale, out_rotation, out_translation) =
+                matrix.to_scale_rotation_translation().unwrap();
+            assert!(vec3_close(out_scale, scale));
+            assert!(out_rotation.abs_diff_eq(rotation, EPSILON));
+            assert_eq!(out_translation, translation);
+            let recomposed =
+                Mat4f::from_scale_rotation_translation(out_scale, out_rotation, out_translation);
+            assert!(recomposed.abs_diff_eq(matrix, 1e-4));
+        }
+    }
+
+    // Tests that decomposing into a quaternion and recomposing round-trips, including negative
+    // scale
+    #[test]
+    fn test_mat4f_scale_quat_translation_round_trip() {
+        let rotation = Quatf::from_axis_angle(Vec3f::new(2.0, 3.0, 6.0) / 7.0, -0.9);
+        let translation = Vec3f::new(10.0, -20.0, 30.0);
+        for scale in [Vec3f::new(1.0, 1.0, 1.0), Vec3f::new(-2.0, 0.25, 8.0)] {
+            let matrix = Mat4f::from_scale_quat_translation(scale, rotation, translation);
+            let expected =
+                Mat4f::from_scale_rotation_translation(scale, rotation.to_mat3(), translation);
+            assert!(matrix.abs_diff_eq(expected, EPSILON));
+            let (out_scale, out_rotation, out_translation) =
+                matrix.to_scale_quat_translation().unwrap();
+            assert!(vec3_close(out_scale, scale));
+            assert!(out_rotation.abs_diff_eq(rotation, EPSILON));
+            assert_eq!(out_translation, translation);
+            let recomposed =
+                Mat4f::from_scale_quat_translation(out_scale, out_rotation, out_translation);
+            assert!(recomposed.abs_diff_eq(matrix, 1e-4));
+        }
+        let projection = Mat4f::perspective_rh(1.0, 1.0, 0.1, 10.0);
+        assert_eq!(
+            projection.to_scale_quat_translation(),
+            Err(Error::Projective)
+        );
+    }
+
+    // Tests that projective, sheared and degenerate transforms are reported
+    #[test]
+    fn test_mat4f_to_scale_rotation_translation_errors() {
+        let projection = Mat4f::perspective_rh(1.0, 1.0, 0.1, 10.0);
+        assert_eq!(
+            projection.to_scale_rotation_translation(),
+            Err(Error::Projective)
+        );
+        let mut shear = Mat4f::IDENTITY;
+        shear.y_axis.x = 1.0;
+        assert_eq!(shear.to_scale_rotation_translation(), Err(Error::Shear));
+        let degenerate = Mat4f::from_scale(Vec3f::new(1.0, 1.0, 0.0));
+        assert_eq!(
+            degenerate.to_scale_rotation_translation(),
+            Err(Error::Degenerate)
+        );
+    }
+}
+
+mod mat4d_tests {
+    use super::Mat4d;
+    use super::Quatd;
+    use super::Vec3d;
+    use core::f64::consts::FRAC_PI_2;
+
+    // Tests that a matrix times its inverse is the identity
+    #[test]
+    fn test_mat4d_inverse() {
+        let matrix = Mat4d::from_cols_array([
+            2.0, 1.0, 0.0, 0.5, -1.0, 3.0, 1.0, 0.0, 0.0, 2.0, 4.0, 1.0, 1.0, 0.0, -2.0, 3.0,
+        ]);
+        assert_eq!(matrix.determinant(), 78.0);
+        assert!((matrix * matrix.inverse()).abs_diff_eq(Mat4d::IDENTITY, 1e-12));
+    }
+
+    // Tests the projection of a point through a `Mat4d` perspective projection
+    #[test]
+    fn test_mat4d_project_point3() {
+        let projection = Mat4d::perspective_rh(FRAC_PI_2, 1.0, 1.0, 10.0);
+        let projected = projection.project_point3(Vec3d::new(1.0, -1.0, -1.0));
+        assert!((projected - Vec3d::new(1.0, -1.0, 0.0)).length() < 1e-12);
+    }
+
+    // Tests that decomposing into a quaternion and recomposing round-trips
+    #[test]
+    fn test_mat4d_scale_quat_translation_round_trip() {
+        let rotation = Quatd::from_axis_angle(Vec3d::new(2.0, 3.0, 6.0) / 7.0, 2.4);
+        let (scale, translation) = (Vec3d::new(0.5, 4.0, 2.0), Vec3d::new(-1.0, 2.0, 5.0));
+        let matrix = Mat4d::from_scale_quat_translation(scale, rotation, translation);
+        let (out_scale, out_rotation, out_translation) =
+            matrix.to_scale_quat_translation().unwrap();
+        assert!((out_scale - scale).length() < 1e-12);
+        assert!(out_rotation.abs_diff_eq(rotation, 1e-12));
+        assert_eq!(out_translation, translation);
+    }
+}
@@ -0,0 +1,278 @@
+use core::ops::*;
+
+use super::{Lu, VectorN};
+use crate::error::Error;
+use crate::scalar::{Float, Scalar};
+
+/// A dense column-major matrix with `R` rows and `C` columns of type `T`
+///
+/// Elements are indexed as `matrix[(row, col)]`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Matrix<T, const R: usize, const C: usize> {
+    /// The columns of the matrix
+    pub cols: [[T; R]; C],
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Creates a new matrix from its columns
+    pub const fn from_cols(cols: [[T; R]; C]) -> Self {
+        Self { cols }
+    }
+
+    /// Creates a new matrix from its rows
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::matrix::Matrix;
+    /// let matrix = Matrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(matrix[(0, 2)], 3);
+    /// assert_eq!(matrix.cols[2], [3, 6]);
+    /// ```
+    pub fn from_rows(rows: [[T; C]; R]) -> Self {
+        let mut res = Self::zeros();
+        for (r, row) in rows.iter().enumerate() {
+            for (c, val) in row.iter().enumerate() {
+                res.cols[c][r] = *val;
+            }
+        }
+        res
+    }
+
+    /// Creates a matrix with every element set to zero
+    pub fn zeros() -> Self {
+        Self::from_cols([[T::ZERO; R]; C])
+    }
+
+    /// Creates a matrix with ones along its diagonal and zeros everywhere else
+    pub fn identity() -> Self {
+        let mut res = Self::zeros();
+        for i in 0..R.min(C) {
+            res.cols[i][i] = T::ONE;
+        }
+        res
+    }
+
+    /// Returns the row at `index`
+    pub fn row(&self, index: usize) -> VectorN<T, C> {
+        VectorN::new(core::array::from_fn(|c| self.cols[c][index]))
+    }
+
+    /// Returns the column at `index`
+    pub fn col(&self, index: usize) -> VectorN<T, R> {
+        VectorN::new(self.cols[index])
+    }
+
+    /// Returns the transpose of the matrix
+    pub fn transpose(&self) -> Matrix<T, C, R> {
+        Matrix::from_cols(core::array::from_fn(|r| self.row(r).data))
+    }
+
+    /// Swaps the rows at `a` and `b`
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        for col in self.cols.iter_mut() {
+            col.swap(a, b);
+        }
+    }
+}
+
+impl<T: Float, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Returns the largest absolute value of any element
+    pub fn max_abs(&self) -> T {
+        self.cols.iter().flatten().fold(
+            T::ZERO,
+            |max, val| if val.abs() > max { val.abs() } else { max },
+        )
+    }
+
+    /// Returns the numerical rank of the matrix
+    ///
+    /// The rank is estimated with Gaussian elimination using complete pivoting. Pivots no larger
+    /// than `max(R, C) * EPSILON` times the largest element are treated as zero.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::matrix::Matrix;
+    /// let matrix = Matrix::from_rows([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]);
+    /// assert_eq!(matrix.rank(), 1);
+    /// ```
+    pub fn rank(&self) -> usize {
+        let tolerance = self.max_abs() * T::from_usize(R.max(C)) * T::EPSILON;
+        let mut a = *self;
+        let mut col_order: [usize; C] = core::array::from_fn(|c| c);
+        let mut rank = 0;
+        while rank < R.min(C) {
+            let (mut pivot_row, mut pivot_col, mut pivot) = (rank, rank, T::ZERO);
+            for r in rank..R {
+                for (c, &col) in col_order.iter().enumerate().skip(rank) {
+                    let val = a.cols[col][r].abs();
+                    if val > pivot {
+                        (pivot_row, pivot_col, pivot) = (r, c, val);
+                    }
+                }
+            }
+            if pivot <= tolerance {
+                break;
+            }
+            a.swap_rows(rank, pivot_row);
+            col_order.swap(rank, pivot_col);
+            let pivot_val = a.cols[col_order[rank]][rank];
+            for r in rank + 1..R {
+                let factor = a.cols[col_order[rank]][r] / pivot_val;
+                for &col in &col_order[rank..] {
+                    let sub = factor * a.cols[col][rank];
+                    a.cols[col][r] -= sub;
+                }
+            }
+            rank += 1;
+        }
+        rank
+    }
+
+    /// Returns `true` if every element of `self` and `rhs` differs by at most `max_abs_diff`
+    pub fn abs_diff_eq(&self, rhs: &Self, max_abs_diff: T) -> bool {
+        self.cols
+            .iter()
+            .flatten()
+            .zip(rhs.cols.iter().flatten())
+            .all(|(a, b)| (*a - *b).abs() <= max_abs_diff)
+    }
+}
+
+impl<T: Float, const N: usize> Matrix<T, N, N> {
+    /// Returns the LU decomposition of the matrix with partial pivoting
+    pub fn lu(&self) -> Lu<T, N> {
+        Lu::new(self)
+    }
+
+    /// Returns the determinant of the matrix
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::matrix::Matrix;
+    /// let matrix = Matrix::from_rows([[2.0f64, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]);
+    /// assert!((matrix.determinant() - 6.0).abs() < 1e-12);
+    /// ```
+    pub fn determinant(&self) -> T {
+        self.lu().determinant()
+    }
+
+    /// Returns the inverse of the matrix
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if the matrix is not invertible
+    pub fn inverse(&self) -> Result<Self, Error> {
+        self.lu().inverse()
+    }
+
+    /// Solves `self * x = b` for `x`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if the system does not have a unique solution
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::matrix::{Matrix, VectorN};
+    /// use yavml::Error;
+    /// let matrix = Matrix::from_rows([[2.0, 1.0], [1.0, 3.0]]);
+    /// let x = matrix.solve(&VectorN::new([3.0, 5.0])).unwrap();
+    /// assert!(x.abs_diff_eq(&VectorN::new([0.8, 1.4]), 1e-12));
+    ///
+    /// let singular = Matrix::from_rows([[1.0, 2.0], [2.0, 4.0]]);
+    /// assert_eq!(singular.solve(&VectorN::new([1.0, 1.0])), Err(Error::Singular));
+    /// ```
+    pub fn solve(&self, b: &VectorN<T, N>) -> Result<VectorN<T, N>, Error> {
+        self.lu().solve(b)
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Default for Matrix<T, R, C> {
+    fn default() -> Self {
+        Self::zeros()
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<T, R, C> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        &self.cols[col][row]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<T, R, C> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        &mut self.cols[col][row]
+    }
+}
+
+/// Addition of matrices
+impl<T: Scalar, const R: usize, const C: usize> Add<Matrix<T, R, C>> for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        for (a, b) in self
+            .cols
+            .iter_mut()
+            .flatten()
+            .zip(rhs.cols.iter().flatten())
+        {
+            *a += *b;
+        }
+        self
+    }
+}
+
+/// Subtraction of matrices
+impl<T: Scalar, const R: usize, const C: usize> Sub<Matrix<T, R, C>> for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        for (a, b) in self
+            .cols
+            .iter_mut()
+            .flatten()
+            .zip(rhs.cols.iter().flatten())
+        {
+            *a -= *b;
+        }
+        self
+    }
+}
+
+/// Multiplication of a matrix by a scalar
+impl<T: Scalar, const R: usize, const C: usize> Mul<T> for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self.cols.iter_mut().flatten().for_each(|a| *a *= rhs);
+        self
+    }
+}
+
+/// Multiplication of matrices
+impl<T: Scalar, const R: usize, const C: usize, const K: usize> Mul<Matrix<T, C, K>>
+    for Matrix<T, R, C>
+{
+    type Output = Matrix<T, R, K>;
+
+    fn mul(self, rhs: Matrix<T, C, K>) -> Self::Output {
+        Matrix::from_cols(rhs.cols.map(|col| (self * VectorN::new(col)).data))
+    }
+}
+
+/// Multiplication of a vector by a matrix
+impl<T: Scalar, const R: usize, const C: usize> Mul<VectorN<T, C>> for Matrix<T, R, C> {
+    type Output = VectorN<T, R>;
+
+    fn mul(self, rhs: VectorN<T, C>) -> Self::Output {
+        let mut res = VectorN::zeros();
+        for (col, scale) in self.cols.iter().zip(rhs.data) {
+            for (out, val) in res.data.iter_mut().zip(col) {
+                *out += *val * scale;
+            }
+        }
+        res
+    }
+}
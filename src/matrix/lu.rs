@@ -0,0 +1,147 @@
+use super::{Matrix, VectorN};
+use crate::error::Error;
+use crate::scalar::Float;
+
+/// The LU decomposition of a square matrix with partial pivoting, `P * A = L * U`
+///
+/// `L` is unit lower triangular and `U` is upper triangular. Both are packed into one matrix.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lu<T, const N: usize> {
+    /// `L` below the diagonal and `U` on and above it
+    lu: Matrix<T, N, N>,
+    /// Row `i` of `P * A` is row `perm[i]` of `A`
+    perm: [usize; N],
+    /// Whether `P` swaps an odd number of rows
+    odd_swaps: bool,
+    /// Pivots with an absolute value at or below this are treated as zero
+    tolerance: T,
+}
+
+impl<T: Float, const N: usize> Lu<T, N> {
+    /// Decomposes `matrix`
+    ///
+    /// The decomposition always succeeds. Singular matrices produce a zero pivot on the diagonal
+    /// of `U`, which is reported by [`Lu::solve`] and [`Lu::inverse`].
+    pub fn new(matrix: &Matrix<T, N, N>) -> Self {
+        let mut lu = *matrix;
+        let mut perm: [usize; N] = core::array::from_fn(|i| i);
+        let mut odd_swaps = false;
+        let tolerance = matrix.max_abs() * T::from_usize(N) * T::EPSILON;
+
+        for k in 0..N {
+            let mut pivot_row = k;
+            for r in k + 1..N {
+                if lu[(r, k)].abs() > lu[(pivot_row, k)].abs() {
+                    pivot_row = r;
+                }
+            }
+            if pivot_row != k {
+                lu.swap_rows(k, pivot_row);
+                perm.swap(k, pivot_row);
+                odd_swaps = !odd_swaps;
+            }
+            let pivot = lu[(k, k)];
+            if pivot.abs() <= tolerance {
+                continue;
+            }
+            for r in k + 1..N {
+                let factor = lu[(r, k)] / pivot;
+                lu[(r, k)] = factor;
+                for c in k + 1..N {
+                    let sub = factor * lu[(k, c)];
+                    lu[(r, c)] -= sub;
+                }
+            }
+        }
+
+        Self {
+            lu,
+            perm,
+            odd_swaps,
+            tolerance,
+        }
+    }
+
+    /// Returns `true` if the decomposed matrix is singular
+    pub fn is_singular(&self) -> bool {
+        (0..N).any(|i| self.lu[(i, i)].abs() <= self.tolerance)
+    }
+
+    /// Returns the unit lower triangular factor `L`
+    pub fn l(&self) -> Matrix<T, N, N> {
+        let mut l = Matrix::identity();
+        for c in 0..N {
+            for r in c + 1..N {
+                l[(r, c)] = self.lu[(r, c)];
+            }
+        }
+        l
+    }
+
+    /// Returns the upper triangular factor `U`
+    pub fn u(&self) -> Matrix<T, N, N> {
+        let mut u = Matrix::zeros();
+        for c in 0..N {
+            for r in 0..=c {
+                u[(r, c)] = self.lu[(r, c)];
+            }
+        }
+        u
+    }
+
+    /// Returns the row permutation, where row `i` of `P * A` is row `permutation()[i]` of `A`
+    pub fn permutation(&self) -> [usize; N] {
+        self.perm
+    }
+
+    /// Returns the determinant of the decomposed matrix
+    pub fn determinant(&self) -> T {
+        let det = (0..N).fold(T::ONE, |det, i| det * self.lu[(i, i)]);
+        if self.odd_swaps {
+            -det
+        } else {
+            det
+        }
+    }
+
+    /// Solves `A * x = b` for `x`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if the decomposed matrix is singular
+    pub fn solve(&self, b: &VectorN<T, N>) -> Result<VectorN<T, N>, Error> {
+        if self.is_singular() {
+            return Err(Error::Singular);
+        }
+        let mut x = VectorN::new(self.perm.map(|i| b[i]));
+        for r in 0..N {
+            for c in 0..r {
+                let sub = self.lu[(r, c)] * x[c];
+                x[r] -= sub;
+            }
+        }
+        for r in (0..N).rev() {
+            for c in r + 1..N {
+                let sub = self.lu[(r, c)] * x[c];
+                x[r] -= sub;
+            }
+            x[r] /= self.lu[(r, r)];
+        }
+        Ok(x)
+    }
+
+    /// Returns the inverse of the decomposed matrix
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Singular`] if the decomposed matrix is singular
+    pub fn inverse(&self) -> Result<Matrix<T, N, N>, Error> {
+        let mut inverse = Matrix::zeros();
+        for c in 0..N {
+            let mut e = VectorN::zeros();
+            e[c] = T::ONE;
+            inverse.cols[c] = self.solve(&e)?.data;
+        }
+        Ok(inverse)
+    }
+}
@@ -0,0 +1,11 @@
+pub mod dense;
+pub use dense::Matrix;
+
+pub mod vectorn;
+pub use vectorn::VectorN;
+
+pub mod lu;
+pub use lu::Lu;
+
+#[cfg(test)]
+mod tests;
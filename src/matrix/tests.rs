@@ -0,0 +1,109 @@
+use super::Matrix;
+use super::VectorN;
+
+mod matrix_tests {
+    use super::Matrix;
+    use super::VectorN;
+    use crate::Error;
+
+    // Tests indexing, building from rows and transposing a non-square matrix
+    #[test]
+    fn test_matrix_from_rows_transpose() {
+        let matrix = Matrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(matrix[(1, 0)], 4);
+        assert_eq!(matrix.row(1), VectorN::new([4, 5, 6]));
+        assert_eq!(matrix.col(2), VectorN::new([3, 6]));
+        assert_eq!(
+            matrix.transpose(),
+            Matrix::from_rows([[1, 4], [2, 5], [3, 6]])
+        );
+    }
+
+    // Tests multiplying matrices of different shapes and a matrix by a vector
+    #[test]
+    fn test_matrix_mul() {
+        let a = Matrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+        let b = Matrix::from_rows([[7, 8], [9, 10], [11, 12]]);
+        assert_eq!(a * b, Matrix::from_rows([[58, 64], [139, 154]]));
+        assert_eq!(a * VectorN::new([1, 0, -1]), VectorN::new([-2, -2]));
+        assert_eq!(a * Matrix::<i32, 3, 3>::identity(), a);
+        assert_eq!(a + a - a, a);
+        assert_eq!(a * 2, Matrix::from_rows([[2, 4, 6], [8, 10, 12]]));
+    }
+
+    // Tests that the LU factors reproduce the permuted input
+    #[test]
+    fn test_matrix_lu_factors() {
+        let matrix = Matrix::from_rows([[0.0f64, 2.0, 1.0], [4.0, 1.0, -1.0], [2.0, 3.0, 5.0]]);
+        let lu = matrix.lu();
+        let perm = lu.permutation();
+        let permuted = Matrix::from_rows(core::array::from_fn(|i| matrix.row(perm[i]).data));
+        assert!((lu.l() * lu.u()).abs_diff_eq(&permuted, 1e-12));
+        assert_eq!(perm[0], 1);
+        assert!(!lu.is_singular());
+    }
+
+    // Tests the determinant against a cofactor expansion, including the sign of row swaps
+    #[test]
+    fn test_matrix_determinant() {
+        let matrix = Matrix::from_rows([[0.0f64, 2.0, 1.0], [4.0, 1.0, -1.0], [2.0, 3.0, 5.0]]);
+        assert!((matrix.determinant() - -34.0).abs() < 1e-12);
+        let swapped = Matrix::from_rows([[0.0, 1.0], [1.0, 0.0]]);
+        assert_eq!(swapped.determinant(), -1.0);
+        assert_eq!(Matrix::<f64, 4, 4>::identity().determinant(), 1.0);
+    }
+
+    // Tests solving a 4x4 system and that the inverse times the matrix is the identity
+    #[test]
+    fn test_matrix_solve_inverse() {
+        let matrix = Matrix::from_rows([
+            [4.0f64, -2.0, 1.0, 3.0],
+            [3.0, 6.0, -4.0, 2.0],
+            [2.0, 1.0, 8.0, -5.0],
+            [1.0, -3.0, 2.0, 7.0],
+        ]);
+        let expected = VectorN::new([1.0, -2.0, 3.0, 0.5]);
+        let b = matrix * expected;
+        assert!(matrix.solve(&b).unwrap().abs_diff_eq(&expected, 1e-12));
+
+        let inverse = matrix.inverse().unwrap();
+        assert!((inverse * matrix).abs_diff_eq(&Matrix::identity(), 1e-12));
+        assert!((matrix * inverse).abs_diff_eq(&Matrix::identity(), 1e-12));
+    }
+
+    // Tests solving in single precision
+    #[test]
+    fn test_matrix_solve_f32() {
+        let matrix = Matrix::from_rows([[2.0f32, 1.0], [1.0, 3.0]]);
+        let x = matrix.solve(&VectorN::new([3.0, 5.0])).unwrap();
+        assert!(x.abs_diff_eq(&VectorN::new([0.8, 1.4]), 1e-6));
+    }
+
+    // Tests that singular and numerically singular systems report an error
+    #[test]
+    fn test_matrix_singular() {
+        let singular = Matrix::from_rows([[1.0f64, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        assert!(singular.lu().is_singular());
+        assert_eq!(singular.inverse(), Err(Error::Singular));
+        assert_eq!(
+            singular.solve(&VectorN::new([1.0, 2.0, 3.0])),
+            Err(Error::Singular)
+        );
+        assert_eq!(Matrix::<f64, 2, 2>::zeros().inverse(), Err(Error::Singular));
+        assert!(singular.determinant().abs() < 1e-12);
+    }
+
+    // Tests rank estimation for full, deficient and non-square matrices
+    #[test]
+    fn test_matrix_rank() {
+        assert_eq!(Matrix::<f64, 3, 3>::identity().rank(), 3);
+        assert_eq!(Matrix::<f64, 3, 3>::zeros().rank(), 0);
+        let singular = Matrix::from_rows([[1.0f64, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        assert_eq!(singular.rank(), 2);
+        let wide = Matrix::from_rows([[1.0, 0.0, 2.0, 1.0], [2.0, 0.0, 4.0, 2.0]]);
+        assert_eq!(wide.rank(), 1);
+        assert_eq!(wide.transpose().rank(), 1);
+        let tall = Matrix::from_rows([[1.0f32, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+        assert_eq!(tall.rank(), 2);
+    }
+}
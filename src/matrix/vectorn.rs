@@ -0,0 +1,124 @@
+use core::ops::*;
+
+use crate::scalar::{Float, Scalar};
+
+/// A vector with `N` values of type `T`, for use with [`Matrix`](super::Matrix)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VectorN<T, const N: usize> {
+    /// The values of the vector
+    pub data: [T; N],
+}
+
+impl<T: Scalar, const N: usize> VectorN<T, N> {
+    /// Creates a new vector from its values
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::matrix::VectorN;
+    /// let vector = VectorN::new([1.0, 2.0, 3.0]);
+    /// assert_eq!(vector[2], 3.0);
+    /// ```
+    pub const fn new(data: [T; N]) -> Self {
+        Self { data }
+    }
+
+    /// Creates a vector with every value set to zero
+    pub fn zeros() -> Self {
+        Self::splat(T::ZERO)
+    }
+
+    /// Creates a vector with every value set to `val`
+    pub fn splat(val: T) -> Self {
+        Self::new([val; N])
+    }
+
+    /// Create an array from the vector's values
+    pub const fn to_array(&self) -> [T; N] {
+        self.data
+    }
+
+    /// Returns the dot product of `self` and `rhs`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::matrix::VectorN;
+    /// assert_eq!(VectorN::new([1, 2, 3]).dot(&VectorN::new([4, 5, 6])), 32);
+    /// ```
+    pub fn dot(&self, rhs: &Self) -> T {
+        self.data
+            .iter()
+            .zip(rhs.data.iter())
+            .fold(T::ZERO, |acc, (a, b)| acc + *a * *b)
+    }
+}
+
+impl<T: Float, const N: usize> VectorN<T, N> {
+    /// Returns the length of the vector
+    pub fn length(&self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns `true` if every value of `self` and `rhs` differs by at most `max_abs_diff`
+    pub fn abs_diff_eq(&self, rhs: &Self, max_abs_diff: T) -> bool {
+        self.data
+            .iter()
+            .zip(rhs.data.iter())
+            .all(|(a, b)| (*a - *b).abs() <= max_abs_diff)
+    }
+}
+
+impl<T: Scalar, const N: usize> Default for VectorN<T, N> {
+    fn default() -> Self {
+        Self::zeros()
+    }
+}
+
+impl<T, const N: usize> Index<usize> for VectorN<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for VectorN<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+/// Addition of vectors
+impl<T: Scalar, const N: usize> Add<VectorN<T, N>> for VectorN<T, N> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.data
+            .iter_mut()
+            .zip(rhs.data)
+            .for_each(|(a, b)| *a += b);
+        self
+    }
+}
+
+/// Subtraction of vectors
+impl<T: Scalar, const N: usize> Sub<VectorN<T, N>> for VectorN<T, N> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self.data
+            .iter_mut()
+            .zip(rhs.data)
+            .for_each(|(a, b)| *a -= b);
+        self
+    }
+}
+
+/// Multiplication of a vector by a scalar
+impl<T: Scalar, const N: usize> Mul<T> for VectorN<T, N> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self.data.iter_mut().for_each(|a| *a *= rhs);
+        self
+    }
+}
@@ -0,0 +1,515 @@
+//! Multi-threaded bulk operations over slices, enabled with the `parallel` feature
+//!
+//! Work is split into chunks of [`ParallelConfig::chunk_size`] items which are handed out to
+//! workers spawned with [`std::thread::scope`]. The chunk boundaries only depend on the chunk
+//! size, never on the number of threads, and partial results of reductions are always combined
+//! in chunk order, so every operation returns bit-identical results for any thread count.
+
+use std::num::NonZeroUsize;
+use std::thread;
+
+use crate::mat4::{Mat4d, Mat4f};
+use crate::vec2::{Vec2d, Vec2f, Vec2i};
+use crate::vec3::{Vec3d, Vec3f};
+
+/// Controls how bulk operations split their work between threads
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParallelConfig {
+    /// The number of items processed as one unit of work
+    pub chunk_size: usize,
+    /// The maximum number of worker threads to spawn
+    pub threads: usize,
+}
+
+impl ParallelConfig {
+    /// The chunk size used by [`ParallelConfig::default`]
+    pub const DEFAULT_CHUNK_SIZE: usize = 16 * 1024;
+
+    /// Creates a new config with the given chunk size, using every available thread
+    ///
+    /// # Arguments
+    ///
+    /// * `chunk_size` - The number of items processed as one unit of work. A size of `0` is treated as `1`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::parallel::ParallelConfig;
+    /// let config = ParallelConfig::new(1024);
+    /// assert_eq!(config.chunk_size, 1024);
+    /// ```
+    pub fn new(chunk_size: usize) -> Self {
+        Self {
+            chunk_size: chunk_size.max(1),
+            threads: thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        }
+    }
+
+    /// Returns a copy of the config limited to `threads` worker threads
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::parallel::ParallelConfig;
+    /// let config = ParallelConfig::new(1024).with_threads(4);
+    /// assert_eq!(config.threads, 4);
+    /// ```
+    pub fn with_threads(self, threads: usize) -> Self {
+        Self {
+            threads: threads.max(1),
+            ..self
+        }
+    }
+
+    /// Returns how many chunks each worker handles for `len` items, and the number of workers
+    fn split(&self, len: usize) -> (usize, usize) {
+        let chunk_size = self.chunk_size.max(1);
+        let chunks = len.div_ceil(chunk_size);
+        let threads = self.threads.clamp(1, chunks.max(1));
+        (chunks.div_ceil(threads).max(1), threads)
+    }
+}
+
+impl Default for ParallelConfig {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CHUNK_SIZE)
+    }
+}
+
+/// Calls `f` on every item of `data`
+///
+/// # Examples:
+/// ```
+/// use yavml::parallel::{for_each_mut, ParallelConfig};
+/// use yavml::vec2::Vec2f;
+/// let mut points = vec![Vec2f::ONE; 100];
+/// for_each_mut(&mut points, &ParallelConfig::new(8), |p| *p *= 2.0);
+/// assert!(points.iter().all(|p| *p == Vec2f::splat(2.0)));
+/// ```
+pub fn for_each_mut<T, F>(data: &mut [T], config: &ParallelConfig, f: F)
+where
+    T: Send,
+    F: Fn(&mut T) + Sync,
+{
+    let (chunks_per_thread, threads) = config.split(data.len());
+    if threads == 1 {
+        data.iter_mut().for_each(f);
+        return;
+    }
+    let f = &f;
+    thread::scope(|scope| {
+        for work in data.chunks_mut(chunks_per_thread * config.chunk_size.max(1)) {
+            scope.spawn(move || work.iter_mut().for_each(f));
+        }
+    });
+}
+
+/// Writes `f(&src[i])` into `dst[i]` for every item of `src`
+///
+/// This is the building block for transforming large batches of points by a matrix.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths
+///
+/// # Examples:
+/// ```
+/// use yavml::parallel::{map_into, ParallelConfig};
+/// use yavml::vec2::{Vec2f, Vec2i};
+/// let points = vec![Vec2i::new(1, 2); 100];
+/// let mut out = vec![Vec2f::ZERO; 100];
+/// map_into(&points, &mut out, &ParallelConfig::new(8), |p| p.as_vec2f());
+/// assert!(out.iter().all(|p| *p == Vec2f::new(1.0, 2.0)));
+/// ```
+pub fn map_into<T, U, F>(src: &[T], dst: &mut [U], config: &ParallelConfig, f: F)
+where
+    T: Sync,
+    U: Send,
+    F: Fn(&T) -> U + Sync,
+{
+    assert_eq!(
+        src.len(),
+        dst.len(),
+        "source and destination lengths differ"
+    );
+    let (chunks_per_thread, threads) = config.split(src.len());
+    let map = |src: &[T], dst: &mut [U]| {
+        for (s, d) in src.iter().zip(dst.iter_mut()) {
+            *d = f(s);
+        }
+    };
+    if threads == 1 {
+        map(src, dst);
+        return;
+    }
+    let map = &map;
+    let work_size = chunks_per_thread * config.chunk_size.max(1);
+    thread::scope(|scope| {
+        for (s, d) in src.chunks(work_size).zip(dst.chunks_mut(work_size)) {
+            scope.spawn(move || map(s, d));
+        }
+    });
+}
+
+/// Reduces `data` by calling `map` on every chunk and folding the partial results with `combine`
+///
+/// Partial results are always folded from the first chunk to the last, so the result only
+/// depends on the chunk size. Returns `None` if `data` is empty.
+///
+/// # Examples:
+/// ```
+/// use yavml::parallel::{reduce, ParallelConfig};
+/// let values: Vec<u64> = (1..=1000).collect();
+/// let sum = reduce(&values, &ParallelConfig::new(64), |c| c.iter().sum::<u64>(), |a, b| a + b);
+/// assert_eq!(sum, Some(500500));
+/// ```
+pub fn reduce<T, A, M, C>(data: &[T], config: &ParallelConfig, map: M, combine: C) -> Option<A>
+where
+    T: Sync,
+    A: Send,
+    M: Fn(&[T]) -> A + Sync,
+    C: Fn(A, A) -> A,
+{
+    let chunk_size = config.chunk_size.max(1);
+    let (chunks_per_thread, threads) = config.split(data.len());
+    let partials: Vec<A> = if threads == 1 {
+        data.chunks(chunk_size).map(&map).collect()
+    } else {
+        let map = &map;
+        thread::scope(|scope| {
+            let workers: Vec<_> = data
+                .chunks(chunks_per_thread * chunk_size)
+                .map(|work| {
+                    scope.spawn(move || work.chunks(chunk_size).map(map).collect::<Vec<A>>())
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().expect("parallel worker panicked"))
+                .collect()
+        })
+    };
+    partials.into_iter().reduce(combine)
+}
+
+/// Returns the average of `points`, or `None` if `points` is empty
+///
+/// The sum is accumulated in `f64` to keep large batches accurate.
+///
+/// # Examples:
+/// ```
+/// use yavml::parallel::{centroid_vec2f, ParallelConfig};
+/// use yavml::vec2::Vec2f;
+/// let points = [Vec2f::new(0.0, 0.0), Vec2f::new(2.0, 0.0), Vec2f::new(2.0, 4.0), Vec2f::new(0.0, 4.0)];
+/// assert_eq!(centroid_vec2f(&points, &ParallelConfig::new(1)), Some(Vec2f::new(1.0, 2.0)));
+/// ```
+pub fn centroid_vec2f(points: &[Vec2f], config: &ParallelConfig) -> Option<Vec2f> {
+    let sum = reduce(
+        points,
+        config,
+        |chunk| chunk.iter().fold(Vec2d::ZERO, |acc, p| acc + p.as_vec2d()),
+        |a, b| a + b,
+    )?;
+    Some((sum / points.len() as f64).as_vec2f())
+}
+
+/// Returns the average of `points`, or `None` if `points` is empty
+///
+/// # Examples:
+/// ```
+/// use yavml::parallel::{centroid_vec2d, ParallelConfig};
+/// use yavml::vec2::Vec2d;
+/// let points = [Vec2d::new(0.0, 0.0), Vec2d::new(2.0, 0.0), Vec2d::new(2.0, 4.0), Vec2d::new(0.0, 4.0)];
+/// assert_eq!(centroid_vec2d(&points, &ParallelConfig::new(1)), Some(Vec2d::new(1.0, 2.0)));
+/// ```
+pub fn centroid_vec2d(points: &[Vec2d], config: &ParallelConfig) -> Option<Vec2d> {
+    let sum = reduce(
+        points,
+        config,
+        |chunk| chunk.iter().fold(Vec2d::ZERO, |acc, p| acc + *p),
+        |a, b| a + b,
+    )?;
+    Some(sum / points.len() as f64)
+}
+
+/// Returns the component-wise `(min, max)` of `points`, or `None` if `points` is empty
+///
+/// # Examples:
+/// ```
+/// use yavml::parallel::{bounds_vec2f, ParallelConfig};
+/// use yavml::vec2::Vec2f;
+/// let points = [Vec2f::new(1.0, -3.0), Vec2f::new(-2.0, 5.0), Vec2f::new(4.0, 0.0)];
+/// assert_eq!(
+///     bounds_vec2f(&points, &ParallelConfig::new(1)),
+///     Some((Vec2f::new(-2.0, -3.0), Vec2f::new(4.0, 5.0)))
+/// );
+/// ```
+pub fn bounds_vec2f(points: &[Vec2f], config: &ParallelConfig) -> Option<(Vec2f, Vec2f)> {
+    reduce(
+        points,
+        config,
+        |chunk| {
+            chunk
+                .iter()
+                .skip(1)
+                .fold((chunk[0], chunk[0]), |(min, max), p| {
+                    (
+                        Vec2f::new(min.x.min(p.x), min.y.min(p.y)),
+                        Vec2f::new(max.x.max(p.x), max.y.max(p.y)),
+                    )
+                })
+        },
+        |(min_a, max_a), (min_b, max_b)| {
+            (
+                Vec2f::new(min_a.x.min(min_b.x), min_a.y.min(min_b.y)),
+                Vec2f::new(max_a.x.max(max_b.x), max_a.y.max(max_b.y)),
+            )
+        },
+    )
+}
+
+/// Returns the component-wise `(min, max)` of `points`, or `None` if `points` is empty
+///
+/// # Examples:
+/// ```
+/// use yavml::parallel::{bounds_vec2d, ParallelConfig};
+/// use yavml::vec2::Vec2d;
+/// let points = [Vec2d::new(1.0, -3.0), Vec2d::new(-2.0, 5.0), Vec2d::new(4.0, 0.0)];
+/// assert_eq!(
+///     bounds_vec2d(&points, &ParallelConfig::new(1)),
+///     Some((Vec2d::new(-2.0, -3.0), Vec2d::new(4.0, 5.0)))
+/// );
+/// ```
+pub fn bounds_vec2d(points: &[Vec2d], config: &ParallelConfig) -> Option<(Vec2d, Vec2d)> {
+    reduce(
+        points,
+        config,
+        |chunk| {
+            chunk
+                .iter()
+                .skip(1)
+                .fold((chunk[0], chunk[0]), |(min, max), p| {
+                    (
+                        Vec2d::new(min.x.min(p.x), min.y.min(p.y)),
+                        Vec2d::new(max.x.max(p.x), max.y.max(p.y)),
+                    )
+                })
+        },
+        |(min_a, max_a), (min_b, max_b)| {
+            (
+                Vec2d::new(min_a.x.min(min_b.x), min_a.y.min(min_b.y)),
+                Vec2d::new(max_a.x.max(max_b.x), max_a.y.max(max_b.y)),
+            )
+        },
+    )
+}
+
+/// Returns the component-wise `(min, max)` of `points`, or `None` if `points` is empty
+///
+/// # Examples:
+/// ```
+/// use yavml::parallel::{bounds_vec2i, ParallelConfig};
+/// use yavml::vec2::Vec2i;
+/// let points = [Vec2i::new(1, -3), Vec2i::new(-2, 5), Vec2i::new(4, 0)];
+/// assert_eq!(
+///     bounds_vec2i(&points, &ParallelConfig::new(1)),
+///     Some((Vec2i::new(-2, -3), Vec2i::new(4, 5)))
+/// );
+/// ```
+pub fn bounds_vec2i(points: &[Vec2i], config: &ParallelConfig) -> Option<(Vec2i, Vec2i)> {
+    reduce(
+        points,
+        config,
+        |chunk| {
+            chunk
+                .iter()
+                .skip(1)
+                .fold((chunk[0], chunk[0]), |(min, max), p| {
+                    (
+                        Vec2i::new(min.x.min(p.x), min.y.min(p.y)),
+                        Vec2i::new(max.x.max(p.x), max.y.max(p.y)),
+                    )
+                })
+        },
+        |(min_a, max_a), (min_b, max_b)| {
+            (
+                Vec2i::new(min_a.x.min(min_b.x), min_a.y.min(min_b.y)),
+                Vec2i::new(max_a.x.max(max_b.x), max_a.y.max(max_b.y)),
+            )
+        },
+    )
+}
+
+/// Returns the average of `points`, or `None` if `points` is empty
+///
+/// The sum is accumulated in `f64` to keep large batches accurate.
+///
+/// # Examples:
+/// ```
+/// use yavml::parallel::{centroid_vec3f, ParallelConfig};
+/// use yavml::vec3::Vec3f;
+/// let points = [Vec3f::new(0.0, 0.0, 0.0), Vec3f::new(2.0, 4.0, 6.0)];
+/// assert_eq!(centroid_vec3f(&points, &ParallelConfig::new(1)), Some(Vec3f::new(1.0, 2.0, 3.0)));
+/// ```
+pub fn centroid_vec3f(points: &[Vec3f], config: &ParallelConfig) -> Option<Vec3f> {
+    let sum = reduce(
+        points,
+        config,
+        |chunk| chunk.iter().fold(Vec3d::ZERO, |acc, p| acc + p.as_vec3d()),
+        |a, b| a + b,
+    )?;
+    Some((sum / points.len() as f64).as_vec3f())
+}
+
+/// Returns the average of `points`, or `None` if `points` is empty
+///
+/// # Examples:
+/// ```
+/// use yavml::parallel::{centroid_vec3d, ParallelConfig};
+/// use yavml::vec3::Vec3d;
+/// let points = [Vec3d::new(0.0, 0.0, 0.0), Vec3d::new(2.0, 4.0, 6.0)];
+/// assert_eq!(centroid_vec3d(&points, &ParallelConfig::new(1)), Some(Vec3d::new(1.0, 2.0, 3.0)));
+/// ```
+pub fn centroid_vec3d(points: &[Vec3d], config: &ParallelConfig) -> Option<Vec3d> {
+    let sum = reduce(
+        points,
+        config,
+        |chunk| chunk.iter().fold(Vec3d::ZERO, |acc, p| acc + *p),
+        |a, b| a + b,
+    )?;
+    Some(sum / points.len() as f64)
+}
+
+/// Returns the component-wise `(min, max)` of `points`, or `None` if `points` is empty
+///
+/// # Examples:
+/// ```
+/// use yavml::parallel::{bounds_vec3f, ParallelConfig};
+/// use yavml::vec3::Vec3f;
+/// let points = [Vec3f::new(1.0, -3.0, 2.0), Vec3f::new(-2.0, 5.0, 0.0)];
+/// assert_eq!(
+///     bounds_vec3f(&points, &ParallelConfig::new(1)),
+///     Some((Vec3f::new(-2.0, -3.0, 0.0), Vec3f::new(1.0, 5.0, 2.0)))
+/// );
+/// ```
+pub fn bounds_vec3f(points: &[Vec3f], config: &ParallelConfig) -> Option<(Vec3f, Vec3f)> {
+    let min = |a: Vec3f, b: Vec3f| Vec3f::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z));
+    let max = |a: Vec3f, b: Vec3f| Vec3f::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z));
+    reduce(
+        points,
+        config,
+        |chunk| {
+            chunk
+                .iter()
+                .skip(1)
+                .fold((chunk[0], chunk[0]), |(lo, hi), p| {
+                    (min(lo, *p), max(hi, *p))
+                })
+        },
+        |(min_a, max_a), (min_b, max_b)| (min(min_a, min_b), max(max_a, max_b)),
+    )
+}
+
+/// Returns the component-wise `(min, max)` of `points`, or `None` if `points` is empty
+///
+/// # Examples:
+/// ```
+/// use yavml::parallel::{bounds_vec3d, ParallelConfig};
+/// use yavml::vec3::Vec3d;
+/// let points = [Vec3d::new(1.0, -3.0, 2.0), Vec3d::new(-2.0, 5.0, 0.0)];
+/// assert_eq!(
+///     bounds_vec3d(&points, &ParallelConfig::new(1)),
+///     Some((Vec3d::new(-2.0, -3.0, 0.0), Vec3d::new(1.0, 5.0, 2.0)))
+/// );
+/// ```
+pub fn bounds_vec3d(points: &[Vec3d], config: &ParallelConfig) -> Option<(Vec3d, Vec3d)> {
+    let min = |a: Vec3d, b: Vec3d| Vec3d::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z));
+    let max = |a: Vec3d, b: Vec3d| Vec3d::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z));
+    reduce(
+        points,
+        config,
+        |chunk| {
+            chunk
+                .iter()
+                .skip(1)
+                .fold((chunk[0], chunk[0]), |(lo, hi), p| {
+                    (min(lo, *p), max(hi, *p))
+                })
+        },
+        |(min_a, max_a), (min_b, max_b)| (min(min_a, min_b), max(max_a, max_b)),
+    )
+}
+
+/// Transforms every item of `points` in place as a 3D point with [`Mat4f::transform_point3`]
+///
+/// # Examples:
+/// ```
+/// use yavml::mat4::Mat4f;
+/// use yavml::parallel::{transform_points3f, ParallelConfig};
+/// use yavml::vec3::Vec3f;
+/// let mut points = vec![Vec3f::ONE; 100];
+/// let matrix = Mat4f::from_translation(Vec3f::new(1.0, 2.0, 3.0));
+/// transform_points3f(&matrix, &mut points, &ParallelConfig::new(8));
+/// assert!(points.iter().all(|p| *p == Vec3f::new(2.0, 3.0, 4.0)));
+/// ```
+pub fn transform_points3f(matrix: &Mat4f, points: &mut [Vec3f], config: &ParallelConfig) {
+    for_each_mut(points, config, |p| *p = matrix.transform_point3(*p));
+}
+
+/// Transforms every item of `points` in place as a 3D point with [`Mat4d::transform_point3`]
+pub fn transform_points3d(matrix: &Mat4d, points: &mut [Vec3d], config: &ParallelConfig) {
+    for_each_mut(points, config, |p| *p = matrix.transform_point3(*p));
+}
+
+/// Transforms every item of `vectors` in place as a 3D direction with
+/// [`Mat4f::transform_vector3`], so translation is ignored
+pub fn transform_vectors3f(matrix: &Mat4f, vectors: &mut [Vec3f], config: &ParallelConfig) {
+    for_each_mut(vectors, config, |v| *v = matrix.transform_vector3(*v));
+}
+
+/// Transforms every item of `vectors` in place as a 3D direction with
+/// [`Mat4d::transform_vector3`], so translation is ignored
+pub fn transform_vectors3d(matrix: &Mat4d, vectors: &mut [Vec3d], config: &ParallelConfig) {
+    for_each_mut(vectors, config, |v| *v = matrix.transform_vector3(*v));
+}
+
+/// Writes the points of `src` transformed by `matrix` into `dst`, leaving `src` untouched
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths
+///
+/// # Examples:
+/// ```
+/// use yavml::mat4::Mat4f;
+/// use yavml::parallel::{transform_points3f_into, ParallelConfig};
+/// use yavml::vec3::Vec3f;
+/// let points = vec![Vec3f::ONE; 100];
+/// let mut out = vec![Vec3f::ZERO; 100];
+/// let matrix = Mat4f::from_scale(Vec3f::splat(2.0));
+/// transform_points3f_into(&matrix, &points, &mut out, &ParallelConfig::new(8));
+/// assert!(out.iter().all(|p| *p == Vec3f::splat(2.0)));
+/// ```
+pub fn transform_points3f_into(
+    matrix: &Mat4f,
+    src: &[Vec3f],
+    dst: &mut [Vec3f],
+    config: &ParallelConfig,
+) {
+    map_into(src, dst, config, |p| matrix.transform_point3(*p));
+}
+
+/// Writes the points of `src` transformed by `matrix` into `dst`, leaving `src` untouched
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths
+pub fn transform_points3d_into(
+    matrix: &Mat4d,
+    src: &[Vec3d],
+    dst: &mut [Vec3d],
+    config: &ParallelConfig,
+) {
+    map_into(src, dst, config, |p| matrix.transform_point3(*p));
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,165 @@
+use super::*;
+
+mod parallel_tests {
+    use super::*;
+
+    fn points(count: usize) -> Vec<Vec2f> {
+        (0..count)
+            .map(|i| {
+                let t = i as f32 * 0.618_034;
+                Vec2f::new(t.sin() * 1000.0 + t, t.cos() * 0.001 - t)
+            })
+            .collect()
+    }
+
+    fn points3(count: usize) -> Vec<Vec3f> {
+        points(count)
+            .iter()
+            .enumerate()
+            .map(|(i, p)| Vec3f::new(p.x, p.y, (i as f32 * 0.414_213).sin() * 500.0))
+            .collect()
+    }
+
+    // Tests that the thread count never exceeds the number of chunks
+    #[test]
+    fn test_parallel_split() {
+        let config = ParallelConfig::new(10).with_threads(8);
+        assert_eq!(config.split(0), (1, 1));
+        assert_eq!(config.split(25), (1, 3));
+        assert_eq!(config.split(1000), (13, 8));
+    }
+
+    // Tests that `for_each_mut` visits every item exactly once
+    #[test]
+    fn test_parallel_for_each_mut() {
+        let mut data: Vec<u32> = (0..10_001).collect();
+        for_each_mut(&mut data, &ParallelConfig::new(7).with_threads(4), |v| {
+            *v += 1
+        });
+        assert!(data.iter().enumerate().all(|(i, v)| *v == i as u32 + 1));
+    }
+
+    // Tests that `map_into` keeps items in order
+    #[test]
+    fn test_parallel_map_into() {
+        let src = points(5_000);
+        let mut dst = vec![Vec2d::ZERO; src.len()];
+        map_into(
+            &src,
+            &mut dst,
+            &ParallelConfig::new(33).with_threads(5),
+            |p| p.as_vec2d(),
+        );
+        assert!(src.iter().zip(&dst).all(|(s, d)| s.as_vec2d() == *d));
+    }
+
+    // Tests that `map_into` rejects slices of different lengths
+    #[test]
+    #[should_panic]
+    fn test_parallel_map_into_length_mismatch() {
+        let mut dst = [0u8; 3];
+        map_into(&[1u8, 2], &mut dst, &ParallelConfig::new(1), |v| *v);
+    }
+
+    // Tests that reductions of an empty slice return `None`
+    #[test]
+    fn test_parallel_empty() {
+        let config = ParallelConfig::default();
+        assert_eq!(centroid_vec2f(&[], &config), None);
+        assert_eq!(bounds_vec2i(&[], &config), None);
+    }
+
+    // Tests that the centroid is identical for every thread count
+    #[test]
+    fn test_parallel_centroid_deterministic() {
+        let points = points(20_000);
+        let expected = centroid_vec2f(&points, &ParallelConfig::new(100).with_threads(1));
+        for threads in [2, 3, 7, 16, 64] {
+            let config = ParallelConfig::new(100).with_threads(threads);
+            assert_eq!(centroid_vec2f(&points, &config), expected);
+        }
+    }
+
+    // Tests that the bounds are identical for every thread count
+    #[test]
+    fn test_parallel_bounds_deterministic() {
+        let points: Vec<Vec2d> = points(20_000).iter().map(|p| p.as_vec2d()).collect();
+        let expected = bounds_vec2d(&points, &ParallelConfig::new(128).with_threads(1)).unwrap();
+        for threads in [2, 5, 9] {
+            let config = ParallelConfig::new(128).with_threads(threads);
+            assert_eq!(bounds_vec2d(&points, &config), Some(expected));
+        }
+        assert!(points.iter().all(|p| p.x >= expected.0.x
+            && p.y >= expected.0.y
+            && p.x <= expected.1.x
+            && p.y <= expected.1.y));
+    }
+
+    // Tests that the 3D centroid and bounds are identical for every thread count
+    #[test]
+    fn test_parallel_vec3_deterministic() {
+        let points = points3(20_000);
+        let single = ParallelConfig::new(100).with_threads(1);
+        let centroid = centroid_vec3f(&points, &single);
+        let bounds = bounds_vec3f(&points, &single).unwrap();
+        let points_d: Vec<Vec3d> = points.iter().map(|p| p.as_vec3d()).collect();
+        let centroid_d = centroid_vec3d(&points_d, &single);
+        let bounds_d = bounds_vec3d(&points_d, &single);
+        for threads in [2, 3, 7, 16] {
+            let config = ParallelConfig::new(100).with_threads(threads);
+            assert_eq!(centroid_vec3f(&points, &config), centroid);
+            assert_eq!(bounds_vec3f(&points, &config), Some(bounds));
+            assert_eq!(centroid_vec3d(&points_d, &config), centroid_d);
+            assert_eq!(bounds_vec3d(&points_d, &config), bounds_d);
+        }
+        assert!(points.iter().all(|p| p.x >= bounds.0.x
+            && p.y >= bounds.0.y
+            && p.z >= bounds.0.z
+            && p.x <= bounds.1.x
+            && p.y <= bounds.1.y
+            && p.z <= bounds.1.z));
+        assert_eq!(centroid_vec3d(&[], &single), None);
+        assert_eq!(bounds_vec3f(&[], &single), None);
+    }
+
+    // Tests that the matrix slice transforms match transforming each item on its own
+    #[test]
+    fn test_parallel_transform3() {
+        let matrix = Mat4f::from_translation(Vec3f::new(1.0, -2.0, 3.0))
+            .mul_mat4(&Mat4f::from_axis_angle(Vec3f::Z, 0.5))
+            .mul_mat4(&Mat4f::from_scale(Vec3f::new(2.0, 3.0, 4.0)));
+        let config = ParallelConfig::new(64).with_threads(4);
+        let src = points3(5_000);
+
+        let mut points = src.clone();
+        transform_points3f(&matrix, &mut points, &config);
+        assert!(src
+            .iter()
+            .zip(&points)
+            .all(|(s, p)| matrix.transform_point3(*s) == *p));
+        let mut out = vec![Vec3f::ZERO; src.len()];
+        transform_points3f_into(&matrix, &src, &mut out, &config);
+        assert_eq!(out, points);
+        let mut vectors = src.clone();
+        transform_vectors3f(&matrix, &mut vectors, &config);
+        assert!(src
+            .iter()
+            .zip(&vectors)
+            .all(|(s, v)| matrix.transform_vector3(*s) == *v));
+
+        let matrix_d = Mat4d::from_translation(Vec3d::new(1.0, -2.0, 3.0));
+        let src_d: Vec<Vec3d> = src.iter().map(|p| p.as_vec3d()).collect();
+        let mut points_d = src_d.clone();
+        transform_points3d(&matrix_d, &mut points_d, &config);
+        let mut out_d = vec![Vec3d::ZERO; src_d.len()];
+        transform_points3d_into(&matrix_d, &src_d, &mut out_d, &config);
+        assert_eq!(out_d, points_d);
+        let mut vectors_d = src_d.clone();
+        transform_vectors3d(&matrix_d, &mut vectors_d, &config);
+        assert_eq!(vectors_d, src_d);
+        assert!(src_d
+            .iter()
+            .zip(&points_d)
+            .all(|(s, p)| *p == *s + Vec3d::new(1.0, -2.0, 3.0)));
+    }
+}
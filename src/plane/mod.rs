@@ -0,0 +1,5 @@
+pub mod plane3f;
+pub use plane3f::{Plane, TriangleSplit};
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,226 @@
+use crate::mat4::Mat4f;
+use crate::vec3::Vec3f;
+use crate::vec4::Vec4f;
+
+/// A plane of `f32` values holding the points `p` where `normal.dot(p) + d == 0`
+///
+/// Points with a positive signed distance are in front of the plane, on the side `normal`
+/// points to. The constructors produce a unit `normal`, which makes signed distances true
+/// distances; a plane built with [`Plane::new`] keeps whatever normal it is given.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Plane {
+    /// The normal of the plane
+    pub normal: Vec3f,
+    /// The offset of the plane along `-normal`
+    pub d: f32,
+}
+
+/// The pieces of a triangle cut by [`Plane::split_triangle`]
+///
+/// Every piece keeps the winding of the original triangle.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TriangleSplit {
+    /// The triangles in front of the plane
+    pub front: Vec<[Vec3f; 3]>,
+    /// The triangles behind the plane
+    pub back: Vec<[Vec3f; 3]>,
+}
+
+impl Plane {
+    /// Creates a new plane from its coefficients
+    ///
+    /// # Arguments
+    ///
+    /// * `normal` - A `Vec3f` holding the normal of the plane
+    ///
+    /// * `d` - The offset of the plane along `-normal`
+    pub const fn new(normal: Vec3f, d: f32) -> Self {
+        Self { normal, d }
+    }
+
+    /// Creates the plane through `point` facing along `normal`, which is normalized
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::plane::Plane;
+    /// use yavml::vec3::Vec3f;
+    /// let plane = Plane::from_point_normal(Vec3f::new(0.0, 3.0, 0.0), Vec3f::new(0.0, 2.0, 0.0));
+    /// assert_eq!(plane, Plane::new(Vec3f::Y, -3.0));
+    /// assert_eq!(plane.signed_distance(Vec3f::new(5.0, 1.0, 5.0)), -2.0);
+    /// ```
+    pub fn from_point_normal(point: Vec3f, normal: Vec3f) -> Self {
+        let normal = normal.normalize();
+        Self::new(normal, -normal.dot(point))
+    }
+
+    /// Creates the plane through `a`, `b` and `c`, or `None` if they are collinear
+    ///
+    /// The normal faces the side from which the points run counterclockwise.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::plane::Plane;
+    /// use yavml::vec3::Vec3f;
+    /// let plane = Plane::from_three_points(Vec3f::ZERO, Vec3f::X, Vec3f::Y).unwrap();
+    /// assert_eq!(plane, Plane::new(Vec3f::Z, 0.0));
+    /// assert_eq!(Plane::from_three_points(Vec3f::ZERO, Vec3f::X, Vec3f::X * 2.0), None);
+    /// ```
+    pub fn from_three_points(a: Vec3f, b: Vec3f, c: Vec3f) -> Option<Self> {
+        let normal = (b - a).cross(c - a);
+        if normal.dot(normal) == 0.0 {
+            return None;
+        }
+        Some(Self::from_point_normal(a, normal))
+    }
+
+    /// Returns the plane scaled so its normal has unit length
+    ///
+    /// The result is non-finite if the normal has a length of zero.
+    pub fn normalize(&self) -> Self {
+        let length = self.normal.dot(self.normal).sqrt();
+        Self::new(self.normal / length, self.d / length)
+    }
+
+    /// Returns the distance from the plane to `point`, which is positive in front of the plane
+    /// and negative behind it
+    ///
+    /// The result is scaled by the length of the normal when it is not unit length.
+    pub fn signed_distance(&self, point: Vec3f) -> f32 {
+        self.normal.dot(point) + self.d
+    }
+
+    /// Returns the point of the plane closest to `point`
+    pub fn project_point(&self, point: Vec3f) -> Vec3f {
+        point - self.normal * (self.signed_distance(point) / self.normal.dot(self.normal))
+    }
+
+    /// Returns the mirror image of `point` on the other side of the plane
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::plane::Plane;
+    /// use yavml::vec3::Vec3f;
+    /// let plane = Plane::new(Vec3f::X, -1.0);
+    /// assert_eq!(plane.reflect_point(Vec3f::new(4.0, 2.0, 0.0)), Vec3f::new(-2.0, 2.0, 0.0));
+    /// ```
+    pub fn reflect_point(&self, point: Vec3f) -> Vec3f {
+        point - self.normal * (2.0 * self.signed_distance(point) / self.normal.dot(self.normal))
+    }
+
+    /// Returns the plane moved by `mat`, or `None` if `mat` is not invertible
+    ///
+    /// Normals do not transform like points under non-uniform scale, so the plane coefficients
+    /// are multiplied by the inverse-transpose of `mat`. The result is normalized.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat4::Mat4f;
+    /// use yavml::plane::Plane;
+    /// use yavml::vec3::Vec3f;
+    /// let plane = Plane::new(Vec3f::Z, -1.0);
+    /// let mat = Mat4f::from_translation(Vec3f::new(0.0, 0.0, 2.0));
+    /// assert_eq!(plane.transform(&mat), Some(Plane::new(Vec3f::Z, -3.0)));
+    /// ```
+    pub fn transform(&self, mat: &Mat4f) -> Option<Self> {
+        let inverse = mat.try_inverse()?;
+        let coefficients = Vec4f::new(self.normal.x, self.normal.y, self.normal.z, self.d);
+        let normal = Vec3f::new(
+            inverse.x_axis.dot(coefficients),
+            inverse.y_axis.dot(coefficients),
+            inverse.z_axis.dot(coefficients),
+        );
+        Some(Self::new(normal, inverse.w_axis.dot(coefficients)).normalize())
+    }
+
+    /// Returns the single point shared by planes `a`, `b` and `c`, or `None` if there is no
+    /// single point
+    ///
+    /// There is no single point when any two of the normals are parallel, or when all three lie
+    /// in one plane. This is detected with a tolerance relative to the normal lengths.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::plane::Plane;
+    /// use yavml::vec3::Vec3f;
+    /// let x = Plane::new(Vec3f::X, -1.0);
+    /// let y = Plane::new(Vec3f::Y, -2.0);
+    /// let z = Plane::new(Vec3f::Z, -3.0);
+    /// assert_eq!(Plane::intersect_planes(&x, &y, &z), Some(Vec3f::new(1.0, 2.0, 3.0)));
+    /// assert_eq!(Plane::intersect_planes(&x, &y, &x), None);
+    /// ```
+    pub fn intersect_planes(a: &Self, b: &Self, c: &Self) -> Option<Vec3f> {
+        let bc = b.normal.cross(c.normal);
+        let denom = a.normal.dot(bc);
+        let scale =
+            (a.normal.dot(a.normal) * b.normal.dot(b.normal) * c.normal.dot(c.normal)).sqrt();
+        if denom.abs() <= f32::EPSILON * scale {
+            return None;
+        }
+        let ca = c.normal.cross(a.normal);
+        let ab = a.normal.cross(b.normal);
+        Some((bc * -a.d + ca * -b.d + ab * -c.d) / denom)
+    }
+
+    /// Cuts `triangle` by the plane into triangles in front of and behind it
+    ///
+    /// Corners within `epsilon` of the plane count as lying on it, which stops slivers being
+    /// cut off triangles that only graze the plane. A triangle that touches the plane without
+    /// crossing it is returned whole on its side, and a triangle lying in the plane goes in
+    /// front when it faces the same way as the plane and behind otherwise. A crossing triangle
+    /// becomes up to two triangles on each side.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::plane::Plane;
+    /// use yavml::vec3::Vec3f;
+    /// let plane = Plane::new(Vec3f::X, 0.0);
+    /// let triangle = [Vec3f::new(-1.0, 0.0, 0.0), Vec3f::new(1.0, 0.0, 0.0), Vec3f::Y];
+    /// let split = plane.split_triangle(triangle, 1e-6);
+    /// assert_eq!(split.front, [[Vec3f::ZERO, Vec3f::new(1.0, 0.0, 0.0), Vec3f::Y]]);
+    /// assert_eq!(split.back, [[Vec3f::new(-1.0, 0.0, 0.0), Vec3f::ZERO, Vec3f::Y]]);
+    /// ```
+    pub fn split_triangle(&self, triangle: [Vec3f; 3], epsilon: f32) -> TriangleSplit {
+        let distances = triangle.map(|corner| self.signed_distance(corner));
+        let mut split = TriangleSplit::default();
+        if distances.iter().all(|distance| distance.abs() <= epsilon) {
+            let [a, b, c] = triangle;
+            if (b - a).cross(c - a).dot(self.normal) >= 0.0 {
+                split.front.push(triangle);
+            } else {
+                split.back.push(triangle);
+            }
+            return split;
+        }
+        let mut front = Vec::with_capacity(4);
+        let mut back = Vec::with_capacity(4);
+        for i in 0..3 {
+            let j = (i + 1) % 3;
+            let (point, distance) = (triangle[i], distances[i]);
+            if distance > epsilon {
+                front.push(point);
+            } else if distance < -epsilon {
+                back.push(point);
+            } else {
+                front.push(point);
+                back.push(point);
+            }
+            let next = distances[j];
+            if (distance > epsilon && next < -epsilon) || (distance < -epsilon && next > epsilon) {
+                let crossing = point + (triangle[j] - point) * (distance / (distance - next));
+                front.push(crossing);
+                back.push(crossing);
+            }
+        }
+        split.front = fan(&front);
+        split.back = fan(&back);
+        split
+    }
+}
+
+/// Returns the triangles of a fan over the convex polygon `points`, or none if it has fewer than
+/// three points
+fn fan(points: &[Vec3f]) -> Vec<[Vec3f; 3]> {
+    (2..points.len())
+        .map(|i| [points[0], points[i - 1], points[i]])
+        .collect()
+}
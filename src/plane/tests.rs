@@ -0,0 +1,158 @@
+use super::Plane;
+use crate::mat4::Mat4f;
+use crate::vec3::Vec3f;
+
+mod plane_tests {
+    use super::Mat4f;
+    use super::Plane;
+    use super::Vec3f;
+
+    fn assert_close(a: Vec3f, b: Vec3f) {
+        let offset = a - b;
+        assert!(offset.dot(offset) < 1e-10, "{a:?} != {b:?}");
+    }
+
+    // Tests construction from a point and normal and from three points
+    #[test]
+    fn test_plane_construction() {
+        let plane = Plane::from_point_normal(Vec3f::new(1.0, 2.0, 3.0), Vec3f::new(0.0, 0.0, -4.0));
+        assert_eq!(plane, Plane::new(Vec3f::new(0.0, 0.0, -1.0), 3.0));
+        let plane = Plane::from_three_points(
+            Vec3f::new(0.0, 0.0, 2.0),
+            Vec3f::new(0.0, 1.0, 2.0),
+            Vec3f::new(1.0, 0.0, 2.0),
+        )
+        .unwrap();
+        assert_eq!(plane, Plane::new(Vec3f::new(0.0, 0.0, -1.0), 2.0));
+        let point = Vec3f::new(3.0, 3.0, 3.0);
+        assert_eq!(Plane::from_three_points(point, point, Vec3f::ZERO), None);
+        let scaled = Plane::new(Vec3f::new(0.0, 3.0, 4.0), 10.0).normalize();
+        assert_eq!(scaled, Plane::new(Vec3f::new(0.0, 0.6, 0.8), 2.0));
+    }
+
+    // Tests signed distances, projection and reflection, including a non-unit normal
+    #[test]
+    fn test_plane_distance_project_reflect() {
+        let plane = Plane::new(Vec3f::Y, -1.0);
+        assert_eq!(plane.signed_distance(Vec3f::new(7.0, 4.0, 2.0)), 3.0);
+        assert_eq!(plane.signed_distance(Vec3f::ZERO), -1.0);
+        assert_eq!(
+            plane.project_point(Vec3f::new(7.0, 4.0, 2.0)),
+            Vec3f::new(7.0, 1.0, 2.0)
+        );
+        assert_eq!(
+            plane.reflect_point(Vec3f::new(7.0, 4.0, 2.0)),
+            Vec3f::new(7.0, -2.0, 2.0)
+        );
+        let unnormalized = Plane::new(Vec3f::new(0.0, 2.0, 0.0), -2.0);
+        assert_eq!(
+            unnormalized.project_point(Vec3f::new(7.0, 4.0, 2.0)),
+            Vec3f::new(7.0, 1.0, 2.0)
+        );
+        assert_eq!(
+            unnormalized.reflect_point(Vec3f::new(7.0, 4.0, 2.0)),
+            Vec3f::new(7.0, -2.0, 2.0)
+        );
+    }
+
+    // Tests that transformed planes still hold the transformed points, including under
+    // non-uniform scale where transforming the normal directly would be wrong
+    #[test]
+    fn test_plane_transform() {
+        let points = [
+            Vec3f::new(1.0, 0.0, 0.0),
+            Vec3f::new(0.0, 1.0, 0.0),
+            Vec3f::new(0.0, 0.0, 1.0),
+        ];
+        let plane = Plane::from_three_points(points[0], points[1], points[2]).unwrap();
+        let mat = Mat4f::from_translation(Vec3f::new(1.0, -2.0, 0.5))
+            * Mat4f::from_axis_angle(Vec3f::Z, 0.7)
+            * Mat4f::from_scale(Vec3f::new(3.0, 1.0, 0.5));
+        let moved = plane.transform(&mat).unwrap();
+        for point in points {
+            let distance = moved.signed_distance(mat.transform_point3(point));
+            assert!(distance.abs() < 1e-5);
+        }
+        let offset = mat.transform_point3(Vec3f::ONE);
+        assert!(moved.signed_distance(offset) > 0.0);
+        assert!((moved.normal.dot(moved.normal) - 1.0).abs() < 1e-6);
+        assert_eq!(plane.transform(&Mat4f::from_scale(Vec3f::ZERO)), None);
+    }
+
+    // Tests the meeting point of three planes and the parallel cases
+    #[test]
+    fn test_plane_intersect_planes() {
+        let a = Plane::from_point_normal(Vec3f::new(1.0, 2.0, 3.0), Vec3f::new(1.0, 1.0, 0.0));
+        let b = Plane::from_point_normal(Vec3f::new(1.0, 2.0, 3.0), Vec3f::new(0.0, 1.0, 1.0));
+        let c = Plane::from_point_normal(Vec3f::new(1.0, 2.0, 3.0), Vec3f::new(1.0, 0.0, 2.0));
+        assert_close(
+            Plane::intersect_planes(&a, &b, &c).unwrap(),
+            Vec3f::new(1.0, 2.0, 3.0),
+        );
+        let shifted = Plane::new(a.normal, a.d + 1.0);
+        assert_eq!(Plane::intersect_planes(&a, &shifted, &c), None);
+        let pencil = Plane::from_point_normal(Vec3f::ZERO, Vec3f::new(1.0, 2.0, 1.0));
+        let through = Plane::from_point_normal(Vec3f::ZERO, Vec3f::new(1.0, 0.0, -1.0));
+        let third = Plane::from_point_normal(Vec3f::ZERO, Vec3f::new(2.0, 2.0, 0.0));
+        assert_eq!(Plane::intersect_planes(&pencil, &through, &third), None);
+    }
+
+    // Tests splitting with one corner on each side, two corners in front and a corner on the
+    // plane
+    #[test]
+    fn test_plane_split_triangle() {
+        let plane = Plane::new(Vec3f::Y, -1.0);
+        let triangle = [
+            Vec3f::new(0.0, 0.0, 0.0),
+            Vec3f::new(4.0, 0.0, 0.0),
+            Vec3f::new(0.0, 4.0, 0.0),
+        ];
+        let split = plane.split_triangle(triangle, 1e-6);
+        assert_eq!(split.front.len(), 1);
+        assert_eq!(split.back.len(), 2);
+        assert_eq!(
+            split.front[0],
+            [
+                Vec3f::new(3.0, 1.0, 0.0),
+                Vec3f::new(0.0, 4.0, 0.0),
+                Vec3f::new(0.0, 1.0, 0.0)
+            ]
+        );
+        let area = |t: &[Vec3f; 3]| (t[1] - t[0]).cross(t[2] - t[0]).z * 0.5;
+        let back_area: f32 = split.back.iter().map(area).sum();
+        assert_eq!(back_area + area(&split.front[0]), area(&triangle));
+        assert!(split.back.iter().chain(&split.front).all(|t| area(t) > 0.0));
+
+        let touching = [
+            Vec3f::new(0.0, 1.0, 0.0),
+            Vec3f::new(1.0, 3.0, 0.0),
+            Vec3f::new(-1.0, 3.0, 0.0),
+        ];
+        let split = plane.split_triangle(touching, 1e-6);
+        assert_eq!(split.front, [touching]);
+        assert!(split.back.is_empty());
+
+        let through_corner = [
+            Vec3f::new(0.0, 1.0, 0.0),
+            Vec3f::new(1.0, 0.0, 0.0),
+            Vec3f::new(1.0, 2.0, 0.0),
+        ];
+        let split = plane.split_triangle(through_corner, 1e-6);
+        assert_eq!(split.front.len(), 1);
+        assert_eq!(split.back.len(), 1);
+    }
+
+    // Tests that triangles lying in the plane go to the side they face
+    #[test]
+    fn test_plane_split_triangle_coplanar() {
+        let plane = Plane::new(Vec3f::Z, 0.0);
+        let facing = [Vec3f::ZERO, Vec3f::X, Vec3f::Y];
+        let split = plane.split_triangle(facing, 1e-6);
+        assert_eq!(split.front, [facing]);
+        assert!(split.back.is_empty());
+        let away = [Vec3f::ZERO, Vec3f::Y, Vec3f::X];
+        let split = plane.split_triangle(away, 1e-6);
+        assert!(split.front.is_empty());
+        assert_eq!(split.back, [away]);
+    }
+}
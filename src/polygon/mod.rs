@@ -0,0 +1,8 @@
+pub mod polygon2;
+pub use polygon2::{FillRule, Polygon2, WindingOrder};
+
+pub mod polygon2i;
+pub use polygon2i::Polygon2i;
+
+#[cfg(test)]
+mod tests;
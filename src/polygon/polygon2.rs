@@ -0,0 +1,270 @@
+use crate::rect::Rect2f;
+use crate::segment::Segment2f;
+use crate::vec2::Vec2f;
+
+/// The direction the vertices of a polygon run in, with y pointing up
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WindingOrder {
+    /// The vertices run counterclockwise, so the signed area is positive
+    CounterClockwise,
+    /// The vertices run clockwise, so the signed area is negative
+    Clockwise,
+}
+
+/// The rule deciding which points are inside a polygon whose edges cross each other
+///
+/// Both rules agree for simple polygons.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FillRule {
+    /// A point is inside when a ray from it crosses the edges an odd number of times
+    EvenOdd,
+    /// A point is inside when the edges wind around it a non-zero number of times
+    NonZero,
+}
+
+/// A closed polygon of `f32` vertices
+///
+/// The last vertex connects back to the first, so the vertices should not repeat the first
+/// vertex at the end. The polygon does not need to be simple or convex, and the queries that
+/// assume either say so.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Polygon2 {
+    vertices: Vec<Vec2f>,
+}
+
+impl Polygon2 {
+    /// Creates a new polygon from its vertices
+    pub const fn new(vertices: Vec<Vec2f>) -> Self {
+        Self { vertices }
+    }
+
+    /// Returns the vertices of the polygon
+    pub fn vertices(&self) -> &[Vec2f] {
+        &self.vertices
+    }
+
+    /// Returns the vertices of the polygon, consuming it
+    pub fn into_vertices(self) -> Vec<Vec2f> {
+        self.vertices
+    }
+
+    /// Returns an iterator over the edges of the polygon, starting with the edge from the first
+    /// vertex to the second and ending with the edge back to the first vertex
+    pub fn edges(&self) -> impl Iterator<Item = Segment2f> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices
+            .iter()
+            .zip(next)
+            .map(|(&start, &end)| Segment2f::new(start, end))
+    }
+
+    /// Returns the area of the polygon from the shoelace formula, which is positive when the
+    /// vertices run counterclockwise and negative when they run clockwise
+    ///
+    /// For a self-intersecting polygon, regions wound in opposite directions cancel out.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::polygon::Polygon2;
+    /// use yavml::vec2::Vec2f;
+    /// let square = Polygon2::new(vec![
+    ///     Vec2f::new(0.0, 0.0),
+    ///     Vec2f::new(2.0, 0.0),
+    ///     Vec2f::new(2.0, 2.0),
+    ///     Vec2f::new(0.0, 2.0),
+    /// ]);
+    /// assert_eq!(square.signed_area(), 4.0);
+    /// ```
+    pub fn signed_area(&self) -> f32 {
+        self.edges()
+            .map(|edge| edge.start.cross(edge.end))
+            .sum::<f32>()
+            * 0.5
+    }
+
+    /// Returns the center of mass of the area of the polygon, or `None` if its area is zero
+    pub fn centroid(&self) -> Option<Vec2f> {
+        let area = self.signed_area();
+        if area == 0.0 {
+            return None;
+        }
+        let sum = self.edges().fold(Vec2f::ZERO, |sum, edge| {
+            sum + (edge.start + edge.end) * edge.start.cross(edge.end)
+        });
+        Some(sum / (6.0 * area))
+    }
+
+    /// Returns the direction the vertices run in, or `None` if the signed area is zero
+    pub fn winding_order(&self) -> Option<WindingOrder> {
+        let area = self.signed_area();
+        if area > 0.0 {
+            Some(WindingOrder::CounterClockwise)
+        } else if area < 0.0 {
+            Some(WindingOrder::Clockwise)
+        } else {
+            None
+        }
+    }
+
+    /// Reverses the order of the vertices in place, flipping the winding order
+    pub fn reverse(&mut self) {
+        self.vertices.reverse();
+    }
+
+    /// Returns the total length of the edges
+    pub fn perimeter(&self) -> f32 {
+        self.edges().map(|edge| edge.length()).sum()
+    }
+
+    /// Returns the smallest rectangle containing every vertex, or `None` if there are none
+    pub fn bounding_rect(&self) -> Option<Rect2f> {
+        Rect2f::from_points(self.vertices.iter().copied())
+    }
+
+    /// Returns `true` if the polygon is convex
+    ///
+    /// Every turn must go the same way and the boundary must wind around exactly once, which
+    /// rules out star polygons. Collinear vertices are allowed, but polygons with fewer than
+    /// three vertices or zero area are not convex.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::polygon::Polygon2;
+    /// use yavml::vec2::Vec2f;
+    /// let arrow = Polygon2::new(vec![
+    ///     Vec2f::new(0.0, 0.0),
+    ///     Vec2f::new(2.0, 1.0),
+    ///     Vec2f::new(0.0, 2.0),
+    ///     Vec2f::new(1.0, 1.0),
+    /// ]);
+    /// assert!(!arrow.is_convex());
+    /// let triangle = Polygon2::new(arrow.vertices()[..3].to_vec());
+    /// assert!(triangle.is_convex());
+    /// ```
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        if n < 3 {
+            return false;
+        }
+        let deltas: Vec<Vec2f> = self.edges().map(|edge| edge.end - edge.start).collect();
+        let mut turn = 0.0_f32;
+        for (i, delta) in deltas.iter().enumerate() {
+            let cross = delta.cross(deltas[(i + 1) % n]);
+            if cross != 0.0 {
+                if turn != 0.0 && cross.signum() != turn.signum() {
+                    return false;
+                }
+                turn = cross;
+            }
+        }
+        turn != 0.0
+            && sign_flips(deltas.iter().map(|delta| delta.x)) <= 2
+            && sign_flips(deltas.iter().map(|delta| delta.y)) <= 2
+    }
+
+    /// Returns `true` if no two edges cross or touch, apart from neighbouring edges sharing their
+    /// common vertex
+    ///
+    /// This checks every pair of edges, so it takes quadratic time. Polygons with fewer than
+    /// three vertices, repeated consecutive vertices or edges that double back are not simple.
+    pub fn is_simple(&self) -> bool {
+        let n = self.vertices.len();
+        if n < 3 {
+            return false;
+        }
+        let edges: Vec<Segment2f> = self.edges().collect();
+        for i in 0..n {
+            let edge = edges[i];
+            let next = edges[(i + 1) % n];
+            if edge.start == edge.end {
+                return false;
+            }
+            let (a, b, c) = (edge.start, edge.end, next.end);
+            if (b - a).cross(c - b) == 0.0 && (b - a).dot(c - b) < 0.0 {
+                return false;
+            }
+            let last = if i == 0 { n - 1 } else { n };
+            for other in &edges[(i + 2).min(last)..last] {
+                if edge.intersect(other).is_some() {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Returns `true` if `point` is inside the polygon under `rule`
+    ///
+    /// Points exactly on an edge may be reported as inside or outside.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::polygon::{FillRule, Polygon2};
+    /// use yavml::vec2::Vec2f;
+    /// // A square traced twice, so its inside is wound around two times
+    /// let square = [
+    ///     Vec2f::new(0.0, 0.0),
+    ///     Vec2f::new(2.0, 0.0),
+    ///     Vec2f::new(2.0, 2.0),
+    ///     Vec2f::new(0.0, 2.0),
+    /// ];
+    /// let twice = Polygon2::new([square, square].concat());
+    /// let center = Vec2f::new(1.0, 1.0);
+    /// assert!(!twice.contains_point(center, FillRule::EvenOdd));
+    /// assert!(twice.contains_point(center, FillRule::NonZero));
+    /// ```
+    pub fn contains_point(&self, point: Vec2f, rule: FillRule) -> bool {
+        let crossings = self.edges().map(|edge| crossing(edge, point));
+        match rule {
+            FillRule::EvenOdd => crossings.filter(|&crossing| crossing != 0).count() % 2 == 1,
+            FillRule::NonZero => crossings.sum::<i32>() != 0,
+        }
+    }
+
+    /// Returns how many times the edges wind counterclockwise around `point`
+    ///
+    /// Clockwise turns count as negative, so the result is zero outside the polygon and `1` or
+    /// `-1` inside a simple polygon, depending on its winding order.
+    pub fn winding_number(&self, point: Vec2f) -> i32 {
+        self.edges().map(|edge| crossing(edge, point)).sum()
+    }
+}
+
+impl From<Vec<Vec2f>> for Polygon2 {
+    /// A polygon with the given vertices
+    fn from(vertices: Vec<Vec2f>) -> Self {
+        Self::new(vertices)
+    }
+}
+
+/// Returns `1` if `edge` crosses the ray from `point` towards positive x going up, `-1` if it
+/// crosses going down and `0` otherwise
+///
+/// Each edge includes its lower end and excludes its upper end, so a ray through a vertex
+/// counts the two edges meeting there once between them.
+fn crossing(edge: Segment2f, point: Vec2f) -> i32 {
+    let side = (edge.end - edge.start).cross(point - edge.start);
+    if edge.start.y <= point.y {
+        i32::from(edge.end.y > point.y && side > 0.0)
+    } else {
+        -i32::from(edge.end.y <= point.y && side < 0.0)
+    }
+}
+
+/// Returns how many times the non-zero values of the closed sequence `deltas` change sign,
+/// including the change from the last value back to the first
+///
+/// The edges of a convex polygon change direction at most twice along each axis.
+pub(super) fn sign_flips<T, I>(deltas: I) -> usize
+where
+    T: Copy + Default + PartialOrd,
+    I: Iterator<Item = T>,
+{
+    let zero = T::default();
+    let signs: Vec<bool> = deltas
+        .filter(|&delta| delta != zero)
+        .map(|delta| delta > zero)
+        .collect();
+    let next = signs.iter().cycle().skip(1);
+    signs.iter().zip(next).filter(|(a, b)| a != b).count()
+}
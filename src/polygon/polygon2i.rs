@@ -0,0 +1,268 @@
+use super::polygon2::sign_flips;
+use super::{FillRule, WindingOrder};
+use crate::rect::Rect2i;
+use crate::vec2::{Vec2d, Vec2i};
+
+/// A closed polygon of integer vertices for exact geometry
+///
+/// Every query that returns an integer or a `bool` is computed exactly, with products widened to
+/// `i128` so no coordinates in the `i32` range can overflow. The last vertex connects back to
+/// the first, so the vertices should not repeat the first vertex at the end.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Polygon2i {
+    vertices: Vec<Vec2i>,
+}
+
+impl Polygon2i {
+    /// Creates a new polygon from its vertices
+    pub const fn new(vertices: Vec<Vec2i>) -> Self {
+        Self { vertices }
+    }
+
+    /// Returns the vertices of the polygon
+    pub fn vertices(&self) -> &[Vec2i] {
+        &self.vertices
+    }
+
+    /// Returns the vertices of the polygon, consuming it
+    pub fn into_vertices(self) -> Vec<Vec2i> {
+        self.vertices
+    }
+
+    /// Returns an iterator over the edges of the polygon as `(start, end)` pairs, ending with the
+    /// edge back to the first vertex
+    pub fn edges(&self) -> impl Iterator<Item = (Vec2i, Vec2i)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    /// Returns twice the signed area of the polygon from the shoelace formula
+    ///
+    /// Twice the area of a polygon with integer vertices is always an integer. It is positive
+    /// when the vertices run counterclockwise and negative when they run clockwise.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::polygon::Polygon2i;
+    /// use yavml::vec2::Vec2i;
+    /// let triangle = Polygon2i::new(vec![Vec2i::new(0, 0), Vec2i::new(3, 0), Vec2i::new(0, 1)]);
+    /// assert_eq!(triangle.twice_signed_area(), 3);
+    /// assert_eq!(triangle.signed_area(), 1.5);
+    /// ```
+    pub fn twice_signed_area(&self) -> i128 {
+        self.edges()
+            .map(|(start, end)| cross(widen(start), widen(end)))
+            .sum()
+    }
+
+    /// Returns the signed area of the polygon, which is positive when the vertices run
+    /// counterclockwise and negative when they run clockwise
+    pub fn signed_area(&self) -> f64 {
+        self.twice_signed_area() as f64 * 0.5
+    }
+
+    /// Returns the center of mass of the area of the polygon, or `None` if its area is zero
+    pub fn centroid(&self) -> Option<Vec2d> {
+        let twice_area = self.twice_signed_area();
+        if twice_area == 0 {
+            return None;
+        }
+        let (mut x, mut y) = (0, 0);
+        for (start, end) in self.edges() {
+            let weight = cross(widen(start), widen(end));
+            x += (i128::from(start.x) + i128::from(end.x)) * weight;
+            y += (i128::from(start.y) + i128::from(end.y)) * weight;
+        }
+        let denom = 3.0 * twice_area as f64;
+        Some(Vec2d::new(x as f64 / denom, y as f64 / denom))
+    }
+
+    /// Returns the direction the vertices run in, or `None` if the signed area is zero
+    pub fn winding_order(&self) -> Option<WindingOrder> {
+        match self.twice_signed_area().signum() {
+            1 => Some(WindingOrder::CounterClockwise),
+            -1 => Some(WindingOrder::Clockwise),
+            _ => None,
+        }
+    }
+
+    /// Reverses the order of the vertices in place, flipping the winding order
+    pub fn reverse(&mut self) {
+        self.vertices.reverse();
+    }
+
+    /// Returns the total length of the edges
+    pub fn perimeter(&self) -> f64 {
+        self.edges()
+            .map(|(start, end)| {
+                let (x, y) = delta(start, end);
+                (x as f64).hypot(y as f64)
+            })
+            .sum()
+    }
+
+    /// Returns the smallest rectangle covering the cell of every vertex, or `None` if there are
+    /// none
+    ///
+    /// Like every [`Rect2i`] the result is half-open, so its `max` is one past the largest
+    /// vertex coordinates.
+    pub fn bounding_rect(&self) -> Option<Rect2i> {
+        Rect2i::from_points(self.vertices.iter().copied())
+    }
+
+    /// Returns `true` if the polygon is convex
+    ///
+    /// Every turn must go the same way and the boundary must wind around exactly once, which
+    /// rules out star polygons. Collinear vertices are allowed, but polygons with fewer than
+    /// three vertices or zero area are not convex.
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        if n < 3 {
+            return false;
+        }
+        let deltas: Vec<(i128, i128)> =
+            self.edges().map(|(start, end)| delta(start, end)).collect();
+        let mut turn = 0;
+        for (i, &delta) in deltas.iter().enumerate() {
+            let cross = cross(delta, deltas[(i + 1) % n]).signum();
+            if cross != 0 {
+                if turn != 0 && cross != turn {
+                    return false;
+                }
+                turn = cross;
+            }
+        }
+        turn != 0
+            && sign_flips(deltas.iter().map(|delta| delta.0)) <= 2
+            && sign_flips(deltas.iter().map(|delta| delta.1)) <= 2
+    }
+
+    /// Returns `true` if no two edges cross or touch, apart from neighbouring edges sharing their
+    /// common vertex
+    ///
+    /// This checks every pair of edges, so it takes quadratic time. Polygons with fewer than
+    /// three vertices, repeated consecutive vertices or edges that double back are not simple.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::polygon::Polygon2i;
+    /// use yavml::vec2::Vec2i;
+    /// let square = [Vec2i::new(0, 0), Vec2i::new(2, 0), Vec2i::new(2, 2), Vec2i::new(0, 2)];
+    /// assert!(Polygon2i::new(square.to_vec()).is_simple());
+    /// let bowtie = [square[0], square[2], square[1], square[3]];
+    /// assert!(!Polygon2i::new(bowtie.to_vec()).is_simple());
+    /// ```
+    pub fn is_simple(&self) -> bool {
+        let n = self.vertices.len();
+        if n < 3 {
+            return false;
+        }
+        let edges: Vec<(Vec2i, Vec2i)> = self.edges().collect();
+        for i in 0..n {
+            let (a, b) = edges[i];
+            let c = edges[(i + 1) % n].1;
+            if a == b {
+                return false;
+            }
+            if orient(a, b, c) == 0 && dot(delta(a, b), delta(b, c)) < 0 {
+                return false;
+            }
+            let last = if i == 0 { n - 1 } else { n };
+            for &(start, end) in &edges[(i + 2).min(last)..last] {
+                if segments_touch(a, b, start, end) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Returns `true` if `point` is inside the polygon under `rule`
+    ///
+    /// The result is exact. Points on an edge follow a consistent tie-breaking rule, so a point
+    /// on an edge shared by two polygons of a tiling is inside exactly one of them.
+    pub fn contains_point(&self, point: Vec2i, rule: FillRule) -> bool {
+        let crossings = self.edges().map(|(start, end)| crossing(start, end, point));
+        match rule {
+            FillRule::EvenOdd => crossings.filter(|&crossing| crossing != 0).count() % 2 == 1,
+            FillRule::NonZero => crossings.sum::<i32>() != 0,
+        }
+    }
+
+    /// Returns how many times the edges wind counterclockwise around `point`
+    ///
+    /// Clockwise turns count as negative, so the result is zero outside the polygon and `1` or
+    /// `-1` inside a simple polygon, depending on its winding order.
+    pub fn winding_number(&self, point: Vec2i) -> i32 {
+        self.edges()
+            .map(|(start, end)| crossing(start, end, point))
+            .sum()
+    }
+}
+
+impl From<Vec<Vec2i>> for Polygon2i {
+    /// A polygon with the given vertices
+    fn from(vertices: Vec<Vec2i>) -> Self {
+        Self::new(vertices)
+    }
+}
+
+/// Returns the coordinates of `v` widened to `i128`
+fn widen(v: Vec2i) -> (i128, i128) {
+    (i128::from(v.x), i128::from(v.y))
+}
+
+/// Returns the vector from `from` to `to` without overflow
+fn delta(from: Vec2i, to: Vec2i) -> (i128, i128) {
+    let ((fx, fy), (tx, ty)) = (widen(from), widen(to));
+    (tx - fx, ty - fy)
+}
+
+/// Returns the cross product of two widened vectors
+fn cross((ax, ay): (i128, i128), (bx, by): (i128, i128)) -> i128 {
+    ax * by - ay * bx
+}
+
+/// Returns the dot product of two widened vectors
+fn dot((ax, ay): (i128, i128), (bx, by): (i128, i128)) -> i128 {
+    ax * bx + ay * by
+}
+
+/// Returns the sign of the turn from `a` through `b` to `c`: `1` for counterclockwise, `-1` for
+/// clockwise and `0` for collinear points
+///
+/// The differences of `i32` coordinates are taken in `i128`, so the result is exact for every
+/// input.
+pub(crate) fn orient(a: Vec2i, b: Vec2i, c: Vec2i) -> i32 {
+    cross(delta(a, b), delta(a, c)).signum() as i32
+}
+
+/// Returns `true` if the segments `a`-`b` and `c`-`d` share at least one point
+fn segments_touch(a: Vec2i, b: Vec2i, c: Vec2i, d: Vec2i) -> bool {
+    let (o1, o2) = (orient(a, b, c), orient(a, b, d));
+    let (o3, o4) = (orient(c, d, a), orient(c, d, b));
+    if o1 * o2 < 0 && o3 * o4 < 0 {
+        return true;
+    }
+    let within = |p: Vec2i, q: Vec2i, r: Vec2i| {
+        r.x >= p.x.min(q.x) && r.x <= p.x.max(q.x) && r.y >= p.y.min(q.y) && r.y <= p.y.max(q.y)
+    };
+    (o1 == 0 && within(a, b, c))
+        || (o2 == 0 && within(a, b, d))
+        || (o3 == 0 && within(c, d, a))
+        || (o4 == 0 && within(c, d, b))
+}
+
+/// Returns `1` if the edge from `start` to `end` crosses the ray from `point` towards positive x
+/// going up, `-1` if it crosses going down and `0` otherwise
+///
+/// Each edge includes its lower end and excludes its upper end, so a ray through a vertex
+/// counts the two edges meeting there once between them.
+fn crossing(start: Vec2i, end: Vec2i, point: Vec2i) -> i32 {
+    let side = orient(start, end, point);
+    if start.y <= point.y {
+        i32::from(end.y > point.y && side > 0)
+    } else {
+        -i32::from(end.y <= point.y && side < 0)
+    }
+}
@@ -0,0 +1,212 @@
+use super::FillRule;
+use super::Polygon2;
+use super::Polygon2i;
+use super::WindingOrder;
+use crate::rect::{Rect2f, Rect2i};
+use crate::vec2::{Vec2d, Vec2f, Vec2i};
+
+mod polygon2_tests {
+    use super::FillRule;
+    use super::Polygon2;
+    use super::Rect2f;
+    use super::Vec2f;
+    use super::WindingOrder;
+
+    fn square() -> Polygon2 {
+        Polygon2::new(vec![
+            Vec2f::new(0.0, 0.0),
+            Vec2f::new(4.0, 0.0),
+            Vec2f::new(4.0, 4.0),
+            Vec2f::new(0.0, 4.0),
+        ])
+    }
+
+    // Tests the area, centroid, perimeter and bounds of a square in both windings
+    #[test]
+    fn test_polygon2_measures() {
+        let mut polygon = square();
+        assert_eq!(polygon.signed_area(), 16.0);
+        assert_eq!(polygon.centroid(), Some(Vec2f::new(2.0, 2.0)));
+        assert_eq!(polygon.perimeter(), 16.0);
+        assert_eq!(
+            polygon.bounding_rect(),
+            Some(Rect2f::new(Vec2f::ZERO, Vec2f::splat(4.0)))
+        );
+        assert_eq!(
+            polygon.winding_order(),
+            Some(WindingOrder::CounterClockwise)
+        );
+        polygon.reverse();
+        assert_eq!(polygon.signed_area(), -16.0);
+        assert_eq!(polygon.centroid(), Some(Vec2f::new(2.0, 2.0)));
+        assert_eq!(polygon.winding_order(), Some(WindingOrder::Clockwise));
+        let flat = Polygon2::new(vec![Vec2f::ZERO, Vec2f::ONE, Vec2f::splat(2.0)]);
+        assert_eq!(flat.winding_order(), None);
+        assert_eq!(flat.centroid(), None);
+        assert_eq!(Polygon2::default().bounding_rect(), None);
+    }
+
+    // Tests convexity with collinear vertices, a reflex vertex and a star that turns one way
+    #[test]
+    fn test_polygon2_is_convex() {
+        assert!(square().is_convex());
+        let mut vertices = square().into_vertices();
+        vertices.insert(1, Vec2f::new(2.0, 0.0));
+        assert!(Polygon2::from(vertices.clone()).is_convex());
+        vertices[1] = Vec2f::new(2.0, 1.0);
+        assert!(!Polygon2::from(vertices).is_convex());
+        let star = Polygon2::new(
+            [0, 2, 4, 1, 3]
+                .iter()
+                .map(|&i| {
+                    let angle = i as f32 * std::f32::consts::TAU / 5.0;
+                    Vec2f::new(angle.cos(), angle.sin())
+                })
+                .collect(),
+        );
+        assert!(!star.is_convex());
+        let line = Polygon2::new(vec![Vec2f::ZERO, Vec2f::ONE]);
+        assert!(!line.is_convex());
+    }
+
+    // Tests simplicity against crossing, touching and doubled-back edges
+    #[test]
+    fn test_polygon2_is_simple() {
+        assert!(square().is_simple());
+        let v = square().into_vertices();
+        assert!(!Polygon2::new(vec![v[0], v[2], v[1], v[3]]).is_simple());
+        let touching = vec![v[0], v[1], Vec2f::new(2.0, 0.0), v[2], v[3]];
+        assert!(!Polygon2::new(touching).is_simple());
+        let repeated = vec![v[0], v[1], v[1], v[2]];
+        assert!(!Polygon2::new(repeated).is_simple());
+        let triangle = Polygon2::new(vec![v[0], v[1], v[2]]);
+        assert!(triangle.is_simple());
+    }
+
+    // Tests point containment and winding numbers for both windings and a doubled square
+    #[test]
+    fn test_polygon2_contains_point() {
+        let mut polygon = square();
+        let inside = Vec2f::new(1.0, 2.0);
+        let outside = Vec2f::new(5.0, 2.0);
+        for rule in [FillRule::EvenOdd, FillRule::NonZero] {
+            assert!(polygon.contains_point(inside, rule));
+            assert!(!polygon.contains_point(outside, rule));
+        }
+        assert_eq!(polygon.winding_number(inside), 1);
+        assert_eq!(polygon.winding_number(Vec2f::new(1.0, 4.0)), 0);
+        polygon.reverse();
+        assert_eq!(polygon.winding_number(inside), -1);
+        assert_eq!(polygon.winding_number(outside), 0);
+        let v = square().into_vertices();
+        let twice = Polygon2::new([v.clone(), v].concat());
+        assert_eq!(twice.winding_number(inside), 2);
+        assert!(!twice.contains_point(inside, FillRule::EvenOdd));
+        assert!(twice.contains_point(inside, FillRule::NonZero));
+    }
+}
+
+mod polygon2i_tests {
+    use super::FillRule;
+    use super::Polygon2i;
+    use super::Rect2i;
+    use super::Vec2d;
+    use super::Vec2i;
+    use super::WindingOrder;
+
+    fn square() -> Polygon2i {
+        Polygon2i::new(vec![
+            Vec2i::new(0, 0),
+            Vec2i::new(4, 0),
+            Vec2i::new(4, 4),
+            Vec2i::new(0, 4),
+        ])
+    }
+
+    // Tests the exact area, centroid, perimeter and bounds of a square in both windings
+    #[test]
+    fn test_polygon2i_measures() {
+        let mut polygon = square();
+        assert_eq!(polygon.twice_signed_area(), 32);
+        assert_eq!(polygon.signed_area(), 16.0);
+        assert_eq!(polygon.centroid(), Some(Vec2d::new(2.0, 2.0)));
+        assert_eq!(polygon.perimeter(), 16.0);
+        assert_eq!(
+            polygon.bounding_rect(),
+            Some(Rect2i::new(Vec2i::ZERO, Vec2i::splat(5)))
+        );
+        assert_eq!(
+            polygon.winding_order(),
+            Some(WindingOrder::CounterClockwise)
+        );
+        polygon.reverse();
+        assert_eq!(polygon.twice_signed_area(), -32);
+        assert_eq!(polygon.winding_order(), Some(WindingOrder::Clockwise));
+        let flat = Polygon2i::new(vec![Vec2i::ZERO, Vec2i::ONE, Vec2i::splat(2)]);
+        assert_eq!(flat.winding_order(), None);
+        assert_eq!(flat.centroid(), None);
+    }
+
+    // Tests that coordinates near the ends of the i32 range do not overflow
+    #[test]
+    fn test_polygon2i_extreme_coordinates() {
+        let (lo, hi) = (i32::MIN, i32::MAX);
+        let polygon = Polygon2i::new(vec![
+            Vec2i::new(lo, lo),
+            Vec2i::new(hi, lo),
+            Vec2i::new(hi, hi),
+            Vec2i::new(lo, hi),
+        ]);
+        let side = i128::from(hi) - i128::from(lo);
+        assert_eq!(polygon.twice_signed_area(), 2 * side * side);
+        assert!(polygon.is_convex());
+        assert!(polygon.is_simple());
+        assert!(polygon.contains_point(Vec2i::ZERO, FillRule::NonZero));
+        assert_eq!(polygon.winding_number(Vec2i::new(hi, 0)), 0);
+    }
+
+    // Tests convexity and simplicity with collinear, reflex, crossing and touching vertices
+    #[test]
+    fn test_polygon2i_convex_simple() {
+        let v = square().into_vertices();
+        assert!(square().is_convex());
+        assert!(square().is_simple());
+        let collinear = Polygon2i::new(vec![v[0], Vec2i::new(2, 0), v[1], v[2], v[3]]);
+        assert!(collinear.is_convex());
+        assert!(collinear.is_simple());
+        let reflex = Polygon2i::new(vec![v[0], Vec2i::new(2, 1), v[1], v[2], v[3]]);
+        assert!(!reflex.is_convex());
+        assert!(reflex.is_simple());
+        let bowtie = Polygon2i::new(vec![v[0], v[2], v[1], v[3]]);
+        assert!(!bowtie.is_convex());
+        assert!(!bowtie.is_simple());
+        let touching = Polygon2i::new(vec![v[0], v[1], Vec2i::new(2, 0), v[2], v[3]]);
+        assert!(!touching.is_simple());
+    }
+
+    // Tests that points on an edge shared by two adjacent squares are inside exactly one
+    #[test]
+    fn test_polygon2i_contains_point_shared_edge() {
+        let left = square();
+        let right = Polygon2i::new(
+            left.vertices()
+                .iter()
+                .map(|&vertex| vertex + Vec2i::new(4, 0))
+                .collect(),
+        );
+        for y in 0..=4 {
+            let point = Vec2i::new(4, y);
+            let count = [&left, &right]
+                .iter()
+                .filter(|polygon| polygon.contains_point(point, FillRule::EvenOdd))
+                .count();
+            assert!(count <= 1);
+            if y < 4 {
+                assert_eq!(count, 1);
+            }
+        }
+        assert!(left.contains_point(Vec2i::new(1, 1), FillRule::EvenOdd));
+        assert!(!left.contains_point(Vec2i::new(-1, 1), FillRule::EvenOdd));
+        assert_eq!(left.winding_number(Vec2i::new(2, 2)), 1);
+    }
+}
@@ -0,0 +1,8 @@
+pub mod quatf;
+pub use quatf::Quatf;
+
+pub mod quatd;
+pub use quatd::Quatd;
+
+#[cfg(test)]
+mod tests;
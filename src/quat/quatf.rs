@@ -0,0 +1,423 @@
+use core::ops::*;
+
+use crate::angle::Radians;
+use crate::mat3::Mat3f;
+use crate::mat4::Mat4f;
+use crate::vec3::Vec3f;
+use crate::vec4::Vec4f;
+
+/// The dot product above which [`Quatf::slerp`] falls back to a normalized linear interpolation
+const SLERP_DOT_THRESHOLD: f32 = 0.9995;
+
+/// A quaternion of `f32` values representing a 3D rotation
+///
+/// Rotations are expected to be unit quaternions. `q` and `-q` represent the same rotation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quatf {
+    /// The x component of the vector part
+    pub x: f32,
+    /// The y component of the vector part
+    pub y: f32,
+    /// The z component of the vector part
+    pub z: f32,
+    /// The scalar part
+    pub w: f32,
+}
+
+impl Quatf {
+    /// The identity rotation
+    pub const IDENTITY: Self = Self::from_xyzw(0.0, 0.0, 0.0, 1.0);
+
+    /// Creates a new quaternion from its components
+    ///
+    /// The result is not normalized, so use [`Quatf::normalize`] if it is meant to be a rotation.
+    pub const fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates a new quaternion from an `[x, y, z, w]` array
+    pub const fn from_array(arr: [f32; 4]) -> Self {
+        Self::from_xyzw(arr[0], arr[1], arr[2], arr[3])
+    }
+
+    /// Create an `[x, y, z, w]` array from the quaternion
+    pub const fn to_array(&self) -> [f32; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    /// Creates a new quaternion from a `Vec4f` holding `x`, `y`, `z` and `w`
+    pub const fn from_vec4(vec: Vec4f) -> Self {
+        Self::from_xyzw(vec.x, vec.y, vec.z, vec.w)
+    }
+
+    /// Create a `Vec4f` holding `x`, `y`, `z` and `w` from the quaternion
+    pub const fn to_vec4(&self) -> Vec4f {
+        Vec4f::new(self.x, self.y, self.z, self.w)
+    }
+
+    /// Returns the vector part of the quaternion
+    pub const fn xyz(&self) -> Vec3f {
+        Vec3f::new(self.x, self.y, self.z)
+    }
+
+    /// Creates a rotation of `angle` radians around the normalized `axis`
+    ///
+    /// The rotation is counter-clockwise when looking down `axis` towards the origin.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::quat::Quatf;
+    /// use yavml::vec3::Vec3f;
+    /// let rotation = Quatf::from_axis_angle(Vec3f::Z, core::f32::consts::FRAC_PI_2);
+    /// assert!((rotation * Vec3f::X - Vec3f::Y).length() < 1e-6);
+    /// ```
+    pub fn from_axis_angle(axis: Vec3f, angle: impl Into<Radians<f32>>) -> Self {
+        let (sin, cos) = (angle.into() * 0.5).sin_cos();
+        let v = axis * sin;
+        Self::from_xyzw(v.x, v.y, v.z, cos)
+    }
+
+    /// Returns the normalized axis and the angle in radians of the rotation
+    ///
+    /// The angle is in `[0, 2 * PI]`. The identity rotation returns the x axis and an angle of
+    /// zero.
+    pub fn to_axis_angle(&self) -> (Vec3f, f32) {
+        let sin_sq = self.xyz().dot(self.xyz());
+        if sin_sq <= f32::EPSILON * f32::EPSILON {
+            return (Vec3f::X, 0.0);
+        }
+        let sin = sin_sq.sqrt();
+        (self.xyz() / sin, 2.0 * sin.atan2(self.w))
+    }
+
+    /// Creates the shortest rotation that turns the normalized vector `from` into the normalized
+    /// vector `to`
+    ///
+    /// When the vectors point in opposite directions the rotation is half a turn around an
+    /// arbitrary axis perpendicular to `from`.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::quat::Quatf;
+    /// use yavml::vec3::Vec3f;
+    /// let rotation = Quatf::from_rotation_arc(Vec3f::X, Vec3f::Z);
+    /// assert!((rotation * Vec3f::X - Vec3f::Z).length() < 1e-6);
+    /// ```
+    pub fn from_rotation_arc(from: Vec3f, to: Vec3f) -> Self {
+        let dot = from.dot(to);
+        if dot < -1.0 + f32::EPSILON * 4.0 {
+            let axis = if from.x.abs() < 0.9 {
+                Vec3f::X
+            } else {
+                Vec3f::Y
+            };
+            let v = from.cross(axis).normalize();
+            return Self::from_xyzw(v.x, v.y, v.z, 0.0);
+        }
+        let v = from.cross(to);
+        Self::from_xyzw(v.x, v.y, v.z, 1.0 + dot).normalize()
+    }
+
+    /// Creates a rotation that turns the z axis towards `forward` and the y axis as close to
+    /// `up` as possible
+    ///
+    /// Neither vector needs to be normalized. If `forward` is parallel to `up`, the result is the
+    /// shortest rotation from the z axis to `forward`.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::quat::Quatf;
+    /// use yavml::vec3::Vec3f;
+    /// let rotation = Quatf::look_rotation(Vec3f::new(1.0, 0.0, 0.0), Vec3f::Y);
+    /// assert!((rotation * Vec3f::Z - Vec3f::X).length() < 1e-6);
+    /// assert!((rotation * Vec3f::Y - Vec3f::Y).length() < 1e-6);
+    /// ```
+    pub fn look_rotation(forward: Vec3f, up: Vec3f) -> Self {
+        let forward = forward.normalize();
+        let right = up.cross(forward);
+        let right_len_sq = right.dot(right);
+        if right_len_sq <= f32::EPSILON * up.dot(up) {
+            return Self::from_rotation_arc(Vec3f::Z, forward);
+        }
+        let right = right / right_len_sq.sqrt();
+        Self::from_mat3(&Mat3f::from_cols(right, forward.cross(right), forward))
+    }
+
+    /// Creates a rotation from the rotation matrix `mat`
+    ///
+    /// The matrix must be orthonormal with a determinant of `1`.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat3::Mat3f;
+    /// use yavml::quat::Quatf;
+    /// use yavml::vec3::Vec3f;
+    /// let matrix = Mat3f::from_axis_angle(Vec3f::Y, 0.5);
+    /// let rotation = Quatf::from_mat3(&matrix);
+    /// assert!(rotation.abs_diff_eq(Quatf::from_axis_angle(Vec3f::Y, 0.5), 1e-6));
+    /// ```
+    pub fn from_mat3(mat: &Mat3f) -> Self {
+        let (m00, m01, m02) = (mat.x_axis.x, mat.y_axis.x, mat.z_axis.x);
+        let (m10, m11, m12) = (mat.x_axis.y, mat.y_axis.y, mat.z_axis.y);
+        let (m20, m21, m22) = (mat.x_axis.z, mat.y_axis.z, mat.z_axis.z);
+        let trace = m00 + m11 + m22;
+        let quat = if trace > 0.0 {
+            let s = (1.0 + trace).sqrt() * 2.0;
+            Self::from_xyzw((m21 - m12) / s, (m02 - m20) / s, (m10 - m01) / s, 0.25 * s)
+        } else if m00 > m11 && m00 > m22 {
+            let s = (1.0 + m00 - m11 - m22).sqrt() * 2.0;
+            Self::from_xyzw(0.25 * s, (m01 + m10) / s, (m02 + m20) / s, (m21 - m12) / s)
+        } else if m11 > m22 {
+            let s = (1.0 + m11 - m00 - m22).sqrt() * 2.0;
+            Self::from_xyzw((m01 + m10) / s, 0.25 * s, (m12 + m21) / s, (m02 - m20) / s)
+        } else {
+            let s = (1.0 + m22 - m00 - m11).sqrt() * 2.0;
+            Self::from_xyzw((m02 + m20) / s, (m12 + m21) / s, 0.25 * s, (m10 - m01) / s)
+        };
+        quat.normalize()
+    }
+
+    /// Creates a rotation from the upper-left 3x3 block of `mat`
+    ///
+    /// The block must be orthonormal with a determinant of `1`.
+    pub fn from_mat4(mat: &Mat4f) -> Self {
+        Self::from_mat3(&mat.to_mat3())
+    }
+
+    /// Returns the rotation matrix of the quaternion
+    pub fn to_mat3(&self) -> Mat3f {
+        let Self { x, y, z, w } = *self;
+        let (x2, y2, z2) = (x + x, y + y, z + z);
+        let (xx, xy, xz) = (x * x2, x * y2, x * z2);
+        let (yy, yz, zz) = (y * y2, y * z2, z * z2);
+        let (wx, wy, wz) = (w * x2, w * y2, w * z2);
+        Mat3f::from_cols(
+            Vec3f::new(1.0 - (yy + zz), xy + wz, xz - wy),
+            Vec3f::new(xy - wz, 1.0 - (xx + zz), yz + wx),
+            Vec3f::new(xz + wy, yz - wx, 1.0 - (xx + yy)),
+        )
+    }
+
+    /// Returns an affine transform matrix that applies the rotation
+    pub fn to_mat4(&self) -> Mat4f {
+        Mat4f::from_mat3(self.to_mat3())
+    }
+
+    /// Returns the dot product of `self` and `rhs`
+    pub fn dot(&self, rhs: Self) -> f32 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+
+    /// Returns the length of the quaternion
+    pub fn length(&self) -> f32 {
+        self.dot(*self).sqrt()
+    }
+
+    /// Returns `self` scaled to a length of `1`
+    ///
+    /// The result is non-finite if `self` has a length of zero.
+    pub fn normalize(&self) -> Self {
+        *self * (1.0 / self.length())
+    }
+
+    /// Returns `true` if the length of the quaternion is within `1e-4` of `1`
+    pub fn is_normalized(&self) -> bool {
+        (self.dot(*self) - 1.0).abs() <= 2e-4
+    }
+
+    /// Returns the conjugate of the quaternion, which negates the vector part
+    ///
+    /// For a unit quaternion this is the inverse rotation.
+    pub const fn conjugate(&self) -> Self {
+        Self::from_xyzw(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Returns the inverse of the quaternion
+    ///
+    /// The result is non-finite if `self` has a length of zero. Use [`Quatf::conjugate`] for
+    /// unit quaternions to skip the division.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::quat::Quatf;
+    /// use yavml::vec3::Vec3f;
+    /// let rotation = Quatf::from_axis_angle(Vec3f::X, 1.0);
+    /// assert!((rotation * rotation.inverse()).abs_diff_eq(Quatf::IDENTITY, 1e-6));
+    /// ```
+    pub fn inverse(&self) -> Self {
+        self.conjugate() * (1.0 / self.dot(*self))
+    }
+
+    /// Rotates the vector `rhs` by the quaternion
+    pub fn mul_vec3(&self, rhs: Vec3f) -> Vec3f {
+        let v = self.xyz();
+        let t = v.cross(rhs) * 2.0;
+        rhs + t * self.w + v.cross(t)
+    }
+
+    /// Returns the Hamilton product `self * rhs`, which applies `rhs` first
+    pub fn mul_quat(&self, rhs: &Self) -> Self {
+        let (a, b) = (*self, *rhs);
+        Self::from_xyzw(
+            a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+            a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+        )
+    }
+
+    /// Returns the angle in radians of the shortest rotation between `self` and `rhs`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::quat::Quatf;
+    /// use yavml::vec3::Vec3f;
+    /// let a = Quatf::from_axis_angle(Vec3f::Z, 0.25);
+    /// let b = Quatf::from_axis_angle(Vec3f::Z, 1.0);
+    /// assert!((a.angle_between(b) - 0.75).abs() < 1e-5);
+    /// ```
+    pub fn angle_between(&self, rhs: Self) -> f32 {
+        let dot = self.dot(rhs).abs().min(1.0);
+        2.0 * dot.acos()
+    }
+
+    /// Linearly interpolates between `self` and `end` along the shorter path and normalizes the
+    /// result
+    ///
+    /// The rotation does not move at a constant angular speed, but is cheaper than
+    /// [`Quatf::slerp`].
+    pub fn nlerp(&self, end: Self, t: f32) -> Self {
+        let end = if self.dot(end) < 0.0 { -end } else { end };
+        (*self * (1.0 - t) + end * t).normalize()
+    }
+
+    /// Spherically interpolates between `self` and `end` along the shorter path
+    ///
+    /// `t` of `0` returns `self` and `1` returns `end`, with a constant angular speed in between.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::quat::Quatf;
+    /// use yavml::vec3::Vec3f;
+    /// let start = Quatf::IDENTITY;
+    /// let end = Quatf::from_axis_angle(Vec3f::Y, 1.0);
+    /// let half = start.slerp(end, 0.5);
+    /// assert!(half.abs_diff_eq(Quatf::from_axis_angle(Vec3f::Y, 0.5), 1e-6));
+    /// ```
+    pub fn slerp(&self, end: Self, t: f32) -> Self {
+        let mut dot = self.dot(end);
+        let end = if dot < 0.0 {
+            dot = -dot;
+            -end
+        } else {
+            end
+        };
+        if dot > SLERP_DOT_THRESHOLD {
+            return (*self * (1.0 - t) + end * t).normalize();
+        }
+        let theta = dot.acos();
+        let sin_theta = theta.sin();
+        let scale_start = ((1.0 - t) * theta).sin() / sin_theta;
+        let scale_end = (t * theta).sin() / sin_theta;
+        *self * scale_start + end * scale_end
+    }
+
+    /// Returns `true` if every component of `self` and `rhs` differs by at most `max_abs_diff`
+    ///
+    /// `q` and `-q` are the same rotation but are not considered equal.
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.to_array()
+            .iter()
+            .zip(rhs.to_array().iter())
+            .all(|(a, b)| (a - b).abs() <= max_abs_diff)
+    }
+}
+
+impl Default for Quatf {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Quatf> for Mat3f {
+    fn from(quat: Quatf) -> Self {
+        quat.to_mat3()
+    }
+}
+
+impl From<Quatf> for Mat4f {
+    fn from(quat: Quatf) -> Self {
+        quat.to_mat4()
+    }
+}
+
+/// Addition of quaternions
+impl Add<Quatf> for Quatf {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_xyzw(
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+            self.w + rhs.w,
+        )
+    }
+}
+
+/// Subtraction of quaternions
+impl Sub<Quatf> for Quatf {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_xyzw(
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+            self.w - rhs.w,
+        )
+    }
+}
+
+/// Multiplication of quaternions
+impl Mul<Quatf> for Quatf {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_quat(&rhs)
+    }
+}
+
+/// Multiplication assignment of quaternions
+impl MulAssign<Quatf> for Quatf {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_quat(&rhs);
+    }
+}
+
+/// Rotation of a vector by a quaternion
+impl Mul<Vec3f> for Quatf {
+    type Output = Vec3f;
+
+    fn mul(self, rhs: Vec3f) -> Self::Output {
+        self.mul_vec3(rhs)
+    }
+}
+
+/// Multiplication of a quaternion by an `f32`
+impl Mul<f32> for Quatf {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self::from_xyzw(self.x * rhs, self.y * rhs, self.z * rhs, self.w * rhs)
+    }
+}
+
+/// Negation of a quaternion
+impl Neg for Quatf {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::from_xyzw(-self.x, -self.y, -self.z, -self.w)
+    }
+}
@@ -0,0 +1,199 @@
+use super::Quatd;
+use super::Quatf;
+use crate::mat3::Mat3d;
+use crate::mat3::Mat3f;
+use crate::mat4::Mat4f;
+use crate::vec3::Vec3d;
+use crate::vec3::Vec3f;
+
+mod quatf_tests {
+    use super::Mat3f;
+    use super::Mat4f;
+    use super::Quatf;
+    use super::Vec3f;
+    use core::f32::consts::{FRAC_PI_2, PI};
+
+    // Tests that the identity leaves vectors unchanged
+    #[test]
+    fn test_quatf_identity() {
+        let vec = Vec3f::new(1.0, -2.0, 3.0);
+        assert_eq!(Quatf::IDENTITY * vec, vec);
+        assert_eq!(Quatf::default(), Quatf::IDENTITY);
+        assert_eq!(Quatf::IDENTITY.to_mat3(), Mat3f::IDENTITY);
+    }
+
+    // Tests that axis-angle rotations match the equivalent matrix
+    #[test]
+    fn test_quatf_from_axis_angle() {
+        let axis = Vec3f::new(0.0, 0.6, 0.8);
+        let rotation = Quatf::from_axis_angle(axis, 1.2);
+        let matrix = Mat3f::from_axis_angle(axis, 1.2);
+        let vec = Vec3f::new(1.0, 2.0, -0.5);
+        assert!((rotation * vec - matrix * vec).length() < 1e-6);
+        assert!(rotation.to_mat3().abs_diff_eq(matrix, 1e-6));
+        assert!(rotation.is_normalized());
+
+        let (out_axis, angle) = rotation.to_axis_angle();
+        assert!((out_axis - axis).length() < 1e-6);
+        assert!((angle - 1.2).abs() < 1e-6);
+        assert_eq!(Quatf::IDENTITY.to_axis_angle(), (Vec3f::X, 0.0));
+    }
+
+    // Tests converting to and from rotation matrices, covering every branch of the conversion
+    #[test]
+    fn test_quatf_mat3_round_trip() {
+        for (axis, angle) in [
+            (Vec3f::X, 0.3),
+            (Vec3f::X, 3.0),
+            (Vec3f::Y, 3.0),
+            (Vec3f::Z, 3.0),
+            (Vec3f::new(0.48, 0.6, 0.64), -2.0),
+        ] {
+            let matrix = Mat3f::from_axis_angle(axis, angle);
+            let rotation = Quatf::from_mat3(&matrix);
+            assert!(rotation.to_mat3().abs_diff_eq(matrix, 1e-5));
+            assert!(rotation.angle_between(Quatf::from_axis_angle(axis, angle)) < 1e-3);
+        }
+    }
+
+    // Tests converting to and from affine transform matrices
+    #[test]
+    fn test_quatf_mat4() {
+        let rotation = Quatf::from_axis_angle(Vec3f::Y, 0.7);
+        let matrix = Mat4f::from(rotation);
+        assert!(matrix.abs_diff_eq(Mat4f::from_axis_angle(Vec3f::Y, 0.7), 1e-6));
+        assert!(Quatf::from_mat4(&matrix).abs_diff_eq(rotation, 1e-6));
+        assert_eq!(Mat3f::from(rotation), rotation.to_mat3());
+    }
+
+    // Tests rotation arcs between general, equal and opposite vectors
+    #[test]
+    fn test_quatf_from_rotation_arc() {
+        let from = Vec3f::new(1.0, 2.0, 2.0) / 3.0;
+        let to = Vec3f::new(0.0, -0.6, 0.8);
+        assert!((Quatf::from_rotation_arc(from, to) * from - to).length() < 1e-6);
+        assert!(Quatf::from_rotation_arc(to, to).abs_diff_eq(Quatf::IDENTITY, 1e-6));
+        for vec in [Vec3f::X, Vec3f::Y, from] {
+            let rotation = Quatf::from_rotation_arc(vec, -vec);
+            assert!((rotation * vec + vec).length() < 1e-6);
+            assert!(rotation.is_normalized());
+        }
+    }
+
+    // Tests that a look rotation points z forward and keeps y close to up
+    #[test]
+    fn test_quatf_look_rotation() {
+        let forward = Vec3f::new(1.0, 1.0, 0.0);
+        let rotation = Quatf::look_rotation(forward, Vec3f::Y);
+        assert!((rotation * Vec3f::Z - forward.normalize()).length() < 1e-6);
+        let up = rotation * Vec3f::Y;
+        assert!(up.dot(forward).abs() < 1e-6);
+        assert!(up.y > 0.0);
+        assert!((rotation * Vec3f::X).y.abs() < 1e-6);
+
+        let straight_up = Quatf::look_rotation(Vec3f::Y, Vec3f::Y);
+        assert!((straight_up * Vec3f::Z - Vec3f::Y).length() < 1e-6);
+    }
+
+    // Tests that composition applies the right-hand rotation first
+    #[test]
+    fn test_quatf_mul() {
+        let a = Quatf::from_axis_angle(Vec3f::Z, FRAC_PI_2);
+        let b = Quatf::from_axis_angle(Vec3f::X, FRAC_PI_2);
+        let vec = Vec3f::new(0.0, 1.0, 0.0);
+        assert!(((a * b) * vec - a * (b * vec)).length() < 1e-6);
+        assert!((a * b)
+            .to_mat3()
+            .abs_diff_eq(a.to_mat3() * b.to_mat3(), 1e-6));
+        let mut c = a;
+        c *= b;
+        assert_eq!(c, a * b);
+    }
+
+    // Tests the conjugate, inverse and normalization
+    #[test]
+    fn test_quatf_inverse() {
+        let rotation = Quatf::from_axis_angle(Vec3f::new(0.6, 0.8, 0.0), 2.0);
+        let conjugate = rotation.conjugate();
+        assert_eq!(
+            conjugate.to_array(),
+            [-rotation.x, -rotation.y, -rotation.z, rotation.w]
+        );
+        assert!((rotation.conjugate() * rotation).abs_diff_eq(Quatf::IDENTITY, 1e-6));
+        let scaled = rotation * 2.0;
+        assert!((scaled * scaled.inverse()).abs_diff_eq(Quatf::IDENTITY, 1e-6));
+        assert!(scaled.normalize().abs_diff_eq(rotation, 1e-6));
+        assert!(!scaled.is_normalized());
+        let vec = Vec3f::new(3.0, -1.0, 2.0);
+        assert!((rotation.inverse() * (rotation * vec) - vec).length() < 1e-5);
+    }
+
+    // Tests spherical interpolation, including the shorter path and nearly equal rotations
+    #[test]
+    fn test_quatf_slerp() {
+        let start = Quatf::from_axis_angle(Vec3f::Z, 0.2);
+        let end = Quatf::from_axis_angle(Vec3f::Z, 1.4);
+        assert!(start.slerp(end, 0.0).abs_diff_eq(start, 1e-6));
+        assert!(start.slerp(end, 1.0).abs_diff_eq(end, 1e-6));
+        let quarter = start.slerp(end, 0.25);
+        assert!(quarter.abs_diff_eq(Quatf::from_axis_angle(Vec3f::Z, 0.5), 1e-6));
+
+        let flipped = start.slerp(-end, 0.25);
+        assert!(flipped.angle_between(quarter) < 1e-3);
+
+        let near = Quatf::from_axis_angle(Vec3f::Z, 0.2001);
+        assert!(start.slerp(near, 0.5).is_normalized());
+    }
+
+    // Tests normalized linear interpolation and the angle between rotations
+    #[test]
+    fn test_quatf_nlerp_angle_between() {
+        let start = Quatf::IDENTITY;
+        let end = Quatf::from_axis_angle(Vec3f::X, PI * 0.5);
+        let half = start.nlerp(end, 0.5);
+        assert!(half.abs_diff_eq(Quatf::from_axis_angle(Vec3f::X, PI * 0.25), 1e-6));
+        assert!(start.nlerp(-end, 0.5).abs_diff_eq(half, 1e-6));
+        assert!((start.angle_between(end) - PI * 0.5).abs() < 1e-5);
+        assert!((end.angle_between(-end)).abs() < 1e-3);
+    }
+}
+
+mod quatd_tests {
+    use super::Mat3d;
+    use super::Quatd;
+    use super::Vec3d;
+
+    // Tests that axis-angle rotations match the equivalent matrix and round trip through it
+    #[test]
+    fn test_quatd_mat3() {
+        let axis = Vec3d::new(0.48, 0.6, 0.64);
+        let rotation = Quatd::from_axis_angle(axis, -2.5);
+        let matrix = Mat3d::from_axis_angle(axis, -2.5);
+        assert!(rotation.to_mat3().abs_diff_eq(matrix, 1e-12));
+        assert!(Quatd::from_mat3(&matrix).angle_between(rotation) < 1e-7);
+        let vec = Vec3d::new(1.0, 2.0, -0.5);
+        assert!((rotation * vec - matrix * vec).length() < 1e-12);
+    }
+
+    // Tests spherical interpolation at a constant angular speed
+    #[test]
+    fn test_quatd_slerp() {
+        let start = Quatd::from_axis_angle(Vec3d::Y, -1.0);
+        let end = Quatd::from_axis_angle(Vec3d::Y, 2.0);
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            let expected = Quatd::from_axis_angle(Vec3d::Y, -1.0 + 3.0 * t);
+            assert!(start.slerp(end, t).abs_diff_eq(expected, 1e-12));
+        }
+    }
+
+    // Tests rotation arcs and look rotations in double precision
+    #[test]
+    fn test_quatd_arc_look() {
+        let to = Vec3d::new(0.0, 0.6, -0.8);
+        assert!((Quatd::from_rotation_arc(Vec3d::X, to) * Vec3d::X - to).length() < 1e-12);
+        let rotation = Quatd::look_rotation(to, Vec3d::Y);
+        assert!((rotation * Vec3d::Z - to).length() < 1e-12);
+        assert!((rotation * Vec3d::X).y.abs() < 1e-12);
+    }
+}
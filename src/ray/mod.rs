@@ -0,0 +1,8 @@
+pub mod ray2f;
+pub use ray2f::{Ray2f, RayHit2f};
+
+pub mod ray3f;
+pub use ray3f::{Ray3f, RayHit3f};
+
+#[cfg(test)]
+mod tests;
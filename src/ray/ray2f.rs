@@ -0,0 +1,214 @@
+use crate::circle::Circle;
+use crate::rect::Rect2f;
+use crate::vec2::Vec2f;
+
+/// A half-line of `f32` values starting at `origin` and running along `dir`
+///
+/// `dir` does not need to be unit length. Hit distances are measured in multiples of `dir`, so
+/// they are true distances only when `dir` is normalized. A ray with a zero `dir` never hits
+/// anything.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Ray2f {
+    /// The point the ray starts at
+    pub origin: Vec2f,
+    /// The direction the ray runs in
+    pub dir: Vec2f,
+}
+
+/// Where a [`Ray2f`] hits a shape
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit2f {
+    /// The ray parameter of the hit, so the hit point is `ray.at(distance)`
+    pub distance: f32,
+    /// The unit normal of the shape at the hit, facing against the ray direction
+    pub normal: Vec2f,
+}
+
+impl Ray2f {
+    /// Creates a new ray
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - A `Vec2f` holding the point the ray starts at
+    ///
+    /// * `dir` - A `Vec2f` holding the direction of the ray
+    pub const fn new(origin: Vec2f, dir: Vec2f) -> Self {
+        Self { origin, dir }
+    }
+
+    /// Returns the point `origin + dir * t`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::ray::Ray2f;
+    /// use yavml::vec2::Vec2f;
+    /// let ray = Ray2f::new(Vec2f::new(1.0, 1.0), Vec2f::new(2.0, 0.0));
+    /// assert_eq!(ray.at(1.5), Vec2f::new(4.0, 1.0));
+    /// ```
+    pub fn at(&self, t: f32) -> Vec2f {
+        self.origin + self.dir * t
+    }
+
+    /// Returns the first hit of the ray with the segment from `a` to `b`, or `None` if it
+    /// misses
+    ///
+    /// When the ray runs along a collinear segment, the hit is the first point of the segment
+    /// the ray reaches, or the origin if it starts on the segment, and the normal is the
+    /// reversed ray direction. A zero-length segment is treated as a single point.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::ray::Ray2f;
+    /// use yavml::vec2::Vec2f;
+    /// let ray = Ray2f::new(Vec2f::ZERO, Vec2f::new(1.0, 0.0));
+    /// let hit = ray.intersect_segment(Vec2f::new(3.0, -1.0), Vec2f::new(3.0, 1.0)).unwrap();
+    /// assert_eq!(hit.distance, 3.0);
+    /// assert_eq!(hit.normal, Vec2f::new(-1.0, 0.0));
+    /// ```
+    pub fn intersect_segment(&self, a: Vec2f, b: Vec2f) -> Option<RayHit2f> {
+        let dir_squared = self.dir.dot(self.dir);
+        if dir_squared == 0.0 {
+            return None;
+        }
+        let edge = b - a;
+        let to_a = a - self.origin;
+        let denom = self.dir.cross(edge);
+        if denom == 0.0 {
+            if to_a.cross(self.dir) != 0.0 {
+                return None;
+            }
+            let t_a = to_a.dot(self.dir) / dir_squared;
+            let t_b = (b - self.origin).dot(self.dir) / dir_squared;
+            if t_a.max(t_b) < 0.0 {
+                return None;
+            }
+            return Some(RayHit2f {
+                distance: t_a.min(t_b).max(0.0),
+                normal: facing(-self.dir, self.dir),
+            });
+        }
+        let t = to_a.cross(edge) / denom;
+        let s = to_a.cross(self.dir) / denom;
+        if t < 0.0 || !(0.0..=1.0).contains(&s) {
+            return None;
+        }
+        Some(RayHit2f {
+            distance: t,
+            normal: facing(Vec2f::new(-edge.y, edge.x), self.dir),
+        })
+    }
+
+    /// Returns the first hit of the ray with the edge of `circle`, or `None` if it misses
+    ///
+    /// A ray starting inside the circle hits the edge on its way out.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::circle::Circle;
+    /// use yavml::ray::Ray2f;
+    /// use yavml::vec2::Vec2f;
+    /// let ray = Ray2f::new(Vec2f::new(-5.0, 0.0), Vec2f::new(1.0, 0.0));
+    /// let hit = ray.intersect_circle(&Circle::new(Vec2f::ZERO, 2.0)).unwrap();
+    /// assert_eq!(hit.distance, 3.0);
+    /// assert_eq!(hit.normal, Vec2f::new(-1.0, 0.0));
+    /// ```
+    pub fn intersect_circle(&self, circle: &Circle) -> Option<RayHit2f> {
+        let a = self.dir.dot(self.dir);
+        if a == 0.0 {
+            return None;
+        }
+        let offset = self.origin - circle.center;
+        let b = offset.dot(self.dir);
+        let c = offset.dot(offset) - circle.radius * circle.radius;
+        if c > 0.0 && b > 0.0 {
+            return None;
+        }
+        let discriminant = b * b - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let root = discriminant.sqrt();
+        let near = (-b - root) / a;
+        let t = if near >= 0.0 { near } else { (-b + root) / a };
+        Some(RayHit2f {
+            distance: t,
+            normal: facing(self.at(t) - circle.center, self.dir),
+        })
+    }
+
+    /// Returns the first hit of the ray with the edges of `rect` using the slab method, or
+    /// `None` if it misses or `rect` is empty
+    ///
+    /// A ray starting inside the rectangle hits the edge it leaves through. A ray running
+    /// exactly along an edge counts as hitting it.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::ray::Ray2f;
+    /// use yavml::rect::Rect2f;
+    /// use yavml::vec2::Vec2f;
+    /// let rect = Rect2f::new(Vec2f::new(2.0, -1.0), Vec2f::new(4.0, 1.0));
+    /// let ray = Ray2f::new(Vec2f::ZERO, Vec2f::new(1.0, 0.25));
+    /// let hit = ray.intersect_rect(&rect).unwrap();
+    /// assert_eq!(hit.distance, 2.0);
+    /// assert_eq!(hit.normal, Vec2f::new(-1.0, 0.0));
+    /// ```
+    pub fn intersect_rect(&self, rect: &Rect2f) -> Option<RayHit2f> {
+        if rect.is_empty() || self.dir.dot(self.dir) == 0.0 {
+            return None;
+        }
+        let origin = self.origin.to_array();
+        let dir = self.dir.to_array();
+        let min = rect.min.to_array();
+        let max = rect.max.to_array();
+        let (mut near, mut near_axis) = (f32::NEG_INFINITY, 0);
+        let (mut far, mut far_axis) = (f32::INFINITY, 0);
+        for axis in 0..2 {
+            if dir[axis] == 0.0 {
+                if origin[axis] < min[axis] || origin[axis] > max[axis] {
+                    return None;
+                }
+                continue;
+            }
+            let t_min = (min[axis] - origin[axis]) / dir[axis];
+            let t_max = (max[axis] - origin[axis]) / dir[axis];
+            let (entry, exit) = (t_min.min(t_max), t_min.max(t_max));
+            if entry > near {
+                (near, near_axis) = (entry, axis);
+            }
+            if exit < far {
+                (far, far_axis) = (exit, axis);
+            }
+        }
+        if near > far || far < 0.0 {
+            return None;
+        }
+        let (t, axis) = if near >= 0.0 {
+            (near, near_axis)
+        } else {
+            (far, far_axis)
+        };
+        let mut normal = [0.0; 2];
+        normal[axis] = -dir[axis].signum();
+        Some(RayHit2f {
+            distance: t,
+            normal: Vec2f::from_arr(normal),
+        })
+    }
+}
+
+/// Returns `normal` scaled to unit length and flipped to face against `dir`, falling back to the
+/// reversed `dir` when `normal` is zero
+fn facing(normal: Vec2f, dir: Vec2f) -> Vec2f {
+    let normal = if normal.dot(normal) == 0.0 {
+        -dir
+    } else {
+        normal
+    };
+    let normal = normal / normal.dot(normal).sqrt();
+    if normal.dot(dir) > 0.0 {
+        -normal
+    } else {
+        normal
+    }
+}
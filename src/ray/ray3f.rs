@@ -0,0 +1,236 @@
+use crate::aabb::Aabb3f;
+use crate::circle::Sphere;
+use crate::plane::Plane;
+use crate::vec3::Vec3f;
+
+/// A half-line of `f32` values starting at `origin` and running along `dir`
+///
+/// `dir` does not need to be unit length. Hit distances are measured in multiples of `dir`, so
+/// they are true distances only when `dir` is normalized. A ray with a zero `dir` never hits
+/// anything.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Ray3f {
+    /// The point the ray starts at
+    pub origin: Vec3f,
+    /// The direction the ray runs in
+    pub dir: Vec3f,
+}
+
+/// Where a [`Ray3f`] hits a shape
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit3f {
+    /// The ray parameter of the hit, so the hit point is `ray.at(distance)`
+    pub distance: f32,
+    /// The unit normal of the shape at the hit, facing against the ray direction
+    pub normal: Vec3f,
+}
+
+impl Ray3f {
+    /// Creates a new ray
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - A `Vec3f` holding the point the ray starts at
+    ///
+    /// * `dir` - A `Vec3f` holding the direction of the ray
+    pub const fn new(origin: Vec3f, dir: Vec3f) -> Self {
+        Self { origin, dir }
+    }
+
+    /// Returns the point `origin + dir * t`
+    pub fn at(&self, t: f32) -> Vec3f {
+        self.origin + self.dir * t
+    }
+
+    /// Returns the first hit of the ray with the surface of `sphere`, or `None` if it misses
+    ///
+    /// A ray starting inside the sphere hits the surface on its way out.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::circle::Sphere;
+    /// use yavml::ray::Ray3f;
+    /// use yavml::vec3::Vec3f;
+    /// let ray = Ray3f::new(Vec3f::new(0.0, 0.0, 10.0), Vec3f::new(0.0, 0.0, -2.0));
+    /// let hit = ray.intersect_sphere(&Sphere::new(Vec3f::ZERO, 4.0)).unwrap();
+    /// assert_eq!(hit.distance, 3.0);
+    /// assert_eq!(hit.normal, Vec3f::Z);
+    /// ```
+    pub fn intersect_sphere(&self, sphere: &Sphere) -> Option<RayHit3f> {
+        let a = self.dir.dot(self.dir);
+        if a == 0.0 {
+            return None;
+        }
+        let offset = self.origin - sphere.center;
+        let b = offset.dot(self.dir);
+        let c = offset.dot(offset) - sphere.radius * sphere.radius;
+        if c > 0.0 && b > 0.0 {
+            return None;
+        }
+        let discriminant = b * b - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let root = discriminant.sqrt();
+        let near = (-b - root) / a;
+        let t = if near >= 0.0 { near } else { (-b + root) / a };
+        Some(RayHit3f {
+            distance: t,
+            normal: facing(self.at(t) - sphere.center, self.dir),
+        })
+    }
+
+    /// Returns the first hit of the ray with the faces of `aabb` using the slab method, or
+    /// `None` if it misses or `aabb` is empty
+    ///
+    /// A ray starting inside the box hits the face it leaves through. A ray running exactly
+    /// along a face counts as hitting it.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::aabb::Aabb3f;
+    /// use yavml::ray::Ray3f;
+    /// use yavml::vec3::Vec3f;
+    /// let aabb = Aabb3f::new(Vec3f::splat(-1.0), Vec3f::splat(1.0));
+    /// let ray = Ray3f::new(Vec3f::new(0.5, -4.0, 0.0), Vec3f::Y);
+    /// let hit = ray.intersect_aabb(&aabb).unwrap();
+    /// assert_eq!(hit.distance, 3.0);
+    /// assert_eq!(hit.normal, Vec3f::new(0.0, -1.0, 0.0));
+    /// ```
+    pub fn intersect_aabb(&self, aabb: &Aabb3f) -> Option<RayHit3f> {
+        if aabb.is_empty() || self.dir.dot(self.dir) == 0.0 {
+            return None;
+        }
+        let origin = self.origin.to_array();
+        let dir = self.dir.to_array();
+        let min = aabb.min.to_array();
+        let max = aabb.max.to_array();
+        let (mut near, mut near_axis) = (f32::NEG_INFINITY, 0);
+        let (mut far, mut far_axis) = (f32::INFINITY, 0);
+        for axis in 0..3 {
+            if dir[axis] == 0.0 {
+                if origin[axis] < min[axis] || origin[axis] > max[axis] {
+                    return None;
+                }
+                continue;
+            }
+            let t_min = (min[axis] - origin[axis]) / dir[axis];
+            let t_max = (max[axis] - origin[axis]) / dir[axis];
+            let (entry, exit) = (t_min.min(t_max), t_min.max(t_max));
+            if entry > near {
+                (near, near_axis) = (entry, axis);
+            }
+            if exit < far {
+                (far, far_axis) = (exit, axis);
+            }
+        }
+        if near > far || far < 0.0 {
+            return None;
+        }
+        let (t, axis) = if near >= 0.0 {
+            (near, near_axis)
+        } else {
+            (far, far_axis)
+        };
+        let mut normal = [0.0; 3];
+        normal[axis] = -dir[axis].signum();
+        Some(RayHit3f {
+            distance: t,
+            normal: Vec3f::from_arr(normal),
+        })
+    }
+
+    /// Returns the hit of the ray with `plane`, or `None` if it misses
+    ///
+    /// A ray parallel to the plane never hits it, even when it lies in the plane, and neither
+    /// does any ray when the plane normal is zero.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::plane::Plane;
+    /// use yavml::ray::Ray3f;
+    /// use yavml::vec3::Vec3f;
+    /// let ray = Ray3f::new(Vec3f::new(0.0, 5.0, 0.0), Vec3f::new(1.0, -1.0, 0.0));
+    /// let hit = ray.intersect_plane(&Plane::new(Vec3f::Y, -2.0)).unwrap();
+    /// assert_eq!(ray.at(hit.distance), Vec3f::new(3.0, 2.0, 0.0));
+    /// assert_eq!(hit.normal, Vec3f::Y);
+    /// ```
+    pub fn intersect_plane(&self, plane: &Plane) -> Option<RayHit3f> {
+        let denom = plane.normal.dot(self.dir);
+        if denom == 0.0 {
+            return None;
+        }
+        let t = -plane.signed_distance(self.origin) / denom;
+        if t < 0.0 {
+            return None;
+        }
+        Some(RayHit3f {
+            distance: t,
+            normal: facing(plane.normal, self.dir),
+        })
+    }
+
+    /// Returns the hit of the ray with the triangle `a`, `b`, `c` using the Möller–Trumbore
+    /// algorithm, or `None` if it misses
+    ///
+    /// Both sides of the triangle can be hit. Degenerate triangles with collinear corners and
+    /// rays parallel to the triangle, including rays lying in its plane, never hit.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::ray::Ray3f;
+    /// use yavml::vec3::Vec3f;
+    /// let ray = Ray3f::new(Vec3f::new(0.25, 0.25, -3.0), Vec3f::Z);
+    /// let hit = ray
+    ///     .intersect_triangle(Vec3f::ZERO, Vec3f::X, Vec3f::Y)
+    ///     .unwrap();
+    /// assert_eq!(hit.distance, 3.0);
+    /// assert_eq!(hit.normal, Vec3f::new(0.0, 0.0, -1.0));
+    /// ```
+    pub fn intersect_triangle(&self, a: Vec3f, b: Vec3f, c: Vec3f) -> Option<RayHit3f> {
+        let edge1 = b - a;
+        let edge2 = c - a;
+        let normal = edge1.cross(edge2);
+        let p = self.dir.cross(edge2);
+        let det = edge1.dot(p);
+        let scale = (self.dir.dot(self.dir) * normal.dot(normal)).sqrt();
+        if scale == 0.0 || det.abs() <= f32::EPSILON * scale {
+            return None;
+        }
+        let inv_det = 1.0 / det;
+        let s = self.origin - a;
+        let u = s.dot(p) * inv_det;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+        let q = s.cross(edge1);
+        let v = self.dir.dot(q) * inv_det;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+        let t = edge2.dot(q) * inv_det;
+        if t < 0.0 {
+            return None;
+        }
+        Some(RayHit3f {
+            distance: t,
+            normal: facing(normal, self.dir),
+        })
+    }
+}
+
+/// Returns `normal` scaled to unit length and flipped to face against `dir`, falling back to the
+/// reversed `dir` when `normal` is zero
+fn facing(normal: Vec3f, dir: Vec3f) -> Vec3f {
+    let normal = if normal.dot(normal) == 0.0 {
+        -dir
+    } else {
+        normal
+    };
+    let normal = normal / normal.dot(normal).sqrt();
+    if normal.dot(dir) > 0.0 {
+        -normal
+    } else {
+        normal
+    }
+}
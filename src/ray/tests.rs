@@ -0,0 +1,208 @@
+use super::Ray2f;
+use super::Ray3f;
+use crate::aabb::Aabb3f;
+use crate::circle::Circle;
+use crate::circle::Sphere;
+use crate::plane::Plane;
+use crate::rect::Rect2f;
+use crate::vec2::Vec2f;
+use crate::vec3::Vec3f;
+
+mod ray2f_tests {
+    use super::Circle;
+    use super::Ray2f;
+    use super::Rect2f;
+    use super::Vec2f;
+
+    // Tests segment hits, misses behind the origin and past the ends
+    #[test]
+    fn test_ray2f_intersect_segment() {
+        let ray = Ray2f::new(Vec2f::new(0.0, 1.0), Vec2f::new(2.0, 0.0));
+        let hit = ray
+            .intersect_segment(Vec2f::new(4.0, 0.0), Vec2f::new(4.0, 2.0))
+            .unwrap();
+        assert_eq!(hit.distance, 2.0);
+        assert_eq!(hit.normal, Vec2f::new(-1.0, 0.0));
+        assert!(ray
+            .intersect_segment(Vec2f::new(-4.0, 0.0), Vec2f::new(-4.0, 2.0))
+            .is_none());
+        assert!(ray
+            .intersect_segment(Vec2f::new(4.0, 2.0), Vec2f::new(4.0, 3.0))
+            .is_none());
+    }
+
+    // Tests parallel, collinear and zero-length segments
+    #[test]
+    fn test_ray2f_intersect_segment_degenerate() {
+        let ray = Ray2f::new(Vec2f::ZERO, Vec2f::new(1.0, 0.0));
+        assert!(ray
+            .intersect_segment(Vec2f::new(1.0, 1.0), Vec2f::new(5.0, 1.0))
+            .is_none());
+        let hit = ray
+            .intersect_segment(Vec2f::new(5.0, 0.0), Vec2f::new(3.0, 0.0))
+            .unwrap();
+        assert_eq!(hit.distance, 3.0);
+        assert_eq!(hit.normal, Vec2f::new(-1.0, 0.0));
+        let hit = ray
+            .intersect_segment(Vec2f::new(-1.0, 0.0), Vec2f::new(3.0, 0.0))
+            .unwrap();
+        assert_eq!(hit.distance, 0.0);
+        assert!(ray
+            .intersect_segment(Vec2f::new(-3.0, 0.0), Vec2f::new(-1.0, 0.0))
+            .is_none());
+        let point = Vec2f::new(2.0, 0.0);
+        assert_eq!(ray.intersect_segment(point, point).unwrap().distance, 2.0);
+        assert!(ray
+            .intersect_segment(Vec2f::new(2.0, 1.0), Vec2f::new(2.0, 1.0))
+            .is_none());
+        let still = Ray2f::new(Vec2f::ZERO, Vec2f::ZERO);
+        assert!(still
+            .intersect_segment(Vec2f::new(-1.0, 0.0), Vec2f::new(1.0, 0.0))
+            .is_none());
+    }
+
+    // Tests circle hits from outside and inside, and misses
+    #[test]
+    fn test_ray2f_intersect_circle() {
+        let circle = Circle::new(Vec2f::new(5.0, 0.0), 1.0);
+        let ray = Ray2f::new(Vec2f::ZERO, Vec2f::new(1.0, 0.0));
+        let hit = ray.intersect_circle(&circle).unwrap();
+        assert_eq!(hit.distance, 4.0);
+        assert_eq!(hit.normal, Vec2f::new(-1.0, 0.0));
+        let inside = Ray2f::new(Vec2f::new(5.0, 0.0), Vec2f::new(0.0, 1.0));
+        let hit = inside.intersect_circle(&circle).unwrap();
+        assert_eq!(hit.distance, 1.0);
+        assert_eq!(hit.normal, Vec2f::new(0.0, -1.0));
+        let away = Ray2f::new(Vec2f::ZERO, Vec2f::new(-1.0, 0.0));
+        assert!(away.intersect_circle(&circle).is_none());
+        let above = Ray2f::new(Vec2f::new(0.0, 1.5), Vec2f::new(1.0, 0.0));
+        assert!(above.intersect_circle(&circle).is_none());
+    }
+
+    // Tests slab hits on each axis, from inside, along an edge and for axis-parallel misses
+    #[test]
+    fn test_ray2f_intersect_rect() {
+        let rect = Rect2f::new(Vec2f::new(-1.0, -1.0), Vec2f::new(1.0, 1.0));
+        let down = Ray2f::new(Vec2f::new(0.5, 3.0), Vec2f::new(0.0, -1.0));
+        let hit = down.intersect_rect(&rect).unwrap();
+        assert_eq!(hit.distance, 2.0);
+        assert_eq!(hit.normal, Vec2f::new(0.0, 1.0));
+        let inside = Ray2f::new(Vec2f::ZERO, Vec2f::new(2.0, 1.0));
+        let hit = inside.intersect_rect(&rect).unwrap();
+        assert_eq!(hit.distance, 0.5);
+        assert_eq!(hit.normal, Vec2f::new(-1.0, 0.0));
+        let along = Ray2f::new(Vec2f::new(-3.0, 1.0), Vec2f::new(1.0, 0.0));
+        assert_eq!(along.intersect_rect(&rect).unwrap().distance, 2.0);
+        let beside = Ray2f::new(Vec2f::new(-3.0, 2.0), Vec2f::new(1.0, 0.0));
+        assert!(beside.intersect_rect(&rect).is_none());
+        let diagonal = Ray2f::new(Vec2f::new(-3.0, 0.0), Vec2f::new(1.0, 1.0));
+        assert!(diagonal.intersect_rect(&rect).is_none());
+        let empty = Rect2f::new(Vec2f::ONE, Vec2f::ZERO);
+        assert!(down.intersect_rect(&empty).is_none());
+    }
+}
+
+mod ray3f_tests {
+    use super::Aabb3f;
+    use super::Plane;
+    use super::Ray3f;
+    use super::Sphere;
+    use super::Vec3f;
+
+    // Tests that at moves along the direction
+    #[test]
+    fn test_ray3f_at() {
+        let ray = Ray3f::new(Vec3f::ONE, Vec3f::new(0.0, 2.0, -1.0));
+        assert_eq!(ray.at(0.0), Vec3f::ONE);
+        assert_eq!(ray.at(2.0), Vec3f::new(1.0, 5.0, -1.0));
+    }
+
+    // Tests sphere hits from outside and inside, tangent hits and misses
+    #[test]
+    fn test_ray3f_intersect_sphere() {
+        let sphere = Sphere::new(Vec3f::new(0.0, 0.0, -5.0), 2.0);
+        let ray = Ray3f::new(Vec3f::ZERO, Vec3f::new(0.0, 0.0, -1.0));
+        let hit = ray.intersect_sphere(&sphere).unwrap();
+        assert_eq!(hit.distance, 3.0);
+        assert_eq!(hit.normal, Vec3f::Z);
+        let inside = Ray3f::new(sphere.center, Vec3f::X);
+        let hit = inside.intersect_sphere(&sphere).unwrap();
+        assert_eq!(hit.distance, 2.0);
+        assert_eq!(hit.normal, Vec3f::new(-1.0, 0.0, 0.0));
+        let tangent = Ray3f::new(Vec3f::new(2.0, 0.0, 0.0), Vec3f::new(0.0, 0.0, -1.0));
+        assert_eq!(tangent.intersect_sphere(&sphere).unwrap().distance, 5.0);
+        let miss = Ray3f::new(Vec3f::new(2.5, 0.0, 0.0), Vec3f::new(0.0, 0.0, -1.0));
+        assert!(miss.intersect_sphere(&sphere).is_none());
+        assert!(Ray3f::new(Vec3f::ZERO, Vec3f::Z)
+            .intersect_sphere(&sphere)
+            .is_none());
+    }
+
+    // Tests slab hits against each face, hits from inside and misses
+    #[test]
+    fn test_ray3f_intersect_aabb() {
+        let aabb = Aabb3f::new(Vec3f::ZERO, Vec3f::new(2.0, 2.0, 2.0));
+        let ray = Ray3f::new(Vec3f::new(1.0, 1.0, 6.0), Vec3f::new(0.0, 0.0, -2.0));
+        let hit = ray.intersect_aabb(&aabb).unwrap();
+        assert_eq!(hit.distance, 2.0);
+        assert_eq!(hit.normal, Vec3f::Z);
+        let inside = Ray3f::new(Vec3f::ONE, Vec3f::new(1.0, 3.0, 2.0));
+        let hit = inside.intersect_aabb(&aabb).unwrap();
+        assert!((hit.distance - 1.0 / 3.0).abs() < 1e-6);
+        assert_eq!(hit.normal, Vec3f::new(0.0, -1.0, 0.0));
+        let miss = Ray3f::new(Vec3f::new(3.0, 1.0, 6.0), Vec3f::new(0.0, 0.0, -1.0));
+        assert!(miss.intersect_aabb(&aabb).is_none());
+        let behind = Ray3f::new(Vec3f::new(1.0, 1.0, 6.0), Vec3f::Z);
+        assert!(behind.intersect_aabb(&aabb).is_none());
+    }
+
+    // Tests plane hits from both sides, and parallel and backwards rays
+    #[test]
+    fn test_ray3f_intersect_plane() {
+        let ray = Ray3f::new(Vec3f::new(1.0, 1.0, -4.0), Vec3f::Z);
+        let hit = ray
+            .intersect_plane(&Plane::new(Vec3f::new(0.0, 0.0, 2.0), -2.0))
+            .unwrap();
+        assert_eq!(hit.distance, 5.0);
+        assert_eq!(hit.normal, Vec3f::new(0.0, 0.0, -1.0));
+        let back = Ray3f::new(Vec3f::new(0.0, 0.0, 4.0), Vec3f::new(0.0, 0.0, -1.0));
+        assert_eq!(
+            back.intersect_plane(&Plane::new(Vec3f::Z, -1.0))
+                .unwrap()
+                .normal,
+            Vec3f::Z
+        );
+        assert!(ray.intersect_plane(&Plane::new(Vec3f::Z, 5.0)).is_none());
+        assert!(ray.intersect_plane(&Plane::new(Vec3f::X, 0.0)).is_none());
+        assert!(ray.intersect_plane(&Plane::new(Vec3f::ZERO, 1.0)).is_none());
+    }
+
+    // Tests triangle hits on both sides, edge misses, and parallel and degenerate cases
+    #[test]
+    fn test_ray3f_intersect_triangle() {
+        let (a, b, c) = (
+            Vec3f::new(0.0, 0.0, 1.0),
+            Vec3f::new(4.0, 0.0, 1.0),
+            Vec3f::new(0.0, 4.0, 1.0),
+        );
+        let down = Ray3f::new(Vec3f::new(1.0, 1.0, 5.0), Vec3f::new(0.0, 0.0, -1.0));
+        let hit = down.intersect_triangle(a, b, c).unwrap();
+        assert_eq!(hit.distance, 4.0);
+        assert_eq!(hit.normal, Vec3f::Z);
+        let up = Ray3f::new(Vec3f::new(1.0, 1.0, -5.0), Vec3f::Z);
+        let hit = up.intersect_triangle(a, b, c).unwrap();
+        assert_eq!(hit.distance, 6.0);
+        assert_eq!(hit.normal, Vec3f::new(0.0, 0.0, -1.0));
+        let outside = Ray3f::new(Vec3f::new(3.0, 3.0, 5.0), Vec3f::new(0.0, 0.0, -1.0));
+        assert!(outside.intersect_triangle(a, b, c).is_none());
+        assert!(up
+            .intersect_triangle(a, b, c + Vec3f::new(0.0, 0.0, 9.0))
+            .is_some());
+        assert!(Ray3f::new(Vec3f::new(1.0, 1.0, 5.0), Vec3f::Z)
+            .intersect_triangle(a, b, c)
+            .is_none());
+        let in_plane = Ray3f::new(Vec3f::new(-1.0, 1.0, 1.0), Vec3f::X);
+        assert!(in_plane.intersect_triangle(a, b, c).is_none());
+        assert!(down.intersect_triangle(a, b, a + (b - a) * 2.0).is_none());
+    }
+}
@@ -0,0 +1,14 @@
+pub mod rect2f;
+pub use rect2f::Rect2f;
+
+pub mod rect2d;
+pub use rect2d::Rect2d;
+
+pub mod rect2i;
+pub use rect2i::Rect2i;
+
+pub mod rect2i_iter;
+pub use rect2i_iter::{BorderPoints, Chunks, ColumnMajorPoints, RowMajorPoints};
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,173 @@
+use crate::vec2::Vec2f;
+
+/// An axis-aligned rectangle of `f32` values stored as its minimum and maximum corners
+///
+/// The rectangle is closed: points on its edges are inside it, and rectangles that only touch
+/// along an edge still intersect. A rectangle with `min` greater than `max` on either axis
+/// contains nothing.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect2f {
+    /// The corner with the smallest coordinates
+    pub min: Vec2f,
+    /// The corner with the largest coordinates
+    pub max: Vec2f,
+}
+
+impl Rect2f {
+    /// Creates a new rectangle from its corners
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - A `Vec2f` holding the corner with the smallest coordinates
+    ///
+    /// * `max` - A `Vec2f` holding the corner with the largest coordinates
+    pub const fn new(min: Vec2f, max: Vec2f) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates a rectangle of size `size` centered on `center`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::rect::Rect2f;
+    /// use yavml::vec2::Vec2f;
+    /// let rect = Rect2f::from_center_size(Vec2f::new(1.0, 1.0), Vec2f::new(4.0, 2.0));
+    /// assert_eq!(rect, Rect2f::new(Vec2f::new(-1.0, 0.0), Vec2f::new(3.0, 2.0)));
+    /// ```
+    pub fn from_center_size(center: Vec2f, size: Vec2f) -> Self {
+        let half = size * 0.5;
+        Self::new(center - half, center + half)
+    }
+
+    /// Creates the smallest rectangle containing every point of `points`, or `None` if there
+    /// are no points
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::rect::Rect2f;
+    /// use yavml::vec2::Vec2f;
+    /// let points = [Vec2f::new(1.0, 5.0), Vec2f::new(-2.0, 3.0), Vec2f::new(0.0, 7.0)];
+    /// let rect = Rect2f::from_points(points).unwrap();
+    /// assert_eq!(rect, Rect2f::new(Vec2f::new(-2.0, 3.0), Vec2f::new(1.0, 7.0)));
+    /// assert_eq!(Rect2f::from_points([]), None);
+    /// ```
+    pub fn from_points<I: IntoIterator<Item = Vec2f>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, first), |rect, point| rect.expand(point)))
+    }
+
+    /// Returns the width and height of the rectangle
+    pub fn size(&self) -> Vec2f {
+        self.max - self.min
+    }
+
+    /// Returns the center of the rectangle
+    pub fn center(&self) -> Vec2f {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Returns the area of the rectangle, or zero if it is empty
+    pub fn area(&self) -> f32 {
+        let size = self.size();
+        size.x.max(0.0) * size.y.max(0.0)
+    }
+
+    /// Returns `true` if `min` is greater than `max` on either axis
+    ///
+    /// A rectangle with `min == max` is a single point and is not empty.
+    pub fn is_empty(&self) -> bool {
+        !(self.min.x <= self.max.x && self.min.y <= self.max.y)
+    }
+
+    /// Returns `true` if `point` is inside the rectangle or on its edges
+    pub fn contains_point(&self, point: Vec2f) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+    }
+
+    /// Returns `true` if every point of `rect` is inside `self`
+    ///
+    /// An empty `rect` is contained in every rectangle.
+    pub fn contains_rect(&self, rect: &Self) -> bool {
+        rect.is_empty() || (self.contains_point(rect.min) && self.contains_point(rect.max))
+    }
+
+    /// Returns `true` if `self` and `rect` share at least one point
+    ///
+    /// An empty rectangle intersects nothing, matching [`Self::intersection`].
+    pub fn intersects(&self, rect: &Self) -> bool {
+        self.intersection(rect).is_some()
+    }
+
+    /// Returns the overlap of `self` and `rect`, or `None` if they do not intersect
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::rect::Rect2f;
+    /// use yavml::vec2::Vec2f;
+    /// let a = Rect2f::new(Vec2f::ZERO, Vec2f::new(4.0, 4.0));
+    /// let b = Rect2f::new(Vec2f::new(2.0, 3.0), Vec2f::new(6.0, 8.0));
+    /// let overlap = Rect2f::new(Vec2f::new(2.0, 3.0), Vec2f::new(4.0, 4.0));
+    /// assert_eq!(a.intersection(&b), Some(overlap));
+    /// ```
+    pub fn intersection(&self, rect: &Self) -> Option<Self> {
+        let overlap = Self::new(
+            Vec2f::new(self.min.x.max(rect.min.x), self.min.y.max(rect.min.y)),
+            Vec2f::new(self.max.x.min(rect.max.x), self.max.y.min(rect.max.y)),
+        );
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    /// Returns the smallest rectangle containing both `self` and `rect`
+    ///
+    /// Empty rectangles are ignored, and the union of two empty rectangles is `self`.
+    pub fn union(&self, rect: &Self) -> Self {
+        if rect.is_empty() {
+            return *self;
+        }
+        if self.is_empty() {
+            return *rect;
+        }
+        Self::new(
+            Vec2f::new(self.min.x.min(rect.min.x), self.min.y.min(rect.min.y)),
+            Vec2f::new(self.max.x.max(rect.max.x), self.max.y.max(rect.max.y)),
+        )
+    }
+
+    /// Returns the smallest rectangle containing both `self` and `point`
+    pub fn expand(&self, point: Vec2f) -> Self {
+        Self::new(
+            Vec2f::new(self.min.x.min(point.x), self.min.y.min(point.y)),
+            Vec2f::new(self.max.x.max(point.x), self.max.y.max(point.y)),
+        )
+    }
+
+    /// Returns the rectangle with each edge moved outwards by `amount`
+    ///
+    /// Negative values shrink the rectangle, which becomes empty once an edge passes its
+    /// opposite edge.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::rect::Rect2f;
+    /// use yavml::vec2::Vec2f;
+    /// let rect = Rect2f::new(Vec2f::ZERO, Vec2f::new(4.0, 2.0)).inflate(Vec2f::new(1.0, -0.5));
+    /// assert_eq!(rect, Rect2f::new(Vec2f::new(-1.0, 0.5), Vec2f::new(5.0, 1.5)));
+    /// ```
+    pub fn inflate(&self, amount: Vec2f) -> Self {
+        Self::new(self.min - amount, self.max + amount)
+    }
+
+    /// Returns the point of the rectangle closest to `point`
+    ///
+    /// The rectangle must not be empty.
+    pub fn clamp_point(&self, point: Vec2f) -> Vec2f {
+        Vec2f::new(
+            point.x.max(self.min.x).min(self.max.x),
+            point.y.max(self.min.y).min(self.max.y),
+        )
+    }
+}
@@ -0,0 +1,194 @@
+use super::Rect2f;
+use crate::vec2::Vec2i;
+
+/// An axis-aligned rectangle of integer cells stored as its minimum and maximum corners
+///
+/// The rectangle is half-open: `min` is inclusive and `max` is exclusive, so it covers the cells
+/// with `min.x <= x < max.x` and `min.y <= y < max.y`. Its size is `max - min`, and two
+/// rectangles that only share an edge do not intersect because they have no cell in common. A
+/// rectangle with `min` greater than or equal to `max` on either axis covers no cells.
+///
+/// # Examples:
+/// ```
+/// use yavml::rect::Rect2i;
+/// use yavml::vec2::Vec2i;
+/// let rect = Rect2i::new(Vec2i::new(0, 0), Vec2i::new(2, 3));
+/// assert_eq!(rect.area(), 6);
+/// assert!(rect.contains_point(Vec2i::new(1, 2)));
+/// assert!(!rect.contains_point(Vec2i::new(2, 2)));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect2i {
+    /// The first cell inside the rectangle
+    pub min: Vec2i,
+    /// The corner one past the last cell inside the rectangle
+    pub max: Vec2i,
+}
+
+impl Rect2i {
+    /// Creates a new rectangle from its inclusive `min` and exclusive `max` corners
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - A `Vec2i` holding the first cell inside the rectangle
+    ///
+    /// * `max` - A `Vec2i` holding the corner one past the last cell inside the rectangle
+    pub const fn new(min: Vec2i, max: Vec2i) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates a rectangle of `size` cells placed at `center`
+    ///
+    /// When a side has an even number of cells, `center` is the first cell of the upper half.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::rect::Rect2i;
+    /// use yavml::vec2::Vec2i;
+    /// let rect = Rect2i::from_center_size(Vec2i::new(5, 5), Vec2i::new(3, 4));
+    /// assert_eq!(rect, Rect2i::new(Vec2i::new(4, 3), Vec2i::new(7, 7)));
+    /// ```
+    pub fn from_center_size(center: Vec2i, size: Vec2i) -> Self {
+        let min = center - size / 2;
+        Self::new(min, min + size)
+    }
+
+    /// Creates the smallest rectangle covering every cell of `points`, or `None` if there are no
+    /// points
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::rect::Rect2i;
+    /// use yavml::vec2::Vec2i;
+    /// let rect = Rect2i::from_points([Vec2i::new(1, 5), Vec2i::new(-2, 3)]).unwrap();
+    /// assert_eq!(rect, Rect2i::new(Vec2i::new(-2, 3), Vec2i::new(2, 6)));
+    /// ```
+    pub fn from_points<I: IntoIterator<Item = Vec2i>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(
+            points.fold(Self::new(first, first + Vec2i::ONE), |rect, point| {
+                rect.expand(point)
+            }),
+        )
+    }
+
+    /// Returns the number of cells along the x and y axes
+    ///
+    /// A side of an empty rectangle can be zero or negative.
+    pub fn size(&self) -> Vec2i {
+        self.max - self.min
+    }
+
+    /// Returns the cell at the center of the rectangle, rounding towards `min`
+    pub fn center(&self) -> Vec2i {
+        self.min + self.size() / 2
+    }
+
+    /// Returns the number of cells in the rectangle, or zero if it is empty
+    ///
+    /// The count is a `u64`, which holds the area of every rectangle with corners in the `i32`
+    /// range without overflowing.
+    pub fn area(&self) -> u64 {
+        let side = |min: i32, max: i32| (i64::from(max) - i64::from(min)).max(0) as u64;
+        side(self.min.x, self.max.x) * side(self.min.y, self.max.y)
+    }
+
+    /// Returns `true` if the rectangle covers no cells
+    pub fn is_empty(&self) -> bool {
+        self.min.x >= self.max.x || self.min.y >= self.max.y
+    }
+
+    /// Returns `true` if the cell `point` is inside the rectangle
+    pub fn contains_point(&self, point: Vec2i) -> bool {
+        point.x >= self.min.x
+            && point.x < self.max.x
+            && point.y >= self.min.y
+            && point.y < self.max.y
+    }
+
+    /// Returns `true` if every cell of `rect` is inside `self`
+    ///
+    /// An empty `rect` is contained in every rectangle.
+    pub fn contains_rect(&self, rect: &Self) -> bool {
+        rect.is_empty()
+            || (rect.min.x >= self.min.x
+                && rect.max.x <= self.max.x
+                && rect.min.y >= self.min.y
+                && rect.max.y <= self.max.y)
+    }
+
+    /// Returns `true` if `self` and `rect` share at least one cell
+    pub fn intersects(&self, rect: &Self) -> bool {
+        self.intersection(rect).is_some()
+    }
+
+    /// Returns the cells shared by `self` and `rect`, or `None` if there are none
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::rect::Rect2i;
+    /// use yavml::vec2::Vec2i;
+    /// let a = Rect2i::new(Vec2i::new(0, 0), Vec2i::new(4, 4));
+    /// let b = Rect2i::new(Vec2i::new(2, 3), Vec2i::new(6, 8));
+    /// assert_eq!(a.intersection(&b), Some(Rect2i::new(Vec2i::new(2, 3), Vec2i::new(4, 4))));
+    /// let touching = Rect2i::new(Vec2i::new(4, 0), Vec2i::new(6, 4));
+    /// assert_eq!(a.intersection(&touching), None);
+    /// ```
+    pub fn intersection(&self, rect: &Self) -> Option<Self> {
+        let overlap = Self::new(
+            Vec2i::new(self.min.x.max(rect.min.x), self.min.y.max(rect.min.y)),
+            Vec2i::new(self.max.x.min(rect.max.x), self.max.y.min(rect.max.y)),
+        );
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    /// Returns the smallest rectangle covering both `self` and `rect`
+    ///
+    /// Empty rectangles are ignored, and the union of two empty rectangles is `self`.
+    pub fn union(&self, rect: &Self) -> Self {
+        if rect.is_empty() {
+            return *self;
+        }
+        if self.is_empty() {
+            return *rect;
+        }
+        Self::new(
+            Vec2i::new(self.min.x.min(rect.min.x), self.min.y.min(rect.min.y)),
+            Vec2i::new(self.max.x.max(rect.max.x), self.max.y.max(rect.max.y)),
+        )
+    }
+
+    /// Returns the smallest rectangle covering both `self` and the cell `point`
+    ///
+    /// The rectangle must not be empty.
+    pub fn expand(&self, point: Vec2i) -> Self {
+        Self::new(
+            Vec2i::new(self.min.x.min(point.x), self.min.y.min(point.y)),
+            Vec2i::new(self.max.x.max(point.x + 1), self.max.y.max(point.y + 1)),
+        )
+    }
+
+    /// Returns the rectangle with each edge moved outwards by `amount` cells
+    ///
+    /// Negative values shrink the rectangle, which becomes empty once an edge reaches its
+    /// opposite edge.
+    pub fn inflate(&self, amount: Vec2i) -> Self {
+        Self::new(self.min - amount, self.max + amount)
+    }
+
+    /// Returns the cell of the rectangle closest to `point`
+    ///
+    /// The result is always between `min` and `max - 1`, so the rectangle must not be empty.
+    pub fn clamp_point(&self, point: Vec2i) -> Vec2i {
+        Vec2i::new(
+            point.x.min(self.max.x - 1).max(self.min.x),
+            point.y.min(self.max.y - 1).max(self.min.y),
+        )
+    }
+
+    /// Returns the area covered by the cells as a `Rect2f`
+    pub const fn as_rect2f(&self) -> Rect2f {
+        Rect2f::new(self.min.as_vec2f(), self.max.as_vec2f())
+    }
+}
@@ -0,0 +1,328 @@
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use super::Rect2i;
+use crate::vec2::Vec2i;
+
+/// An iterator over the cells of a [`Rect2i`] one row at a time, with x increasing fastest
+///
+/// Created by [`Rect2i::points_row_major`].
+#[derive(Clone, Debug)]
+pub struct RowMajorPoints {
+    rect: Rect2i,
+    indices: Range<usize>,
+}
+
+/// An iterator over the cells of a [`Rect2i`] one column at a time, with y increasing fastest
+///
+/// Created by [`Rect2i::points_column_major`].
+#[derive(Clone, Debug)]
+pub struct ColumnMajorPoints {
+    rect: Rect2i,
+    indices: Range<usize>,
+}
+
+/// An iterator over the outermost ring of cells of a [`Rect2i`]
+///
+/// Created by [`Rect2i::border_points`].
+#[derive(Clone, Debug)]
+pub struct BorderPoints {
+    rect: Rect2i,
+    indices: Range<usize>,
+}
+
+/// An iterator over a [`Rect2i`] split into tiles of a fixed size, in row-major order
+///
+/// Created by [`Rect2i::chunks`].
+#[derive(Clone, Debug)]
+pub struct Chunks {
+    rect: Rect2i,
+    size: Vec2i,
+    columns: usize,
+    indices: Range<usize>,
+}
+
+impl Rect2i {
+    /// Returns an iterator over every cell of the rectangle, one row at a time from `min.y`
+    /// upwards with x increasing fastest
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::rect::Rect2i;
+    /// use yavml::vec2::Vec2i;
+    /// let rect = Rect2i::new(Vec2i::new(0, 0), Vec2i::new(2, 2));
+    /// let points: Vec<Vec2i> = rect.points_row_major().collect();
+    /// assert_eq!(points, [Vec2i::new(0, 0), Vec2i::new(1, 0), Vec2i::new(0, 1), Vec2i::new(1, 1)]);
+    /// ```
+    pub fn points_row_major(&self) -> RowMajorPoints {
+        let (width, height) = sides(self);
+        RowMajorPoints {
+            rect: *self,
+            indices: 0..width * height,
+        }
+    }
+
+    /// Returns an iterator over every cell of the rectangle, one column at a time from `min.x`
+    /// rightwards with y increasing fastest
+    pub fn points_column_major(&self) -> ColumnMajorPoints {
+        let (width, height) = sides(self);
+        ColumnMajorPoints {
+            rect: *self,
+            indices: 0..width * height,
+        }
+    }
+
+    /// Returns an iterator over the cells on the edges of the rectangle
+    ///
+    /// The ring starts at `min` and runs along the bottom row, up the right column, back along
+    /// the top row and down the left column, visiting each cell once. A rectangle one cell wide
+    /// or tall yields its cells in increasing order.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::rect::Rect2i;
+    /// use yavml::vec2::Vec2i;
+    /// let rect = Rect2i::new(Vec2i::new(0, 0), Vec2i::new(3, 3));
+    /// let ring: Vec<Vec2i> = rect.border_points().collect();
+    /// assert_eq!(ring.len(), 8);
+    /// assert!(!ring.contains(&Vec2i::new(1, 1)));
+    /// assert_eq!(ring[3], Vec2i::new(2, 1));
+    /// ```
+    pub fn border_points(&self) -> BorderPoints {
+        let (width, height) = sides(self);
+        let len = if width == 0 || height == 0 {
+            0
+        } else if width == 1 || height == 1 {
+            width * height
+        } else {
+            2 * (width + height) - 4
+        };
+        BorderPoints {
+            rect: *self,
+            indices: 0..len,
+        }
+    }
+
+    /// Returns an iterator over tiles of `size` cells that cover the rectangle, starting at `min`
+    ///
+    /// Tiles are visited in row-major order. Tiles in the last row or column are cut off at
+    /// `max`, so every cell belongs to exactly one tile.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either component of `size` is not positive.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::rect::Rect2i;
+    /// use yavml::vec2::Vec2i;
+    /// let rect = Rect2i::new(Vec2i::new(0, 0), Vec2i::new(5, 4));
+    /// let tiles: Vec<Rect2i> = rect.chunks(Vec2i::new(2, 4)).collect();
+    /// assert_eq!(tiles.len(), 3);
+    /// assert_eq!(tiles[2], Rect2i::new(Vec2i::new(4, 0), Vec2i::new(5, 4)));
+    /// ```
+    pub fn chunks(&self, size: Vec2i) -> Chunks {
+        assert!(size.x > 0 && size.y > 0, "chunk size must be positive");
+        let (width, height) = sides(self);
+        let columns = width.div_ceil(size.x as usize);
+        let rows = height.div_ceil(size.y as usize);
+        Chunks {
+            rect: *self,
+            size,
+            columns,
+            indices: 0..columns * rows,
+        }
+    }
+}
+
+impl IntoIterator for Rect2i {
+    type Item = Vec2i;
+    type IntoIter = RowMajorPoints;
+
+    /// Iterates over every cell of the rectangle in row-major order
+    fn into_iter(self) -> Self::IntoIter {
+        self.points_row_major()
+    }
+}
+
+impl RowMajorPoints {
+    fn point(&self, index: usize) -> Vec2i {
+        let (width, _) = sides(&self.rect);
+        let min = self.rect.min;
+        Vec2i::new(offset(min.x, index % width), offset(min.y, index / width))
+    }
+}
+
+impl ColumnMajorPoints {
+    fn point(&self, index: usize) -> Vec2i {
+        let (_, height) = sides(&self.rect);
+        let min = self.rect.min;
+        Vec2i::new(offset(min.x, index / height), offset(min.y, index % height))
+    }
+}
+
+impl BorderPoints {
+    fn point(&self, index: usize) -> Vec2i {
+        let Rect2i { min, max } = self.rect;
+        let (width, height) = sides(&self.rect);
+        let mut index = index;
+        if height == 1 {
+            return Vec2i::new(offset(min.x, index), min.y);
+        }
+        if width == 1 {
+            return Vec2i::new(min.x, offset(min.y, index));
+        }
+        let (run_x, run_y) = (width - 1, height - 1);
+        if index < run_x {
+            return Vec2i::new(offset(min.x, index), min.y);
+        }
+        index -= run_x;
+        if index < run_y {
+            return Vec2i::new(max.x - 1, offset(min.y, index));
+        }
+        index -= run_y;
+        if index < run_x {
+            return Vec2i::new(offset(min.x, run_x - index), max.y - 1);
+        }
+        index -= run_x;
+        Vec2i::new(min.x, offset(min.y, run_y - index))
+    }
+}
+
+impl Chunks {
+    fn tile(&self, index: usize) -> Rect2i {
+        let (column, row) = (index % self.columns, index / self.columns);
+        let (width, height) = (self.size.x as usize, self.size.y as usize);
+        let min = Vec2i::new(
+            offset(self.rect.min.x, column * width),
+            offset(self.rect.min.y, row * height),
+        );
+        let end = |start: i32, size: i32, max: i32| {
+            (i64::from(start) + i64::from(size)).min(i64::from(max)) as i32
+        };
+        Rect2i::new(
+            min,
+            Vec2i::new(
+                end(min.x, self.size.x, self.rect.max.x),
+                end(min.y, self.size.y, self.rect.max.y),
+            ),
+        )
+    }
+}
+
+/// Returns the number of cells along the x and y axes of `rect`, or zero for an empty side
+///
+/// The sides are computed in `i64`, so they cannot overflow for any corners in the `i32` range.
+fn sides(rect: &Rect2i) -> (usize, usize) {
+    let side = |min: i32, max: i32| (i64::from(max) - i64::from(min)).max(0) as usize;
+    (side(rect.min.x, rect.max.x), side(rect.min.y, rect.max.y))
+}
+
+/// Returns the coordinate `by` cells after `start`, which must lie inside the rectangle
+fn offset(start: i32, by: usize) -> i32 {
+    (i64::from(start) + by as i64) as i32
+}
+
+impl Iterator for RowMajorPoints {
+    type Item = Vec2i;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.indices.next().map(|index| self.point(index))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.indices.nth(n).map(|index| self.point(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for RowMajorPoints {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.indices.next_back().map(|index| self.point(index))
+    }
+}
+
+impl ExactSizeIterator for RowMajorPoints {}
+
+impl FusedIterator for RowMajorPoints {}
+
+impl Iterator for ColumnMajorPoints {
+    type Item = Vec2i;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.indices.next().map(|index| self.point(index))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.indices.nth(n).map(|index| self.point(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for ColumnMajorPoints {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.indices.next_back().map(|index| self.point(index))
+    }
+}
+
+impl ExactSizeIterator for ColumnMajorPoints {}
+
+impl FusedIterator for ColumnMajorPoints {}
+
+impl Iterator for BorderPoints {
+    type Item = Vec2i;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.indices.next().map(|index| self.point(index))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.indices.nth(n).map(|index| self.point(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for BorderPoints {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.indices.next_back().map(|index| self.point(index))
+    }
+}
+
+impl ExactSizeIterator for BorderPoints {}
+
+impl FusedIterator for BorderPoints {}
+
+impl Iterator for Chunks {
+    type Item = Rect2i;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.indices.next().map(|index| self.tile(index))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.indices.nth(n).map(|index| self.tile(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Chunks {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.indices.next_back().map(|index| self.tile(index))
+    }
+}
+
+impl ExactSizeIterator for Chunks {}
+
+impl FusedIterator for Chunks {}
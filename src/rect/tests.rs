@@ -0,0 +1,336 @@
+use super::Rect2d;
+use super::Rect2f;
+use super::Rect2i;
+use crate::vec2::Vec2d;
+use crate::vec2::Vec2f;
+use crate::vec2::Vec2i;
+
+mod rect2f_tests {
+    use super::Rect2f;
+    use super::Vec2f;
+
+    fn sample() -> Rect2f {
+        Rect2f::new(Vec2f::new(-1.0, 2.0), Vec2f::new(3.0, 5.0))
+    }
+
+    // Tests the size, center and area, including empty rectangles
+    #[test]
+    fn test_rect2f_measurements() {
+        let rect = sample();
+        assert_eq!(rect.size(), Vec2f::new(4.0, 3.0));
+        assert_eq!(rect.center(), Vec2f::new(1.0, 3.5));
+        assert_eq!(rect.area(), 12.0);
+        assert_eq!(Rect2f::from_center_size(rect.center(), rect.size()), rect);
+        let empty = Rect2f::new(Vec2f::new(1.0, 0.0), Vec2f::new(0.0, 5.0));
+        assert!(empty.is_empty());
+        assert_eq!(empty.area(), 0.0);
+        assert!(!Rect2f::new(Vec2f::ONE, Vec2f::ONE).is_empty());
+    }
+
+    // Tests that edges are inside the rectangle and touching rectangles intersect
+    #[test]
+    fn test_rect2f_containment() {
+        let rect = sample();
+        assert!(rect.contains_point(Vec2f::new(-1.0, 5.0)));
+        assert!(rect.contains_point(Vec2f::new(0.0, 3.0)));
+        assert!(!rect.contains_point(Vec2f::new(3.1, 3.0)));
+        assert!(!rect.contains_point(Vec2f::new(f32::NAN, 3.0)));
+        assert!(rect.contains_rect(&rect.inflate(Vec2f::splat(-0.5))));
+        assert!(!rect.contains_rect(&rect.inflate(Vec2f::new(0.0, 0.5))));
+
+        let touching = Rect2f::new(Vec2f::new(3.0, 0.0), Vec2f::new(4.0, 2.0));
+        assert!(rect.intersects(&touching));
+        assert_eq!(
+            rect.intersection(&touching),
+            Some(Rect2f::new(Vec2f::new(3.0, 2.0), Vec2f::new(3.0, 2.0)))
+        );
+        let apart = Rect2f::new(Vec2f::new(3.5, 0.0), Vec2f::new(4.0, 2.0));
+        assert!(!rect.intersects(&apart));
+        assert_eq!(rect.intersection(&apart), None);
+    }
+
+    // Tests union, expansion, inflation, clamping and construction from points
+    #[test]
+    fn test_rect2f_set_operations() {
+        let rect = sample();
+        let other = Rect2f::new(Vec2f::new(0.0, -4.0), Vec2f::new(1.0, 0.0));
+        let union = rect.union(&other);
+        assert_eq!(
+            union,
+            Rect2f::new(Vec2f::new(-1.0, -4.0), Vec2f::new(3.0, 5.0))
+        );
+        assert!(union.contains_rect(&rect) && union.contains_rect(&other));
+        assert_eq!(
+            rect.expand(Vec2f::new(10.0, 0.0)).max,
+            Vec2f::new(10.0, 5.0)
+        );
+        assert!(rect.inflate(Vec2f::new(-3.0, 0.0)).is_empty());
+        assert_eq!(
+            rect.clamp_point(Vec2f::new(-5.0, 4.0)),
+            Vec2f::new(-1.0, 4.0)
+        );
+        assert_eq!(rect.clamp_point(Vec2f::new(0.5, 0.5)), Vec2f::new(0.5, 2.0));
+
+        let points = [
+            Vec2f::new(3.0, 2.0),
+            Vec2f::new(-1.0, 5.0),
+            Vec2f::new(0.0, 3.0),
+        ];
+        assert_eq!(Rect2f::from_points(points), Some(rect));
+        assert_eq!(Rect2f::from_points(points.iter().copied().take(0)), None);
+    }
+
+    // Tests that empty rectangles are ignored by union and contained in every rectangle
+    #[test]
+    fn test_rect2f_empty_operands() {
+        let rect = sample();
+        let empty = Rect2f::new(Vec2f::new(50.0, 50.0), Vec2f::new(40.0, 60.0));
+        assert_eq!(rect.union(&empty), rect);
+        assert_eq!(empty.union(&rect), rect);
+        assert_eq!(empty.union(&empty), empty);
+        assert!(rect.contains_rect(&empty));
+        assert!(empty.contains_rect(&empty));
+        assert!(!empty.contains_rect(&rect));
+        let inside = Rect2f::new(Vec2f::new(2.0, 4.0), Vec2f::new(1.0, 3.0));
+        assert!(!rect.intersects(&inside));
+        assert!(!inside.intersects(&rect));
+        assert_eq!(rect.intersection(&inside), None);
+    }
+}
+
+mod rect2d_tests {
+    use super::Rect2d;
+    use super::Vec2d;
+
+    // Tests the basic operations in double precision
+    #[test]
+    fn test_rect2d_operations() {
+        let rect = Rect2d::from_center_size(Vec2d::new(0.5, 0.5), Vec2d::new(1.0, 3.0));
+        assert_eq!(rect.min, Vec2d::new(0.0, -1.0));
+        assert_eq!(rect.area(), 3.0);
+        let other = Rect2d::new(Vec2d::new(0.5, 0.0), Vec2d::new(2.0, 4.0));
+        assert_eq!(
+            rect.intersection(&other),
+            Some(Rect2d::new(Vec2d::new(0.5, 0.0), Vec2d::new(1.0, 2.0)))
+        );
+        assert_eq!(rect.union(&other).size(), Vec2d::new(2.0, 5.0));
+        let empty = Rect2d::new(Vec2d::splat(9.0), Vec2d::splat(-9.0));
+        assert_eq!(rect.union(&empty), rect);
+        assert!(rect.contains_rect(&empty));
+        let inside = Rect2d::new(Vec2d::splat(0.75), Vec2d::splat(0.25));
+        assert!(!rect.intersects(&inside));
+        assert_eq!(rect.intersection(&inside), None);
+    }
+}
+
+mod rect2i_tests {
+    use super::Rect2i;
+    use super::Vec2i;
+
+    fn sample() -> Rect2i {
+        Rect2i::new(Vec2i::new(-1, 2), Vec2i::new(3, 5))
+    }
+
+    // Tests that the maximum corner is excluded from the covered cells
+    #[test]
+    fn test_rect2i_half_open() {
+        let rect = sample();
+        assert_eq!(rect.size(), Vec2i::new(4, 3));
+        assert_eq!(rect.area(), 12);
+        assert!(rect.contains_point(Vec2i::new(-1, 2)));
+        assert!(rect.contains_point(Vec2i::new(2, 4)));
+        assert!(!rect.contains_point(Vec2i::new(3, 4)));
+        assert!(!rect.contains_point(Vec2i::new(2, 5)));
+        let mut count = 0;
+        for y in -5..10 {
+            for x in -5..10 {
+                count += rect.contains_point(Vec2i::new(x, y)) as u64;
+            }
+        }
+        assert_eq!(count, rect.area());
+        assert!(Rect2i::new(Vec2i::ONE, Vec2i::ONE).is_empty());
+        assert_eq!(Rect2i::new(Vec2i::ONE, Vec2i::ZERO).area(), 0);
+        let huge = Rect2i::new(Vec2i::splat(i32::MIN), Vec2i::splat(i32::MAX));
+        assert_eq!(huge.area(), u64::from(u32::MAX).pow(2));
+    }
+
+    // Tests that rectangles sharing only an edge do not intersect
+    #[test]
+    fn test_rect2i_intersection() {
+        let rect = sample();
+        let touching = Rect2i::new(Vec2i::new(3, 0), Vec2i::new(6, 10));
+        assert!(!rect.intersects(&touching));
+        assert_eq!(rect.intersection(&touching), None);
+        let overlapping = Rect2i::new(Vec2i::new(2, 0), Vec2i::new(6, 3));
+        assert!(rect.intersects(&overlapping));
+        assert_eq!(
+            rect.intersection(&overlapping),
+            Some(Rect2i::new(Vec2i::new(2, 2), Vec2i::new(3, 3)))
+        );
+    }
+
+    // Tests union, expansion, inflation, clamping, centering and construction from points
+    #[test]
+    fn test_rect2i_set_operations() {
+        let rect = sample();
+        let empty = Rect2i::new(Vec2i::new(50, 50), Vec2i::new(40, 60));
+        assert_eq!(rect.union(&empty), rect);
+        assert_eq!(empty.union(&rect), rect);
+        let other = Rect2i::new(Vec2i::new(0, -4), Vec2i::new(1, 0));
+        assert_eq!(
+            rect.union(&other),
+            Rect2i::new(Vec2i::new(-1, -4), Vec2i::new(3, 5))
+        );
+        assert!(rect.contains_rect(&rect.inflate(Vec2i::new(-1, -1))));
+        assert!(rect.contains_rect(&empty));
+
+        let expanded = rect.expand(Vec2i::new(7, 0));
+        assert_eq!(expanded, Rect2i::new(Vec2i::new(-1, 0), Vec2i::new(8, 5)));
+        assert!(expanded.contains_point(Vec2i::new(7, 0)));
+        assert_eq!(rect.clamp_point(Vec2i::new(10, -10)), Vec2i::new(2, 2));
+        assert!(rect.contains_point(rect.clamp_point(Vec2i::new(10, 10))));
+
+        assert_eq!(rect.center(), Vec2i::new(1, 3));
+        assert_eq!(
+            Rect2i::from_center_size(Vec2i::ZERO, Vec2i::new(4, 3)).min,
+            Vec2i::new(-2, -1)
+        );
+        let points = [Vec2i::new(2, 2), Vec2i::new(-1, 4), Vec2i::new(0, 3)];
+        assert_eq!(Rect2i::from_points(points), Some(rect));
+        assert_eq!(Rect2i::from_points(core::iter::empty()), None);
+        assert_eq!(rect.as_rect2f().area(), rect.area() as f32);
+    }
+
+    // Tests that row-major and column-major iteration visit every cell once in order
+    #[test]
+    fn test_rect2i_points() {
+        let rect = sample();
+        let rows: Vec<Vec2i> = rect.points_row_major().collect();
+        assert_eq!(rows.len(), rect.area() as usize);
+        assert_eq!(rows[0], Vec2i::new(-1, 2));
+        assert_eq!(rows[1], Vec2i::new(0, 2));
+        assert_eq!(rows[4], Vec2i::new(-1, 3));
+        assert!(rows.iter().all(|point| rect.contains_point(*point)));
+        assert!(rows
+            .windows(2)
+            .all(|pair| (pair[0].y, pair[0].x) < (pair[1].y, pair[1].x)));
+
+        let columns: Vec<Vec2i> = rect.points_column_major().collect();
+        assert_eq!(columns[1], Vec2i::new(-1, 3));
+        assert_eq!(columns[3], Vec2i::new(0, 2));
+        assert!(columns
+            .windows(2)
+            .all(|pair| (pair[0].x, pair[0].y) < (pair[1].x, pair[1].y)));
+
+        let reversed: Vec<Vec2i> = rect.points_row_major().rev().collect();
+        assert!(reversed.iter().eq(rows.iter().rev()));
+        let mut iter = rect.points_column_major();
+        assert_eq!(iter.len(), 12);
+        assert_eq!(iter.next_back(), Some(Vec2i::new(2, 4)));
+        assert_eq!(iter.nth(2), Some(columns[2]));
+        assert_eq!(iter.len(), 8);
+        assert_eq!(rect.into_iter().count(), 12);
+        let empty = Rect2i::new(Vec2i::ONE, Vec2i::new(1, 5));
+        assert_eq!(empty.points_row_major().len(), 0);
+        assert_eq!(empty.points_column_major().next(), None);
+    }
+
+    // Tests that the border ring visits each edge cell once and is contiguous
+    #[test]
+    fn test_rect2i_border_points() {
+        let rect = sample();
+        let ring: Vec<Vec2i> = rect.border_points().collect();
+        assert_eq!(ring.len(), 10);
+        assert_eq!(ring[0], rect.min);
+        for (i, point) in ring.iter().enumerate() {
+            assert!(rect.contains_point(*point));
+            assert!(!rect.inflate(Vec2i::NEG_ONE).contains_point(*point));
+            assert!(!ring[i + 1..].contains(point));
+            let next = ring[(i + 1) % ring.len()];
+            assert_eq!((next.x - point.x).abs() + (next.y - point.y).abs(), 1);
+        }
+        let reversed: Vec<Vec2i> = rect.border_points().rev().collect();
+        assert!(reversed.iter().eq(ring.iter().rev()));
+
+        let row = Rect2i::new(Vec2i::ZERO, Vec2i::new(3, 1));
+        let row_ring: Vec<Vec2i> = row.border_points().collect();
+        assert_eq!(
+            row_ring,
+            [Vec2i::new(0, 0), Vec2i::new(1, 0), Vec2i::new(2, 0)]
+        );
+        let column = Rect2i::new(Vec2i::ZERO, Vec2i::new(1, 2));
+        assert_eq!(column.border_points().len(), 2);
+        assert_eq!(
+            Rect2i::new(Vec2i::ZERO, Vec2i::ONE).border_points().len(),
+            1
+        );
+        assert_eq!(Rect2i::default().border_points().len(), 0);
+    }
+
+    // Tests that chunks tile the rectangle exactly, clipping the last row and column
+    #[test]
+    fn test_rect2i_chunks() {
+        let rect = Rect2i::new(Vec2i::new(1, -2), Vec2i::new(8, 3));
+        let chunks: Vec<Rect2i> = rect.chunks(Vec2i::new(3, 2)).collect();
+        assert_eq!(chunks.len(), 9);
+        assert_eq!(chunks[0], Rect2i::new(Vec2i::new(1, -2), Vec2i::new(4, 0)));
+        assert_eq!(chunks[2], Rect2i::new(Vec2i::new(7, -2), Vec2i::new(8, 0)));
+        assert_eq!(chunks[8], Rect2i::new(Vec2i::new(7, 2), Vec2i::new(8, 3)));
+        assert_eq!(chunks.iter().map(Rect2i::area).sum::<u64>(), rect.area());
+        for point in rect {
+            assert_eq!(
+                chunks
+                    .iter()
+                    .filter(|chunk| chunk.contains_point(point))
+                    .count(),
+                1
+            );
+        }
+        let mut iter = rect.chunks(Vec2i::new(3, 2));
+        assert_eq!(iter.next_back(), Some(chunks[8]));
+        assert_eq!(iter.len(), 8);
+        assert_eq!(Rect2i::default().chunks(Vec2i::ONE).len(), 0);
+    }
+
+    // Tests that rectangles with more cells than fit in an i32 iterate without overflowing
+    #[test]
+    fn test_rect2i_large_iterators() {
+        let rect = Rect2i::new(Vec2i::ZERO, Vec2i::splat(50_000));
+        assert_eq!(rect.points_row_major().len(), 2_500_000_000);
+        assert_eq!(
+            rect.points_row_major().next_back(),
+            Some(Vec2i::splat(49_999))
+        );
+        assert_eq!(
+            rect.points_column_major().nth(50_001),
+            Some(Vec2i::new(1, 1))
+        );
+        assert_eq!(rect.border_points().len(), 199_996);
+        assert_eq!(rect.chunks(Vec2i::splat(25_000)).len(), 4);
+
+        let huge = Rect2i::new(Vec2i::splat(i32::MIN), Vec2i::splat(i32::MAX));
+        let side = u32::MAX as usize;
+        assert_eq!(huge.points_row_major().len(), side * side);
+        assert_eq!(
+            huge.points_row_major().next_back(),
+            Some(Vec2i::splat(i32::MAX - 1))
+        );
+        let mut ring = huge.border_points();
+        assert_eq!(ring.len(), 4 * side - 4);
+        assert_eq!(
+            ring.nth(2 * side - 2),
+            Some(Vec2i::new(i32::MAX - 1, i32::MAX - 1))
+        );
+        assert_eq!(ring.next_back(), Some(Vec2i::new(i32::MIN, i32::MIN + 1)));
+        let tiles: Vec<Rect2i> = huge.chunks(Vec2i::splat(i32::MAX)).collect();
+        assert_eq!(tiles.len(), 9);
+        assert_eq!(tiles[8].max, huge.max);
+    }
+
+    // Tests that a chunk size without cells panics
+    #[test]
+    #[should_panic]
+    fn test_rect2i_chunks_zero_size() {
+        sample().chunks(Vec2i::new(0, 1));
+    }
+}
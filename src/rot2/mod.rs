@@ -0,0 +1,8 @@
+pub mod rot2f;
+pub use rot2f::Rot2f;
+
+pub mod rot2d;
+pub use rot2d::Rot2d;
+
+#[cfg(test)]
+mod tests;
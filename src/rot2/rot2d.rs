@@ -0,0 +1,191 @@
+use core::ops::*;
+
+use crate::angle::Radians;
+use crate::mat2::Mat2d;
+use crate::vec2::Vec2d;
+
+/// A 2D rotation of `f64` values stored as the cosine and sine of its angle
+///
+/// This is a unit complex number, so applying it is a few multiplications with no trigonometry.
+/// Repeated composition slowly drifts away from unit length, which
+/// [`Rot2d::normalize`] and [`Rot2d::renormalize_fast`] correct.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rot2d {
+    /// The cosine of the rotation angle
+    pub cos: f64,
+    /// The sine of the rotation angle
+    pub sin: f64,
+}
+
+impl Rot2d {
+    /// The identity rotation
+    pub const IDENTITY: Self = Self::from_cos_sin(1.0, 0.0);
+
+    /// Creates a new rotation from the cosine and sine of its angle
+    ///
+    /// The values are not checked, so they should satisfy `cos * cos + sin * sin == 1`.
+    pub const fn from_cos_sin(cos: f64, sin: f64) -> Self {
+        Self { cos, sin }
+    }
+
+    /// Creates a rotation that turns counter-clockwise by `angle` radians
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::rot2::Rot2d;
+    /// use yavml::vec2::Vec2d;
+    /// let rotation = Rot2d::from_angle(core::f64::consts::FRAC_PI_2);
+    /// assert!((rotation * Vec2d::new(1.0, 0.0) - Vec2d::new(0.0, 1.0)).length() < 1e-12);
+    /// ```
+    pub fn from_angle(angle: impl Into<Radians<f64>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        Self::from_cos_sin(cos, sin)
+    }
+
+    /// Creates the rotation that turns the direction of `from` into the direction of `to`
+    ///
+    /// Neither vector needs to be normalized. If either has a length of zero the result is the
+    /// identity.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::rot2::Rot2d;
+    /// use yavml::vec2::Vec2d;
+    /// let rotation = Rot2d::from_to(Vec2d::new(2.0, 0.0), Vec2d::new(0.0, 3.0));
+    /// assert!((rotation.angle() - core::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    /// ```
+    pub fn from_to(from: Vec2d, to: Vec2d) -> Self {
+        let rotation = Self::from_cos_sin(from.dot(to), from.cross(to));
+        if rotation.cos == 0.0 && rotation.sin == 0.0 {
+            return Self::IDENTITY;
+        }
+        rotation.normalize()
+    }
+
+    /// Returns the counter-clockwise angle of the rotation in radians, in `[-PI, PI]`
+    pub fn angle(&self) -> f64 {
+        self.sin.atan2(self.cos)
+    }
+
+    /// Returns the rotation as a matrix
+    pub const fn to_mat2(&self) -> Mat2d {
+        Mat2d::from_cols(
+            Vec2d::new(self.cos, self.sin),
+            Vec2d::new(-self.sin, self.cos),
+        )
+    }
+
+    /// Returns the inverse of the rotation, which turns by the same angle clockwise
+    pub const fn inverse(&self) -> Self {
+        Self::from_cos_sin(self.cos, -self.sin)
+    }
+
+    /// Rotates the vector `vector` counter-clockwise by the rotation
+    pub fn rotate(&self, vector: Vec2d) -> Vec2d {
+        Vec2d::new(
+            self.cos * vector.x - self.sin * vector.y,
+            self.sin * vector.x + self.cos * vector.y,
+        )
+    }
+
+    /// Returns the composition `self * rhs`, which applies `rhs` first
+    ///
+    /// The angles of the two rotations add up.
+    pub fn mul_rot2(&self, rhs: &Self) -> Self {
+        Self::from_cos_sin(
+            self.cos * rhs.cos - self.sin * rhs.sin,
+            self.sin * rhs.cos + self.cos * rhs.sin,
+        )
+    }
+
+    /// Interpolates between `self` and `end` at a constant angular speed along the shorter arc
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::rot2::Rot2d;
+    /// let start = Rot2d::from_angle(3.0);
+    /// let end = Rot2d::from_angle(-3.0);
+    /// let half = start.slerp(end, 0.5);
+    /// assert!((half.angle().abs() - core::f64::consts::PI).abs() < 1e-5);
+    /// ```
+    pub fn slerp(&self, end: Self, t: f64) -> Self {
+        let delta = (self.inverse() * end).angle();
+        *self * Self::from_angle(delta * t)
+    }
+
+    /// Returns the squared length of the stored cosine and sine, which is `1` for a rotation
+    pub fn length_squared(&self) -> f64 {
+        self.cos * self.cos + self.sin * self.sin
+    }
+
+    /// Returns `true` if the stored cosine and sine have a length within `1e-4` of `1`
+    pub fn is_normalized(&self) -> bool {
+        (self.length_squared() - 1.0).abs() <= 2e-4
+    }
+
+    /// Returns the rotation with its cosine and sine scaled back to a length of `1`
+    ///
+    /// The result is non-finite if both values are zero.
+    pub fn normalize(&self) -> Self {
+        let inv_len = 1.0 / self.cos.hypot(self.sin);
+        Self::from_cos_sin(self.cos * inv_len, self.sin * inv_len)
+    }
+
+    /// Returns the rotation moved towards unit length without a square root
+    ///
+    /// This is one Newton step of [`Rot2d::normalize`]. It squares the remaining error, so calling
+    /// it every few compositions keeps accumulated drift at rounding level.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::rot2::Rot2d;
+    /// let drifted = Rot2d::from_cos_sin(0.6 * 1.01, 0.8 * 1.01);
+    /// assert!((drifted.renormalize_fast().length_squared() - 1.0).abs() < 1e-3);
+    /// ```
+    pub fn renormalize_fast(&self) -> Self {
+        let scale = (3.0 - self.length_squared()) * 0.5;
+        Self::from_cos_sin(self.cos * scale, self.sin * scale)
+    }
+
+    /// Returns `true` if the cosine and sine of `self` and `rhs` differ by at most `max_abs_diff`
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f64) -> bool {
+        (self.cos - rhs.cos).abs() <= max_abs_diff && (self.sin - rhs.sin).abs() <= max_abs_diff
+    }
+}
+
+impl Default for Rot2d {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Rot2d> for Mat2d {
+    fn from(rotation: Rot2d) -> Self {
+        rotation.to_mat2()
+    }
+}
+
+/// Composition of rotations
+impl Mul<Rot2d> for Rot2d {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_rot2(&rhs)
+    }
+}
+
+/// Composition assignment of rotations
+impl MulAssign<Rot2d> for Rot2d {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_rot2(&rhs);
+    }
+}
+
+/// Rotation of a vector
+impl Mul<Vec2d> for Rot2d {
+    type Output = Vec2d;
+
+    fn mul(self, rhs: Vec2d) -> Self::Output {
+        self.rotate(rhs)
+    }
+}
@@ -0,0 +1,133 @@
+use super::Rot2d;
+use super::Rot2f;
+use crate::mat2::Mat2d;
+use crate::mat2::Mat2f;
+use crate::vec2::Vec2d;
+use crate::vec2::Vec2f;
+
+mod rot2f_tests {
+    use super::Mat2f;
+    use super::Rot2f;
+    use super::Vec2f;
+    use core::f32::consts::{FRAC_PI_2, PI};
+
+    // Tests that the identity leaves vectors unchanged
+    #[test]
+    fn test_rot2f_identity() {
+        let vec = Vec2f::new(3.0, -4.0);
+        assert_eq!(Rot2f::IDENTITY * vec, vec);
+        assert_eq!(Rot2f::default(), Rot2f::IDENTITY);
+        assert_eq!(Rot2f::IDENTITY.angle(), 0.0);
+    }
+
+    // Tests that rotating a vector matches the equivalent matrix
+    #[test]
+    fn test_rot2f_rotate() {
+        let rotation = Rot2f::from_angle(0.7);
+        let vec = Vec2f::new(2.0, -1.0);
+        assert!((rotation * vec - Mat2f::from_angle(0.7) * vec).length() < 1e-6);
+        assert!(Mat2f::from(rotation).abs_diff_eq(Mat2f::from_angle(0.7), 1e-6));
+        assert!((rotation.angle() - 0.7).abs() < 1e-6);
+        assert!((Rot2f::from_angle(-3.0).angle() + 3.0).abs() < 1e-6);
+    }
+
+    // Tests rotations between vectors of any length, including zero
+    #[test]
+    fn test_rot2f_from_to() {
+        let from = Vec2f::new(3.0, 4.0);
+        let to = Vec2f::new(-2.0, 0.5);
+        let rotation = Rot2f::from_to(from, to);
+        let turned = rotation * from;
+        assert!(turned.cross(to).abs() < 1e-5);
+        assert!(turned.dot(to) > 0.0);
+        assert!(rotation.is_normalized());
+        let half_turn = Rot2f::from_to(Vec2f::new(1.0, 0.0), Vec2f::new(-1.0, 0.0));
+        assert!((half_turn.angle().abs() - PI).abs() < 1e-6);
+        assert_eq!(Rot2f::from_to(Vec2f::ZERO, to), Rot2f::IDENTITY);
+    }
+
+    // Tests that composition adds angles and the inverse undoes a rotation
+    #[test]
+    fn test_rot2f_compose_inverse() {
+        let a = Rot2f::from_angle(0.4);
+        let b = Rot2f::from_angle(FRAC_PI_2);
+        assert!((a * b).abs_diff_eq(Rot2f::from_angle(0.4 + FRAC_PI_2), 1e-6));
+        let mut c = a;
+        c *= b;
+        assert_eq!(c, a * b);
+        assert!((a * a.inverse()).abs_diff_eq(Rot2f::IDENTITY, 1e-6));
+        let vec = Vec2f::new(1.0, 2.0);
+        assert!((a.inverse() * (a * vec) - vec).length() < 1e-6);
+    }
+
+    // Tests interpolation along the shorter arc, including across the +-PI seam
+    #[test]
+    fn test_rot2f_slerp() {
+        let start = Rot2f::from_angle(0.2);
+        let end = Rot2f::from_angle(1.0);
+        assert!(start.slerp(end, 0.0).abs_diff_eq(start, 1e-6));
+        assert!(start.slerp(end, 1.0).abs_diff_eq(end, 1e-6));
+        assert!(start
+            .slerp(end, 0.25)
+            .abs_diff_eq(Rot2f::from_angle(0.4), 1e-6));
+
+        let start = Rot2f::from_angle(PI - 0.1);
+        let end = Rot2f::from_angle(-PI + 0.1);
+        assert!(start
+            .slerp(end, 0.5)
+            .abs_diff_eq(Rot2f::from_angle(PI), 1e-6));
+    }
+
+    // Tests that renormalization removes drift accumulated over many compositions
+    #[test]
+    fn test_rot2f_renormalize() {
+        let step = Rot2f::from_cos_sin(0.6 * 1.0001, 0.8 * 1.0001);
+        let mut drifted = Rot2f::IDENTITY;
+        for _ in 0..1000 {
+            drifted *= step;
+        }
+        assert!(!drifted.is_normalized());
+        assert!((drifted.normalize().length_squared() - 1.0).abs() < 1e-6);
+
+        let mut fast = Rot2f::IDENTITY;
+        for i in 0..1000 {
+            fast *= step;
+            if i % 8 == 7 {
+                fast = fast.renormalize_fast();
+            }
+        }
+        assert!(fast.is_normalized());
+        assert!(fast.abs_diff_eq(drifted.normalize(), 1e-3));
+    }
+}
+
+mod rot2d_tests {
+    use super::Mat2d;
+    use super::Rot2d;
+    use super::Vec2d;
+    use core::f64::consts::PI;
+
+    // Tests rotating vectors and converting to a matrix in double precision
+    #[test]
+    fn test_rot2d_rotate() {
+        let rotation = Rot2d::from_angle(-2.2);
+        let vec = Vec2d::new(2.0, -1.0);
+        assert!((rotation * vec - Mat2d::from_angle(-2.2) * vec).length() < 1e-12);
+        assert!((rotation.angle() + 2.2).abs() < 1e-12);
+        let rotation = Rot2d::from_to(Vec2d::new(1.0, 1.0), Vec2d::new(-1.0, 1.0));
+        assert!((rotation.angle() - PI * 0.5).abs() < 1e-12);
+    }
+
+    // Tests composition and interpolation in double precision
+    #[test]
+    fn test_rot2d_slerp() {
+        let start = Rot2d::from_angle(-1.0);
+        let end = Rot2d::from_angle(2.0);
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            let expected = Rot2d::from_angle(-1.0 + 3.0 * t);
+            assert!(start.slerp(end, t).abs_diff_eq(expected, 1e-12));
+        }
+        assert!((start * end).abs_diff_eq(Rot2d::from_angle(1.0), 1e-12));
+    }
+}
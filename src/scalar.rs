@@ -0,0 +1,147 @@
+//! Traits describing the number types that generic containers such as
+//! [`Matrix`](crate::matrix::Matrix) can hold
+
+use core::fmt::Debug;
+use core::ops::*;
+
+/// A number type supporting the basic arithmetic operators
+pub trait Scalar:
+    Copy
+    + Debug
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    /// The additive identity
+    const ZERO: Self;
+    /// The multiplicative identity
+    const ONE: Self;
+}
+
+/// A floating-point [`Scalar`]
+pub trait Float: Scalar + Neg<Output = Self> {
+    /// The difference between `1.0` and the next larger representable number
+    const EPSILON: Self;
+    /// Archimedes' constant
+    const PI: Self;
+
+    /// Returns the absolute value of `self`
+    fn abs(self) -> Self;
+
+    /// Returns the square root of `self`
+    fn sqrt(self) -> Self;
+
+    /// Returns `true` if `self` is neither infinite nor NaN
+    fn is_finite(self) -> bool;
+
+    /// Converts `n` to the nearest representable value
+    fn from_usize(n: usize) -> Self;
+
+    /// Returns the least non-negative remainder of `self` divided by `rhs`
+    fn rem_euclid(self, rhs: Self) -> Self;
+
+    /// Returns the sine and cosine of `self` in radians
+    fn sin_cos(self) -> (Self, Self);
+
+    /// Converts `self` from radians to degrees
+    fn to_degrees(self) -> Self;
+
+    /// Converts `self` from degrees to radians
+    fn to_radians(self) -> Self;
+}
+
+impl Scalar for i32 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+}
+
+impl Scalar for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+}
+
+impl Scalar for f64 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+}
+
+impl Float for f32 {
+    const EPSILON: Self = f32::EPSILON;
+    const PI: Self = core::f32::consts::PI;
+
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+
+    fn sqrt(self) -> Self {
+        f32::sqrt(self)
+    }
+
+    fn is_finite(self) -> bool {
+        f32::is_finite(self)
+    }
+
+    fn from_usize(n: usize) -> Self {
+        n as f32
+    }
+
+    fn rem_euclid(self, rhs: Self) -> Self {
+        f32::rem_euclid(self, rhs)
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        f32::sin_cos(self)
+    }
+
+    fn to_degrees(self) -> Self {
+        f32::to_degrees(self)
+    }
+
+    fn to_radians(self) -> Self {
+        f32::to_radians(self)
+    }
+}
+
+impl Float for f64 {
+    const EPSILON: Self = f64::EPSILON;
+    const PI: Self = core::f64::consts::PI;
+
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
+
+    fn from_usize(n: usize) -> Self {
+        n as f64
+    }
+
+    fn rem_euclid(self, rhs: Self) -> Self {
+        f64::rem_euclid(self, rhs)
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        f64::sin_cos(self)
+    }
+
+    fn to_degrees(self) -> Self {
+        f64::to_degrees(self)
+    }
+
+    fn to_radians(self) -> Self {
+        f64::to_radians(self)
+    }
+}
@@ -0,0 +1,96 @@
+use super::segment2f::is_parallel;
+use super::Segment2f;
+use crate::vec2::Vec2f;
+
+/// An infinite line of `f32` values through `point` running along `dir`
+///
+/// `dir` does not need to be unit length, and line parameters are measured in multiples of it.
+/// A line with a zero `dir` is the single point `point`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Line2f {
+    /// A point on the line
+    pub point: Vec2f,
+    /// The direction of the line
+    pub dir: Vec2f,
+}
+
+impl Line2f {
+    /// Creates a new line
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - A `Vec2f` holding a point on the line
+    ///
+    /// * `dir` - A `Vec2f` holding the direction of the line
+    pub const fn new(point: Vec2f, dir: Vec2f) -> Self {
+        Self { point, dir }
+    }
+
+    /// Creates the line through `a` and `b`, with `a` at parameter `0` and `b` at parameter `1`
+    pub fn from_points(a: Vec2f, b: Vec2f) -> Self {
+        Self::new(a, b - a)
+    }
+
+    /// Returns the point `point + dir * t`
+    pub fn at(&self, t: f32) -> Vec2f {
+        self.point + self.dir * t
+    }
+
+    /// Returns the parameter of the point of the line closest to `point`
+    ///
+    /// A line with a zero `dir` returns `0`.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::segment::Line2f;
+    /// use yavml::vec2::Vec2f;
+    /// let line = Line2f::from_points(Vec2f::ZERO, Vec2f::new(2.0, 0.0));
+    /// assert_eq!(line.project(Vec2f::new(-3.0, 5.0)), -1.5);
+    /// ```
+    pub fn project(&self, point: Vec2f) -> f32 {
+        let length_squared = self.dir.dot(self.dir);
+        if length_squared == 0.0 {
+            return 0.0;
+        }
+        (point - self.point).dot(self.dir) / length_squared
+    }
+
+    /// Returns the point of the line closest to `point`
+    pub fn closest_point(&self, point: Vec2f) -> Vec2f {
+        self.at(self.project(point))
+    }
+
+    /// Returns the distance from `point` to the line
+    pub fn distance_to_point(&self, point: Vec2f) -> f32 {
+        let offset = point - self.closest_point(point);
+        offset.dot(offset).sqrt()
+    }
+
+    /// Returns the point where `self` and `line` cross, or `None` if they are parallel
+    ///
+    /// Lines that are parallel within a tolerance relative to their direction lengths, including
+    /// identical lines and lines with a zero `dir`, return `None`.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::segment::Line2f;
+    /// use yavml::vec2::Vec2f;
+    /// let a = Line2f::new(Vec2f::new(0.0, 1.0), Vec2f::new(1.0, 0.0));
+    /// let b = Line2f::new(Vec2f::new(3.0, 0.0), Vec2f::new(0.0, 2.0));
+    /// assert_eq!(a.intersect(&b), Some(Vec2f::new(3.0, 1.0)));
+    /// ```
+    pub fn intersect(&self, line: &Self) -> Option<Vec2f> {
+        if is_parallel(self.dir, line.dir) {
+            return None;
+        }
+        let t = (line.point - self.point).cross(line.dir) / self.dir.cross(line.dir);
+        Some(self.at(t))
+    }
+}
+
+impl From<Segment2f> for Line2f {
+    /// The line through both ends of the segment
+    fn from(segment: Segment2f) -> Self {
+        Self::from_points(segment.start, segment.end)
+    }
+}
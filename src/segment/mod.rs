@@ -0,0 +1,11 @@
+pub mod segment2f;
+pub use segment2f::{Segment2f, SegmentIntersection2f};
+
+pub mod segment3f;
+pub use segment3f::Segment3f;
+
+pub mod line2f;
+pub use line2f::Line2f;
+
+#[cfg(test)]
+mod tests;
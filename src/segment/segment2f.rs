@@ -0,0 +1,159 @@
+use crate::vec2::Vec2f;
+
+/// A line segment of `f32` values between `start` and `end`
+///
+/// A segment with `start == end` is a single point, and every query treats it that way.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Segment2f {
+    /// The first end of the segment
+    pub start: Vec2f,
+    /// The second end of the segment
+    pub end: Vec2f,
+}
+
+/// Where two [`Segment2f`] meet
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SegmentIntersection2f {
+    /// The segments cross or touch at a single point
+    Point(Vec2f),
+    /// The segments are collinear and share this stretch, which runs in the direction of the
+    /// first segment
+    Overlap(Segment2f),
+}
+
+impl Segment2f {
+    /// Creates a new segment
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - A `Vec2f` holding the first end of the segment
+    ///
+    /// * `end` - A `Vec2f` holding the second end of the segment
+    pub const fn new(start: Vec2f, end: Vec2f) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the length of the segment
+    pub fn length(&self) -> f32 {
+        let dir = self.end - self.start;
+        dir.dot(dir).sqrt()
+    }
+
+    /// Returns the point `start + (end - start) * t`, which is `start` at `t = 0` and `end` at
+    /// `t = 1`
+    pub fn at(&self, t: f32) -> Vec2f {
+        self.start + (self.end - self.start) * t
+    }
+
+    /// Returns the parameter in `[0, 1]` of the point of the segment closest to `point`
+    ///
+    /// A zero-length segment returns `0`.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::segment::Segment2f;
+    /// use yavml::vec2::Vec2f;
+    /// let segment = Segment2f::new(Vec2f::ZERO, Vec2f::new(4.0, 0.0));
+    /// assert_eq!(segment.project(Vec2f::new(1.0, 3.0)), 0.25);
+    /// assert_eq!(segment.project(Vec2f::new(9.0, 3.0)), 1.0);
+    /// ```
+    pub fn project(&self, point: Vec2f) -> f32 {
+        let dir = self.end - self.start;
+        let length_squared = dir.dot(dir);
+        if length_squared == 0.0 {
+            return 0.0;
+        }
+        ((point - self.start).dot(dir) / length_squared).clamp(0.0, 1.0)
+    }
+
+    /// Returns the point of the segment closest to `point`
+    pub fn closest_point(&self, point: Vec2f) -> Vec2f {
+        self.at(self.project(point))
+    }
+
+    /// Returns the distance from `point` to the closest point of the segment
+    pub fn distance_to_point(&self, point: Vec2f) -> f32 {
+        let offset = point - self.closest_point(point);
+        offset.dot(offset).sqrt()
+    }
+
+    /// Returns where `self` and `segment` meet, or `None` if they do not
+    ///
+    /// Crossing segments meet at a point. Collinear segments meet along the stretch they share,
+    /// whose ends are taken from the input ends so they are exact, or at a point when they only
+    /// touch end to end. Zero-length segments meet other segments at most at a point. Parallel
+    /// and collinear cases are detected with a tolerance relative to the segment lengths.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::segment::{Segment2f, SegmentIntersection2f};
+    /// use yavml::vec2::Vec2f;
+    /// let a = Segment2f::new(Vec2f::ZERO, Vec2f::new(4.0, 4.0));
+    /// let b = Segment2f::new(Vec2f::new(0.0, 4.0), Vec2f::new(4.0, 0.0));
+    /// assert_eq!(a.intersect(&b), Some(SegmentIntersection2f::Point(Vec2f::new(2.0, 2.0))));
+    /// let c = Segment2f::new(Vec2f::new(6.0, 6.0), Vec2f::new(3.0, 3.0));
+    /// let shared = Segment2f::new(Vec2f::new(3.0, 3.0), Vec2f::new(4.0, 4.0));
+    /// assert_eq!(a.intersect(&c), Some(SegmentIntersection2f::Overlap(shared)));
+    /// ```
+    pub fn intersect(&self, segment: &Self) -> Option<SegmentIntersection2f> {
+        let r = self.end - self.start;
+        let s = segment.end - segment.start;
+        let offset = segment.start - self.start;
+        if !is_parallel(r, s) {
+            let denom = r.cross(s);
+            let t = offset.cross(s) / denom;
+            let u = offset.cross(r) / denom;
+            if !(0.0..=1.0).contains(&t) || !(0.0..=1.0).contains(&u) {
+                return None;
+            }
+            return Some(SegmentIntersection2f::Point(self.at(t)));
+        }
+        let (base, axis) = if r.dot(r) >= s.dot(s) {
+            (self.start, r)
+        } else {
+            (segment.start, s)
+        };
+        let axis_squared = axis.dot(axis);
+        if axis_squared == 0.0 {
+            return (self.start == segment.start)
+                .then_some(SegmentIntersection2f::Point(self.start));
+        }
+        let ends = [self.start, self.end, segment.start, segment.end];
+        if !ends.iter().all(|&end| is_parallel(end - base, axis)) {
+            return None;
+        }
+        let along = |point: Vec2f| ((point - base).dot(axis) / axis_squared, point);
+        let ordered = |a: Vec2f, b: Vec2f| {
+            let (a, b) = (along(a), along(b));
+            if a.0 <= b.0 {
+                (a, b)
+            } else {
+                (b, a)
+            }
+        };
+        let (low1, high1) = ordered(self.start, self.end);
+        let (low2, high2) = ordered(segment.start, segment.end);
+        let low = if low1.0 >= low2.0 { low1 } else { low2 };
+        let high = if high1.0 <= high2.0 { high1 } else { high2 };
+        if low.0 > high.0 {
+            return None;
+        }
+        if low.0 == high.0 {
+            return Some(SegmentIntersection2f::Point(low.1));
+        }
+        let overlap = if r.dot(axis) >= 0.0 {
+            Self::new(low.1, high.1)
+        } else {
+            Self::new(high.1, low.1)
+        };
+        Some(SegmentIntersection2f::Overlap(overlap))
+    }
+}
+
+/// Returns `true` if `u` and `v` point along the same line, allowing for rounding relative to
+/// their lengths
+///
+/// A zero vector is parallel to everything.
+pub(super) fn is_parallel(u: Vec2f, v: Vec2f) -> bool {
+    u.cross(v).abs() <= f32::EPSILON * (u.dot(u) * v.dot(v)).sqrt()
+}
@@ -0,0 +1,120 @@
+use crate::vec3::Vec3f;
+
+/// A line segment of `f32` values between `start` and `end`
+///
+/// A segment with `start == end` is a single point, and every query treats it that way.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Segment3f {
+    /// The first end of the segment
+    pub start: Vec3f,
+    /// The second end of the segment
+    pub end: Vec3f,
+}
+
+impl Segment3f {
+    /// Creates a new segment
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - A `Vec3f` holding the first end of the segment
+    ///
+    /// * `end` - A `Vec3f` holding the second end of the segment
+    pub const fn new(start: Vec3f, end: Vec3f) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the length of the segment
+    pub fn length(&self) -> f32 {
+        let dir = self.end - self.start;
+        dir.dot(dir).sqrt()
+    }
+
+    /// Returns the point `start + (end - start) * t`, which is `start` at `t = 0` and `end` at
+    /// `t = 1`
+    pub fn at(&self, t: f32) -> Vec3f {
+        self.start + (self.end - self.start) * t
+    }
+
+    /// Returns the parameter in `[0, 1]` of the point of the segment closest to `point`
+    ///
+    /// A zero-length segment returns `0`.
+    pub fn project(&self, point: Vec3f) -> f32 {
+        let dir = self.end - self.start;
+        let length_squared = dir.dot(dir);
+        if length_squared == 0.0 {
+            return 0.0;
+        }
+        ((point - self.start).dot(dir) / length_squared).clamp(0.0, 1.0)
+    }
+
+    /// Returns the point of the segment closest to `point`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::segment::Segment3f;
+    /// use yavml::vec3::Vec3f;
+    /// let segment = Segment3f::new(Vec3f::ZERO, Vec3f::new(0.0, 0.0, 4.0));
+    /// assert_eq!(segment.closest_point(Vec3f::new(3.0, 0.0, 1.0)), Vec3f::new(0.0, 0.0, 1.0));
+    /// assert_eq!(segment.distance_to_point(Vec3f::new(0.0, 0.0, 7.0)), 3.0);
+    /// ```
+    pub fn closest_point(&self, point: Vec3f) -> Vec3f {
+        self.at(self.project(point))
+    }
+
+    /// Returns the distance from `point` to the closest point of the segment
+    pub fn distance_to_point(&self, point: Vec3f) -> f32 {
+        let offset = point - self.closest_point(point);
+        offset.dot(offset).sqrt()
+    }
+
+    /// Returns the closest pair of points between `self` and `segment`, with the point on `self`
+    /// first
+    ///
+    /// Zero-length segments are treated as points. When the segments are parallel there are
+    /// many closest pairs, and the one through `self.start` is preferred.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::segment::Segment3f;
+    /// use yavml::vec3::Vec3f;
+    /// let a = Segment3f::new(Vec3f::new(-1.0, 0.0, 0.0), Vec3f::new(1.0, 0.0, 0.0));
+    /// let b = Segment3f::new(Vec3f::new(0.5, -1.0, 2.0), Vec3f::new(0.5, 1.0, 2.0));
+    /// let (on_a, on_b) = a.closest_points(&b);
+    /// assert_eq!(on_a, Vec3f::new(0.5, 0.0, 0.0));
+    /// assert_eq!(on_b, Vec3f::new(0.5, 0.0, 2.0));
+    /// ```
+    pub fn closest_points(&self, segment: &Self) -> (Vec3f, Vec3f) {
+        let d1 = self.end - self.start;
+        let d2 = segment.end - segment.start;
+        let r = self.start - segment.start;
+        let a = d1.dot(d1);
+        let e = d2.dot(d2);
+        let f = d2.dot(r);
+        if a == 0.0 && e == 0.0 {
+            return (self.start, segment.start);
+        }
+        if a == 0.0 {
+            return (self.start, segment.at((f / e).clamp(0.0, 1.0)));
+        }
+        let c = d1.dot(r);
+        if e == 0.0 {
+            return (self.at((-c / a).clamp(0.0, 1.0)), segment.start);
+        }
+        let b = d1.dot(d2);
+        let denom = a * e - b * b;
+        let mut s = if denom > f32::EPSILON * a * e {
+            ((b * f - c * e) / denom).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let mut t = (b * s + f) / e;
+        if t < 0.0 {
+            t = 0.0;
+            s = (-c / a).clamp(0.0, 1.0);
+        } else if t > 1.0 {
+            t = 1.0;
+            s = ((b - c) / a).clamp(0.0, 1.0);
+        }
+        (self.at(s), segment.at(t))
+    }
+}
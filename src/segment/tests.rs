@@ -0,0 +1,172 @@
+use super::Line2f;
+use super::Segment2f;
+use super::Segment3f;
+use super::SegmentIntersection2f;
+use crate::vec2::Vec2f;
+use crate::vec3::Vec3f;
+
+mod segment2f_tests {
+    use super::Segment2f;
+    use super::SegmentIntersection2f;
+    use super::Vec2f;
+
+    fn segment(ax: f32, ay: f32, bx: f32, by: f32) -> Segment2f {
+        Segment2f::new(Vec2f::new(ax, ay), Vec2f::new(bx, by))
+    }
+
+    // Tests projection and closest points inside and past both ends
+    #[test]
+    fn test_segment2f_closest_point() {
+        let s = segment(1.0, 1.0, 5.0, 1.0);
+        assert_eq!(s.length(), 4.0);
+        assert_eq!(s.project(Vec2f::new(2.0, 4.0)), 0.25);
+        assert_eq!(s.closest_point(Vec2f::new(2.0, 4.0)), Vec2f::new(2.0, 1.0));
+        assert_eq!(s.closest_point(Vec2f::new(-3.0, 0.0)), s.start);
+        assert_eq!(s.closest_point(Vec2f::new(9.0, 0.0)), s.end);
+        assert_eq!(s.distance_to_point(Vec2f::new(8.0, 5.0)), 5.0);
+    }
+
+    // Tests that a zero-length segment behaves as a point
+    #[test]
+    fn test_segment2f_zero_length() {
+        let point = Vec2f::new(2.0, 3.0);
+        let s = Segment2f::new(point, point);
+        assert_eq!(s.project(Vec2f::ZERO), 0.0);
+        assert_eq!(s.closest_point(Vec2f::ZERO), point);
+        assert_eq!(s.distance_to_point(Vec2f::new(5.0, 7.0)), 5.0);
+        let crossing = segment(0.0, 3.0, 4.0, 3.0);
+        assert_eq!(
+            s.intersect(&crossing),
+            Some(SegmentIntersection2f::Point(point))
+        );
+        assert_eq!(
+            crossing.intersect(&s),
+            Some(SegmentIntersection2f::Point(point))
+        );
+        assert_eq!(s.intersect(&segment(0.0, 4.0, 4.0, 4.0)), None);
+        assert_eq!(s.intersect(&s), Some(SegmentIntersection2f::Point(point)));
+        assert_eq!(s.intersect(&Segment2f::new(Vec2f::ZERO, Vec2f::ZERO)), None);
+    }
+
+    // Tests crossing, touching and separate non-parallel segments
+    #[test]
+    fn test_segment2f_intersect_point() {
+        let a = segment(0.0, 0.0, 4.0, 0.0);
+        assert_eq!(
+            a.intersect(&segment(1.0, -1.0, 1.0, 1.0)),
+            Some(SegmentIntersection2f::Point(Vec2f::new(1.0, 0.0)))
+        );
+        assert_eq!(
+            a.intersect(&segment(4.0, 0.0, 6.0, 2.0)),
+            Some(SegmentIntersection2f::Point(Vec2f::new(4.0, 0.0)))
+        );
+        assert_eq!(a.intersect(&segment(5.0, -1.0, 5.0, 1.0)), None);
+        assert_eq!(a.intersect(&segment(1.0, 0.5, 1.0, 1.0)), None);
+    }
+
+    // Tests parallel, overlapping, nested and end-to-end collinear segments
+    #[test]
+    fn test_segment2f_intersect_collinear() {
+        let a = segment(0.0, 0.0, 4.0, 0.0);
+        assert_eq!(a.intersect(&segment(0.0, 1.0, 4.0, 1.0)), None);
+        assert_eq!(
+            a.intersect(&segment(6.0, 0.0, 2.0, 0.0)),
+            Some(SegmentIntersection2f::Overlap(segment(2.0, 0.0, 4.0, 0.0)))
+        );
+        assert_eq!(
+            segment(6.0, 0.0, 2.0, 0.0).intersect(&a),
+            Some(SegmentIntersection2f::Overlap(segment(4.0, 0.0, 2.0, 0.0)))
+        );
+        assert_eq!(
+            a.intersect(&segment(1.0, 0.0, 3.0, 0.0)),
+            Some(SegmentIntersection2f::Overlap(segment(1.0, 0.0, 3.0, 0.0)))
+        );
+        assert_eq!(
+            a.intersect(&segment(4.0, 0.0, 7.0, 0.0)),
+            Some(SegmentIntersection2f::Point(Vec2f::new(4.0, 0.0)))
+        );
+        assert_eq!(a.intersect(&segment(5.0, 0.0, 7.0, 0.0)), None);
+        assert_eq!(a.intersect(&a), Some(SegmentIntersection2f::Overlap(a)));
+    }
+}
+
+mod segment3f_tests {
+    use super::Segment3f;
+    use super::Vec3f;
+
+    // Tests projection and closest points in 3D, including a zero-length segment
+    #[test]
+    fn test_segment3f_closest_point() {
+        let s = Segment3f::new(Vec3f::ZERO, Vec3f::new(2.0, 2.0, 0.0));
+        assert_eq!(s.project(Vec3f::new(2.0, 0.0, 5.0)), 0.5);
+        assert_eq!(s.closest_point(Vec3f::new(-1.0, -1.0, 1.0)), Vec3f::ZERO);
+        assert_eq!(s.distance_to_point(Vec3f::new(1.0, 1.0, 3.0)), 3.0);
+        let point = Segment3f::new(Vec3f::ONE, Vec3f::ONE);
+        assert_eq!(point.closest_point(Vec3f::ZERO), Vec3f::ONE);
+        assert_eq!(point.length(), 0.0);
+    }
+
+    // Tests closest points between skew, clamped, parallel and degenerate segments
+    #[test]
+    fn test_segment3f_closest_points() {
+        let a = Segment3f::new(Vec3f::ZERO, Vec3f::new(4.0, 0.0, 0.0));
+        let skew = Segment3f::new(Vec3f::new(1.0, 1.0, -1.0), Vec3f::new(1.0, 1.0, 1.0));
+        assert_eq!(
+            a.closest_points(&skew),
+            (Vec3f::new(1.0, 0.0, 0.0), Vec3f::new(1.0, 1.0, 0.0))
+        );
+        let past_end = Segment3f::new(Vec3f::new(6.0, 1.0, 0.0), Vec3f::new(8.0, 3.0, 0.0));
+        assert_eq!(
+            a.closest_points(&past_end),
+            (Vec3f::new(4.0, 0.0, 0.0), Vec3f::new(6.0, 1.0, 0.0))
+        );
+        let parallel = Segment3f::new(Vec3f::new(2.0, 0.0, 3.0), Vec3f::new(6.0, 0.0, 3.0));
+        let (on_a, on_parallel) = a.closest_points(&parallel);
+        assert_eq!(on_parallel - on_a, Vec3f::new(0.0, 0.0, 3.0));
+        assert!(on_a.x >= 2.0 && on_a.x <= 4.0);
+        let point = Segment3f::new(Vec3f::new(2.0, 5.0, 0.0), Vec3f::new(2.0, 5.0, 0.0));
+        assert_eq!(
+            a.closest_points(&point),
+            (Vec3f::new(2.0, 0.0, 0.0), point.start)
+        );
+        assert_eq!(
+            point.closest_points(&a),
+            (point.start, Vec3f::new(2.0, 0.0, 0.0))
+        );
+        assert_eq!(point.closest_points(&point), (point.start, point.start));
+    }
+}
+
+mod line2f_tests {
+    use super::Line2f;
+    use super::Segment2f;
+    use super::Vec2f;
+
+    // Tests projection, closest points and distances on an infinite line
+    #[test]
+    fn test_line2f_closest_point() {
+        let line = Line2f::from_points(Vec2f::new(0.0, 1.0), Vec2f::new(2.0, 3.0));
+        assert_eq!(line.project(Vec2f::new(2.0, 1.0)), 0.5);
+        assert_eq!(line.project(Vec2f::new(-4.0, -3.0)), -2.0);
+        assert_eq!(
+            line.closest_point(Vec2f::new(2.0, 1.0)),
+            Vec2f::new(1.0, 2.0)
+        );
+        let axis = Line2f::new(Vec2f::ZERO, Vec2f::new(3.0, 0.0));
+        assert_eq!(axis.distance_to_point(Vec2f::new(-50.0, -2.0)), 2.0);
+        let point = Line2f::new(Vec2f::ONE, Vec2f::ZERO);
+        assert_eq!(point.closest_point(Vec2f::new(4.0, 5.0)), Vec2f::ONE);
+    }
+
+    // Tests crossing lines and parallel or degenerate lines
+    #[test]
+    fn test_line2f_intersect() {
+        let a = Line2f::from(Segment2f::new(Vec2f::ZERO, Vec2f::new(1.0, 1.0)));
+        let b = Line2f::new(Vec2f::new(4.0, 0.0), Vec2f::new(-1.0, 1.0));
+        assert_eq!(a.intersect(&b), Some(Vec2f::new(2.0, 2.0)));
+        let parallel = Line2f::new(Vec2f::new(1.0, 0.0), Vec2f::new(-2.0, -2.0));
+        assert_eq!(a.intersect(&parallel), None);
+        assert_eq!(a.intersect(&a), None);
+        assert_eq!(a.intersect(&Line2f::new(Vec2f::ONE, Vec2f::ZERO)), None);
+    }
+}
@@ -0,0 +1,8 @@
+pub mod transform2d;
+pub use transform2d::Transform2D;
+
+pub mod transform3d;
+pub use transform3d::Transform3D;
+
+#[cfg(test)]
+mod tests;
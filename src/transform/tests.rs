@@ -0,0 +1,182 @@
+use super::Transform2D;
+use super::Transform3D;
+use crate::mat3::Mat3f;
+use crate::mat4::Mat4f;
+use crate::quat::Quatf;
+use crate::rot2::Rot2f;
+use crate::vec2::Vec2f;
+use crate::vec3::Vec3f;
+
+mod transform2d_tests {
+    use super::Mat3f;
+    use super::Rot2f;
+    use super::Transform2D;
+    use super::Vec2f;
+    use crate::affine::Affine2;
+    use crate::Error;
+
+    fn sample() -> Transform2D {
+        Transform2D::new(
+            Vec2f::new(3.0, -4.0),
+            Rot2f::from_angle(0.7),
+            Vec2f::splat(2.0),
+        )
+    }
+
+    // Tests that points and vectors agree with the equivalent matrix
+    #[test]
+    fn test_transform2d_matches_matrix() {
+        let transform = Transform2D::new(
+            Vec2f::new(3.0, -4.0),
+            Rot2f::from_angle(0.7),
+            Vec2f::new(2.0, 0.5),
+        );
+        let matrix = transform.to_matrix();
+        let point = Vec2f::new(-1.5, 2.5);
+        assert!(
+            (transform.transform_point(point) - matrix.transform_point2(point)).length() < 1e-5
+        );
+        assert!(
+            (transform.transform_vector(point) - matrix.transform_vector2(point)).length() < 1e-5
+        );
+        assert_eq!(Mat3f::from(transform), matrix);
+        assert!(Affine2::from(transform).abs_diff_eq(Affine2::from_mat3(matrix), 1e-6));
+        assert_eq!(Transform2D::default().to_matrix(), Mat3f::IDENTITY);
+    }
+
+    // Tests that converting from a matrix recovers the parts and reports invalid matrices
+    #[test]
+    fn test_transform2d_from_matrix() {
+        let transform = Transform2D::new(
+            Vec2f::new(1.0, 2.0),
+            Rot2f::from_angle(-2.0),
+            Vec2f::new(0.5, 3.0),
+        );
+        let converted = Transform2D::from_matrix(&transform.to_matrix()).unwrap();
+        assert!(converted.abs_diff_eq(transform, 1e-5));
+        let sheared = Mat3f::from_cols_array([1.0, 0.0, 0.0, 0.5, 1.0, 0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(Transform2D::from_matrix(&sheared), Err(Error::Shear));
+    }
+
+    // Tests that composition matches matrix multiplication and the inverse undoes a transform
+    #[test]
+    fn test_transform2d_mul_inverse() {
+        let a = sample();
+        let b = Transform2D::new(
+            Vec2f::new(-1.0, 0.5),
+            Rot2f::from_angle(-1.2),
+            Vec2f::new(0.5, 3.0),
+        );
+        assert!((a * b)
+            .to_matrix()
+            .abs_diff_eq(a.to_matrix() * b.to_matrix(), 1e-5));
+        let mut c = a;
+        c *= b;
+        assert_eq!(c, a * b);
+
+        assert!((a * a.inverse()).abs_diff_eq(Transform2D::IDENTITY, 1e-5));
+        assert!((a.inverse() * a).abs_diff_eq(Transform2D::IDENTITY, 1e-5));
+        let point = Vec2f::new(7.0, -2.0);
+        assert!((a.inverse().transform_point(a.transform_point(point)) - point).length() < 1e-5);
+    }
+
+    // Tests interpolation of each part, including the rotation across the +-PI seam
+    #[test]
+    fn test_transform2d_lerp() {
+        let start = Transform2D::new(Vec2f::ZERO, Rot2f::from_angle(3.0), Vec2f::ONE);
+        let end = Transform2D::new(
+            Vec2f::new(2.0, 4.0),
+            Rot2f::from_angle(-3.0),
+            Vec2f::splat(3.0),
+        );
+        assert!(start.lerp(end, 0.0).abs_diff_eq(start, 1e-6));
+        assert!(start.lerp(end, 1.0).abs_diff_eq(end, 1e-5));
+        let quarter = start.lerp(end, 0.25);
+        assert!((quarter.translation - Vec2f::new(0.5, 1.0)).length() < 1e-6);
+        assert!(quarter.rotation.abs_diff_eq(
+            Rot2f::from_angle(3.0 + 0.25 * (core::f32::consts::TAU - 6.0)),
+            1e-5
+        ));
+        assert!((quarter.scale - Vec2f::splat(1.5)).length() < 1e-6);
+    }
+}
+
+mod transform3d_tests {
+    use super::Mat4f;
+    use super::Quatf;
+    use super::Transform3D;
+    use super::Vec3f;
+    use crate::affine::Affine3;
+
+    fn sample() -> Transform3D {
+        Transform3D::new(
+            Vec3f::new(1.0, -2.0, 3.0),
+            Quatf::from_axis_angle(Vec3f::new(0.0, 0.6, 0.8), 1.1),
+            Vec3f::splat(1.5),
+        )
+    }
+
+    // Tests that points and vectors agree with the equivalent matrix
+    #[test]
+    fn test_transform3d_matches_matrix() {
+        let mut transform = sample();
+        transform.scale = Vec3f::new(1.5, 0.5, 2.0);
+        let matrix = transform.to_matrix();
+        let point = Vec3f::new(-1.0, 0.5, 2.0);
+        assert!(
+            (transform.transform_point(point) - matrix.transform_point3(point)).length() < 1e-5
+        );
+        assert!(
+            (transform.transform_vector(point) - matrix.transform_vector3(point)).length() < 1e-5
+        );
+        assert_eq!(Mat4f::from(transform), matrix);
+        assert!(Affine3::from(transform).abs_diff_eq(Affine3::from_mat4(matrix), 1e-6));
+
+        let converted = Transform3D::from_matrix(&matrix).unwrap();
+        assert!(converted.to_matrix().abs_diff_eq(matrix, 1e-5));
+        assert!((converted.scale - transform.scale).length() < 1e-5);
+        assert!(Transform3D::from_matrix(&Mat4f::ZERO).is_err());
+    }
+
+    // Tests that composition matches matrix multiplication and the inverse undoes a transform
+    #[test]
+    fn test_transform3d_mul_inverse() {
+        let a = sample();
+        let b = Transform3D::new(
+            Vec3f::new(0.5, 0.0, -4.0),
+            Quatf::from_axis_angle(Vec3f::X, -0.4),
+            Vec3f::new(2.0, 1.0, 0.5),
+        );
+        assert!((a * b)
+            .to_matrix()
+            .abs_diff_eq(a.to_matrix() * b.to_matrix(), 1e-5));
+        let mut c = a;
+        c *= b;
+        assert_eq!(c, a * b);
+
+        assert!((a * a.inverse()).abs_diff_eq(Transform3D::IDENTITY, 1e-5));
+        let point = Vec3f::new(7.0, -2.0, 0.25);
+        let back = a.inverse().transform_point(a.transform_point(point));
+        assert!((back - point).length() < 1e-5);
+    }
+
+    // Tests that interpolation slerps the rotation and lerps the other parts
+    #[test]
+    fn test_transform3d_lerp() {
+        let start = Transform3D::from_rotation(Quatf::from_axis_angle(Vec3f::Z, 0.2));
+        let end = Transform3D::new(
+            Vec3f::new(4.0, 0.0, -8.0),
+            Quatf::from_axis_angle(Vec3f::Z, 1.4),
+            Vec3f::new(3.0, 1.0, 5.0),
+        );
+        assert!(start.lerp(end, 0.0).abs_diff_eq(start, 1e-6));
+        assert!(start.lerp(end, 1.0).abs_diff_eq(end, 1e-5));
+        let quarter = start.lerp(end, 0.25);
+        assert!((quarter.translation - Vec3f::new(1.0, 0.0, -2.0)).length() < 1e-6);
+        assert!(quarter
+            .rotation
+            .abs_diff_eq(Quatf::from_axis_angle(Vec3f::Z, 0.5), 1e-6));
+        assert!((quarter.scale - Vec3f::new(1.5, 1.0, 2.0)).length() < 1e-6);
+        assert_eq!(Transform3D::default(), Transform3D::IDENTITY);
+    }
+}
@@ -0,0 +1,214 @@
+use core::ops::*;
+
+use crate::affine::Affine2;
+use crate::error::Error;
+use crate::mat3::Mat3f;
+use crate::rot2::Rot2f;
+use crate::vec2::Vec2f;
+use crate::vec3::Vec3f;
+
+/// A 2D transform stored as a separate translation, rotation and scale
+///
+/// Points are scaled first, then rotated and then translated. Composition and inversion keep
+/// the three parts separate, which is exact as long as the scale is uniform. A non-uniform scale
+/// followed by a rotation would need a shear to represent exactly, so
+/// [`Transform2D::mul_transform`] and [`Transform2D::inverse`] only approximate such transforms.
+/// Use [`Affine2`] when that matters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform2D {
+    /// The translation applied last
+    pub translation: Vec2f,
+    /// The rotation applied after the scale
+    pub rotation: Rot2f,
+    /// The scale along the x and y axes, applied first
+    pub scale: Vec2f,
+}
+
+impl Transform2D {
+    /// The identity transform
+    pub const IDENTITY: Self = Self::new(Vec2f::ZERO, Rot2f::IDENTITY, Vec2f::ONE);
+
+    /// Creates a new transform
+    ///
+    /// # Arguments
+    ///
+    /// * `translation` - The translation applied last
+    ///
+    /// * `rotation` - The rotation applied after the scale
+    ///
+    /// * `scale` - The scale along the x and y axes, applied first
+    pub const fn new(translation: Vec2f, rotation: Rot2f, scale: Vec2f) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Creates a transform that translates by `translation`
+    pub const fn from_translation(translation: Vec2f) -> Self {
+        Self::new(translation, Rot2f::IDENTITY, Vec2f::ONE)
+    }
+
+    /// Creates a transform that rotates by `rotation`
+    pub const fn from_rotation(rotation: Rot2f) -> Self {
+        Self::new(Vec2f::ZERO, rotation, Vec2f::ONE)
+    }
+
+    /// Creates a transform that scales along the x and y axes by `scale`
+    pub const fn from_scale(scale: Vec2f) -> Self {
+        Self::new(Vec2f::ZERO, Rot2f::IDENTITY, scale)
+    }
+
+    /// Creates a transform from a 2D affine `Mat3f`
+    ///
+    /// A matrix with a negative determinant is stored with a negative x scale.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Projective`] if the bottom row is not `(0, 0, 1)`, [`Error::Degenerate`]
+    /// if an axis has zero length and [`Error::Shear`] if the axes are not perpendicular.
+    pub fn from_matrix(mat: &Mat3f) -> Result<Self, Error> {
+        let (scale, angle, translation) = mat.to_scale_angle_translation()?;
+        Ok(Self::new(translation, Rot2f::from_angle(angle), scale))
+    }
+
+    /// Returns the transform as a 2D affine `Mat3f`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat3::Mat3f;
+    /// use yavml::rot2::Rot2f;
+    /// use yavml::transform::Transform2D;
+    /// use yavml::vec2::Vec2f;
+    /// let transform = Transform2D::new(Vec2f::new(1.0, 2.0), Rot2f::from_angle(0.5), Vec2f::splat(3.0));
+    /// let expected = Mat3f::from_scale_angle_translation(Vec2f::splat(3.0), 0.5, Vec2f::new(1.0, 2.0));
+    /// assert!(transform.to_matrix().abs_diff_eq(expected, 1e-6));
+    /// ```
+    pub fn to_matrix(&self) -> Mat3f {
+        let Rot2f { cos, sin } = self.rotation;
+        Mat3f::from_cols(
+            Vec3f::new(cos * self.scale.x, sin * self.scale.x, 0.0),
+            Vec3f::new(-sin * self.scale.y, cos * self.scale.y, 0.0),
+            Vec3f::new(self.translation.x, self.translation.y, 1.0),
+        )
+    }
+
+    /// Returns the transform as an [`Affine2`]
+    pub fn to_affine2(&self) -> Affine2 {
+        Affine2::from_mat3(self.to_matrix())
+    }
+
+    /// Transforms `point`, applying the scale, rotation and translation
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::rot2::Rot2f;
+    /// use yavml::transform::Transform2D;
+    /// use yavml::vec2::Vec2f;
+    /// let transform = Transform2D::new(
+    ///     Vec2f::new(10.0, 0.0),
+    ///     Rot2f::from_angle(core::f32::consts::FRAC_PI_2),
+    ///     Vec2f::splat(2.0),
+    /// );
+    /// let point = transform.transform_point(Vec2f::new(1.0, 0.0));
+    /// assert!((point - Vec2f::new(10.0, 2.0)).length() < 1e-6);
+    /// ```
+    pub fn transform_point(&self, point: Vec2f) -> Vec2f {
+        self.transform_vector(point) + self.translation
+    }
+
+    /// Transforms `vector`, applying only the scale and rotation
+    pub fn transform_vector(&self, vector: Vec2f) -> Vec2f {
+        self.rotation * (self.scale * vector)
+    }
+
+    /// Returns the composition `self * rhs`, which applies `rhs` first
+    ///
+    /// The scales are multiplied component-wise, which is exact when the scale of `self` is
+    /// uniform or `rhs` does not rotate.
+    pub fn mul_transform(&self, rhs: &Self) -> Self {
+        Self::new(
+            self.transform_point(rhs.translation),
+            self.rotation * rhs.rotation,
+            self.scale * rhs.scale,
+        )
+    }
+
+    /// Returns the inverse transform
+    ///
+    /// This is exact for a uniform scale. A zero scale component gives non-finite values.
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.inverse();
+        let scale = Vec2f::ONE / self.scale;
+        Self::new(scale * (rotation * -self.translation), rotation, scale)
+    }
+
+    /// Interpolates between `self` and `end`
+    ///
+    /// The translation and scale are interpolated linearly and the rotation along the shorter
+    /// arc at a constant angular speed.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::rot2::Rot2f;
+    /// use yavml::transform::Transform2D;
+    /// use yavml::vec2::Vec2f;
+    /// let start = Transform2D::IDENTITY;
+    /// let end = Transform2D::new(Vec2f::new(4.0, 0.0), Rot2f::from_angle(1.0), Vec2f::splat(3.0));
+    /// let half = start.lerp(end, 0.5);
+    /// assert_eq!(half.translation, Vec2f::new(2.0, 0.0));
+    /// assert!((half.rotation.angle() - 0.5).abs() < 1e-6);
+    /// assert_eq!(half.scale, Vec2f::splat(2.0));
+    /// ```
+    pub fn lerp(&self, end: Self, t: f32) -> Self {
+        Self::new(
+            self.translation + (end.translation - self.translation) * t,
+            self.rotation.slerp(end.rotation, t),
+            self.scale + (end.scale - self.scale) * t,
+        )
+    }
+
+    /// Returns `true` if every component of `self` and `rhs` differs by at most `max_abs_diff`
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f32) -> bool {
+        (self.translation.x - rhs.translation.x).abs() <= max_abs_diff
+            && (self.translation.y - rhs.translation.y).abs() <= max_abs_diff
+            && self.rotation.abs_diff_eq(rhs.rotation, max_abs_diff)
+            && (self.scale.x - rhs.scale.x).abs() <= max_abs_diff
+            && (self.scale.y - rhs.scale.y).abs() <= max_abs_diff
+    }
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Transform2D> for Mat3f {
+    fn from(transform: Transform2D) -> Self {
+        transform.to_matrix()
+    }
+}
+
+impl From<Transform2D> for Affine2 {
+    fn from(transform: Transform2D) -> Self {
+        transform.to_affine2()
+    }
+}
+
+/// Composition of transforms, applying `rhs` first
+impl Mul<Transform2D> for Transform2D {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_transform(&rhs)
+    }
+}
+
+/// Composition assignment of transforms, applying `rhs` first
+impl MulAssign<Transform2D> for Transform2D {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_transform(&rhs);
+    }
+}
@@ -0,0 +1,215 @@
+use core::ops::*;
+
+use crate::affine::Affine3;
+use crate::error::Error;
+use crate::mat4::Mat4f;
+use crate::quat::Quatf;
+use crate::vec3::Vec3f;
+
+/// A 3D transform stored as a separate translation, rotation and scale
+///
+/// Points are scaled first, then rotated and then translated. Composition and inversion keep
+/// the three parts separate, which is exact as long as the scale is uniform. A non-uniform scale
+/// followed by a rotation would need a shear to represent exactly, so
+/// [`Transform3D::mul_transform`] and [`Transform3D::inverse`] only approximate such transforms.
+/// Use [`Affine3`] when that matters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform3D {
+    /// The translation applied last
+    pub translation: Vec3f,
+    /// The unit quaternion rotation applied after the scale
+    pub rotation: Quatf,
+    /// The scale along the x, y and z axes, applied first
+    pub scale: Vec3f,
+}
+
+impl Transform3D {
+    /// The identity transform
+    pub const IDENTITY: Self = Self::new(Vec3f::ZERO, Quatf::IDENTITY, Vec3f::ONE);
+
+    /// Creates a new transform
+    ///
+    /// # Arguments
+    ///
+    /// * `translation` - The translation applied last
+    ///
+    /// * `rotation` - The unit quaternion rotation applied after the scale
+    ///
+    /// * `scale` - The scale along the x, y and z axes, applied first
+    pub const fn new(translation: Vec3f, rotation: Quatf, scale: Vec3f) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Creates a transform that translates by `translation`
+    pub const fn from_translation(translation: Vec3f) -> Self {
+        Self::new(translation, Quatf::IDENTITY, Vec3f::ONE)
+    }
+
+    /// Creates a transform that rotates by the unit quaternion `rotation`
+    pub const fn from_rotation(rotation: Quatf) -> Self {
+        Self::new(Vec3f::ZERO, rotation, Vec3f::ONE)
+    }
+
+    /// Creates a transform that scales along the x, y and z axes by `scale`
+    pub const fn from_scale(scale: Vec3f) -> Self {
+        Self::new(Vec3f::ZERO, Quatf::IDENTITY, scale)
+    }
+
+    /// Creates a transform from an affine `Mat4f`
+    ///
+    /// A matrix with a negative determinant is stored with a negative x scale.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Projective`] if the bottom row is not `(0, 0, 0, 1)`,
+    /// [`Error::Degenerate`] if an axis has zero length and [`Error::Shear`] if the axes are not
+    /// perpendicular.
+    pub fn from_matrix(mat: &Mat4f) -> Result<Self, Error> {
+        let (scale, rotation, translation) = mat.to_scale_quat_translation()?;
+        Ok(Self::new(translation, rotation, scale))
+    }
+
+    /// Returns the transform as an affine `Mat4f`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::mat4::Mat4f;
+    /// use yavml::quat::Quatf;
+    /// use yavml::transform::Transform3D;
+    /// use yavml::vec3::Vec3f;
+    /// let rotation = Quatf::from_axis_angle(Vec3f::Y, 0.5);
+    /// let transform = Transform3D::new(Vec3f::ONE, rotation, Vec3f::splat(2.0));
+    /// let expected = Mat4f::from_scale_quat_translation(Vec3f::splat(2.0), rotation, Vec3f::ONE);
+    /// assert!(transform.to_matrix().abs_diff_eq(expected, 1e-6));
+    /// ```
+    pub fn to_matrix(&self) -> Mat4f {
+        Mat4f::from_scale_quat_translation(self.scale, self.rotation, self.translation)
+    }
+
+    /// Returns the transform as an [`Affine3`]
+    pub fn to_affine3(&self) -> Affine3 {
+        Affine3::from_scale_quat_translation(self.scale, self.rotation, self.translation)
+    }
+
+    /// Transforms `point`, applying the scale, rotation and translation
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::quat::Quatf;
+    /// use yavml::transform::Transform3D;
+    /// use yavml::vec3::Vec3f;
+    /// let transform = Transform3D::new(
+    ///     Vec3f::new(0.0, 0.0, 5.0),
+    ///     Quatf::from_axis_angle(Vec3f::Z, core::f32::consts::FRAC_PI_2),
+    ///     Vec3f::splat(2.0),
+    /// );
+    /// let point = transform.transform_point(Vec3f::X);
+    /// assert!((point - Vec3f::new(0.0, 2.0, 5.0)).length() < 1e-6);
+    /// ```
+    pub fn transform_point(&self, point: Vec3f) -> Vec3f {
+        self.transform_vector(point) + self.translation
+    }
+
+    /// Transforms `vector`, applying only the scale and rotation
+    pub fn transform_vector(&self, vector: Vec3f) -> Vec3f {
+        self.rotation * (self.scale * vector)
+    }
+
+    /// Returns the composition `self * rhs`, which applies `rhs` first
+    ///
+    /// The scales are multiplied component-wise, which is exact when the scale of `self` is
+    /// uniform or `rhs` does not rotate.
+    pub fn mul_transform(&self, rhs: &Self) -> Self {
+        Self::new(
+            self.transform_point(rhs.translation),
+            self.rotation * rhs.rotation,
+            self.scale * rhs.scale,
+        )
+    }
+
+    /// Returns the inverse transform
+    ///
+    /// This is exact for a uniform scale. A zero scale component gives non-finite values.
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.conjugate();
+        let scale = Vec3f::ONE / self.scale;
+        Self::new(scale * (rotation * -self.translation), rotation, scale)
+    }
+
+    /// Interpolates between `self` and `end`
+    ///
+    /// The translation and scale are interpolated linearly and the rotation is spherically
+    /// interpolated along the shorter arc.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::quat::Quatf;
+    /// use yavml::transform::Transform3D;
+    /// use yavml::vec3::Vec3f;
+    /// let start = Transform3D::IDENTITY;
+    /// let end = Transform3D::new(Vec3f::splat(4.0), Quatf::from_axis_angle(Vec3f::X, 1.0), Vec3f::splat(3.0));
+    /// let half = start.lerp(end, 0.5);
+    /// assert_eq!(half.translation, Vec3f::splat(2.0));
+    /// assert!(half.rotation.abs_diff_eq(Quatf::from_axis_angle(Vec3f::X, 0.5), 1e-6));
+    /// assert_eq!(half.scale, Vec3f::splat(2.0));
+    /// ```
+    pub fn lerp(&self, end: Self, t: f32) -> Self {
+        Self::new(
+            self.translation + (end.translation - self.translation) * t,
+            self.rotation.slerp(end.rotation, t),
+            self.scale + (end.scale - self.scale) * t,
+        )
+    }
+
+    /// Returns `true` if every component of `self` and `rhs` differs by at most `max_abs_diff`
+    ///
+    /// The quaternions `q` and `-q` describe the same rotation but are not considered equal.
+    pub fn abs_diff_eq(&self, rhs: Self, max_abs_diff: f32) -> bool {
+        let diff = |a: Vec3f, b: Vec3f| {
+            (a.x - b.x).abs() <= max_abs_diff
+                && (a.y - b.y).abs() <= max_abs_diff
+                && (a.z - b.z).abs() <= max_abs_diff
+        };
+        diff(self.translation, rhs.translation)
+            && self.rotation.abs_diff_eq(rhs.rotation, max_abs_diff)
+            && diff(self.scale, rhs.scale)
+    }
+}
+
+impl Default for Transform3D {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Transform3D> for Mat4f {
+    fn from(transform: Transform3D) -> Self {
+        transform.to_matrix()
+    }
+}
+
+impl From<Transform3D> for Affine3 {
+    fn from(transform: Transform3D) -> Self {
+        transform.to_affine3()
+    }
+}
+
+/// Composition of transforms, applying `rhs` first
+impl Mul<Transform3D> for Transform3D {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_transform(&rhs)
+    }
+}
+
+/// Composition assignment of transforms, applying `rhs` first
+impl MulAssign<Transform3D> for Transform3D {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_transform(&rhs);
+    }
+}
@@ -0,0 +1,30 @@
+use core::ops::{Add, Mul};
+
+use crate::vec3::Vec3f;
+
+/// Returns the blend of `values` weighted by the barycentric coordinates `bary`
+///
+/// `values` holds the attribute at corners `a`, `b` and `c` in the order used by
+/// [`Triangle2f::barycentric`](super::Triangle2f::barycentric) and
+/// [`Triangle3f::barycentric`](super::Triangle3f::barycentric). Any type that can be added and
+/// scaled by an `f32` works, including the `f32` vectors, colors and texture coordinates.
+///
+/// # Examples:
+/// ```
+/// use yavml::triangle::{interpolate, Triangle2f};
+/// use yavml::vec2::Vec2f;
+/// use yavml::vec3::Vec3f;
+/// let triangle = Triangle2f::new(Vec2f::ZERO, Vec2f::new(2.0, 0.0), Vec2f::new(0.0, 2.0));
+/// let bary = triangle.barycentric(Vec2f::new(1.0, 0.5)).unwrap();
+/// let red = Vec3f::new(1.0, 0.0, 0.0);
+/// let green = Vec3f::new(0.0, 1.0, 0.0);
+/// let blue = Vec3f::new(0.0, 0.0, 1.0);
+/// assert_eq!(interpolate(bary, [red, green, blue]), Vec3f::new(0.25, 0.5, 0.25));
+/// assert_eq!(interpolate(bary, [0.0, 10.0, 20.0]), 10.0);
+/// ```
+pub fn interpolate<T>(bary: Vec3f, [a, b, c]: [T; 3]) -> T
+where
+    T: Add<Output = T> + Mul<f32, Output = T>,
+{
+    a * bary.x + b * bary.y + c * bary.z
+}
@@ -0,0 +1,11 @@
+pub mod triangle2f;
+pub use triangle2f::Triangle2f;
+
+pub mod triangle3f;
+pub use triangle3f::Triangle3f;
+
+pub mod interpolate;
+pub use interpolate::interpolate;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,208 @@
+use super::interpolate;
+use super::Triangle2f;
+use super::Triangle3f;
+use crate::circle::Circle;
+use crate::vec2::Vec2f;
+use crate::vec3::Vec3f;
+
+mod triangle2f_tests {
+    use super::interpolate;
+    use super::Circle;
+    use super::Triangle2f;
+    use super::Vec2f;
+    use super::Vec3f;
+
+    fn sample() -> Triangle2f {
+        Triangle2f::new(Vec2f::ZERO, Vec2f::new(4.0, 0.0), Vec2f::new(0.0, 3.0))
+    }
+
+    // Tests the signed area for both windings and the centroid
+    #[test]
+    fn test_triangle2f_area_centroid() {
+        let triangle = sample();
+        assert_eq!(triangle.signed_area(), 6.0);
+        let reversed = Triangle2f::new(triangle.a, triangle.c, triangle.b);
+        assert_eq!(reversed.signed_area(), -6.0);
+        assert_eq!(reversed.area(), 6.0);
+        assert_eq!(triangle.centroid(), Vec2f::new(4.0 / 3.0, 1.0));
+    }
+
+    // Tests barycentric coordinates at the corners, outside, and round trips through
+    // interpolate
+    #[test]
+    fn test_triangle2f_barycentric() {
+        let triangle = sample();
+        assert_eq!(
+            triangle.barycentric(triangle.a),
+            Some(Vec3f::new(1.0, 0.0, 0.0))
+        );
+        assert_eq!(
+            triangle.barycentric(triangle.b),
+            Some(Vec3f::new(0.0, 1.0, 0.0))
+        );
+        assert_eq!(
+            triangle.barycentric(triangle.c),
+            Some(Vec3f::new(0.0, 0.0, 1.0))
+        );
+        let outside = triangle.barycentric(Vec2f::new(-4.0, 0.0)).unwrap();
+        assert_eq!(outside, Vec3f::new(2.0, -1.0, 0.0));
+        let point = Vec2f::new(1.0, 1.0);
+        let bary = triangle.barycentric(point).unwrap();
+        let corners = [triangle.a, triangle.b, triangle.c];
+        let offset = interpolate(bary, corners) - point;
+        assert!(offset.dot(offset) < 1e-12);
+        let flat = Triangle2f::new(Vec2f::ZERO, Vec2f::ONE, Vec2f::new(2.0, 2.0));
+        assert_eq!(flat.barycentric(point), None);
+    }
+
+    // Tests containment inside, on edges and outside for both windings and degenerate triangles
+    #[test]
+    fn test_triangle2f_contains_point() {
+        let triangle = sample();
+        let reversed = Triangle2f::new(triangle.a, triangle.c, triangle.b);
+        for t in [triangle, reversed] {
+            assert!(t.contains_point(Vec2f::new(1.0, 1.0)));
+            assert!(t.contains_point(Vec2f::new(2.0, 0.0)));
+            assert!(t.contains_point(Vec2f::new(0.0, 3.0)));
+            assert!(!t.contains_point(Vec2f::new(3.0, 3.0)));
+            assert!(!t.contains_point(Vec2f::new(-0.1, 1.0)));
+        }
+        let flat = Triangle2f::new(Vec2f::ZERO, Vec2f::new(2.0, 2.0), Vec2f::ONE);
+        assert!(flat.contains_point(Vec2f::new(1.5, 1.5)));
+        assert!(!flat.contains_point(Vec2f::new(3.0, 3.0)));
+        assert!(!flat.contains_point(Vec2f::new(1.0, 0.0)));
+    }
+
+    // Tests closest points in each Voronoi region and on a degenerate triangle
+    #[test]
+    fn test_triangle2f_closest_point() {
+        let triangle = sample();
+        assert_eq!(
+            triangle.closest_point(Vec2f::new(1.0, 1.0)),
+            Vec2f::new(1.0, 1.0)
+        );
+        assert_eq!(triangle.closest_point(Vec2f::new(-2.0, -2.0)), triangle.a);
+        assert_eq!(triangle.closest_point(Vec2f::new(6.0, -1.0)), triangle.b);
+        assert_eq!(triangle.closest_point(Vec2f::new(-1.0, 5.0)), triangle.c);
+        assert_eq!(
+            triangle.closest_point(Vec2f::new(2.0, -5.0)),
+            Vec2f::new(2.0, 0.0)
+        );
+        assert_eq!(
+            triangle.closest_point(Vec2f::new(-5.0, 1.0)),
+            Vec2f::new(0.0, 1.0)
+        );
+        let offset = triangle.closest_point(Vec2f::new(4.0, 3.0)) - Vec2f::new(2.56, 1.08);
+        assert!(offset.dot(offset) < 1e-10);
+        let flat = Triangle2f::new(Vec2f::ZERO, Vec2f::new(2.0, 0.0), Vec2f::new(4.0, 0.0));
+        assert_eq!(
+            flat.closest_point(Vec2f::new(3.0, 5.0)),
+            Vec2f::new(3.0, 0.0)
+        );
+    }
+
+    // Tests the circumcircle and incircle of a right triangle
+    #[test]
+    fn test_triangle2f_circles() {
+        let triangle = sample();
+        assert_eq!(
+            triangle.circumcircle(),
+            Some(Circle::new(Vec2f::new(2.0, 1.5), 2.5))
+        );
+        assert_eq!(
+            triangle.incircle(),
+            Some(Circle::new(Vec2f::new(1.0, 1.0), 1.0))
+        );
+        let flat = Triangle2f::new(Vec2f::ZERO, Vec2f::ONE, Vec2f::new(2.0, 2.0));
+        assert_eq!(flat.circumcircle(), None);
+        assert_eq!(flat.incircle(), None);
+    }
+}
+
+mod triangle3f_tests {
+    use super::interpolate;
+    use super::Triangle3f;
+    use super::Vec3f;
+
+    fn sample() -> Triangle3f {
+        Triangle3f::new(
+            Vec3f::new(1.0, 0.0, 0.0),
+            Vec3f::new(0.0, 1.0, 0.0),
+            Vec3f::new(0.0, 0.0, 1.0),
+        )
+    }
+
+    // Tests the area, normal and centroid of a tilted triangle
+    #[test]
+    fn test_triangle3f_area_normal_centroid() {
+        let triangle = sample();
+        assert!((triangle.area() - 3.0_f32.sqrt() / 2.0).abs() < 1e-6);
+        let expected = Vec3f::splat(1.0 / 3.0_f32.sqrt());
+        let offset = triangle.normal().unwrap() - expected;
+        assert!(offset.dot(offset) < 1e-12);
+        assert_eq!(triangle.centroid(), Vec3f::splat(1.0 / 3.0));
+    }
+
+    // Tests barycentric coordinates, containment and interpolation of points on the triangle
+    #[test]
+    fn test_triangle3f_barycentric_contains() {
+        let triangle = sample();
+        let bary = triangle.barycentric(triangle.centroid()).unwrap();
+        let offset = bary - Vec3f::splat(1.0 / 3.0);
+        assert!(offset.dot(offset) < 1e-12);
+        let weights = Vec3f::new(0.5, 0.25, 0.25);
+        let point = interpolate(weights, [triangle.a, triangle.b, triangle.c]);
+        assert!(triangle.contains_point(point));
+        assert!(!triangle.contains_point(point + triangle.normal().unwrap() * 0.01));
+        assert!(!triangle.contains_point(Vec3f::new(1.0, 1.0, -1.0)));
+        let flat = Triangle3f::new(Vec3f::ZERO, Vec3f::ONE, Vec3f::splat(2.0));
+        assert_eq!(flat.barycentric(Vec3f::ONE), None);
+        assert_eq!(flat.normal(), None);
+        assert!(!flat.contains_point(Vec3f::ONE));
+    }
+
+    // Tests closest points above the face, past an edge, past a corner and on a degenerate
+    // triangle
+    #[test]
+    fn test_triangle3f_closest_point() {
+        let triangle = Triangle3f::new(
+            Vec3f::ZERO,
+            Vec3f::new(4.0, 0.0, 0.0),
+            Vec3f::new(0.0, 4.0, 0.0),
+        );
+        assert_eq!(
+            triangle.closest_point(Vec3f::new(1.0, 2.0, -3.0)),
+            Vec3f::new(1.0, 2.0, 0.0)
+        );
+        assert_eq!(
+            triangle.closest_point(Vec3f::new(2.0, -2.0, 1.0)),
+            Vec3f::new(2.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            triangle.closest_point(Vec3f::new(9.0, -1.0, 4.0)),
+            triangle.b
+        );
+        let flat = Triangle3f::new(Vec3f::ZERO, Vec3f::Z, Vec3f::new(0.0, 0.0, 3.0));
+        assert_eq!(
+            flat.closest_point(Vec3f::new(1.0, 1.0, 2.0)),
+            Vec3f::new(0.0, 0.0, 2.0)
+        );
+    }
+
+    // Tests that both circles have their centers in the plane and the right distances to the
+    // corners and edges
+    #[test]
+    fn test_triangle3f_circles() {
+        let triangle = sample();
+        let circumcircle = triangle.circumcircle().unwrap();
+        let center = triangle.centroid();
+        assert!((circumcircle.center - center).dot(circumcircle.center - center) < 1e-12);
+        assert!((circumcircle.radius - (2.0_f32 / 3.0).sqrt()).abs() < 1e-6);
+        let incircle = triangle.incircle().unwrap();
+        assert!((incircle.center - center).dot(incircle.center - center) < 1e-12);
+        assert!((incircle.radius - (1.0_f32 / 6.0).sqrt()).abs() < 1e-6);
+        let flat = Triangle3f::new(Vec3f::ZERO, Vec3f::ONE, Vec3f::splat(2.0));
+        assert_eq!(flat.circumcircle(), None);
+        assert_eq!(flat.incircle(), None);
+    }
+}
@@ -0,0 +1,210 @@
+use crate::circle::Circle;
+use crate::rect::Rect2f;
+use crate::segment::Segment2f;
+use crate::vec2::Vec2f;
+use crate::vec3::Vec3f;
+
+/// A triangle of `f32` values with corners `a`, `b` and `c`
+///
+/// The triangle is solid and closed, so points on its edges are inside it. Its corners may run
+/// in either direction, and queries that cannot give a meaningful answer for a degenerate
+/// triangle with collinear corners return `None`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Triangle2f {
+    /// The first corner
+    pub a: Vec2f,
+    /// The second corner
+    pub b: Vec2f,
+    /// The third corner
+    pub c: Vec2f,
+}
+
+impl Triangle2f {
+    /// Creates a new triangle
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - A `Vec2f` holding the first corner
+    ///
+    /// * `b` - A `Vec2f` holding the second corner
+    ///
+    /// * `c` - A `Vec2f` holding the third corner
+    pub const fn new(a: Vec2f, b: Vec2f, c: Vec2f) -> Self {
+        Self { a, b, c }
+    }
+
+    /// Returns the area of the triangle, which is positive when the corners run counterclockwise
+    /// and negative when they run clockwise
+    pub fn signed_area(&self) -> f32 {
+        (self.b - self.a).cross(self.c - self.a) * 0.5
+    }
+
+    /// Returns the area of the triangle
+    pub fn area(&self) -> f32 {
+        self.signed_area().abs()
+    }
+
+    /// Returns the average of the three corners
+    pub fn centroid(&self) -> Vec2f {
+        (self.a + self.b + self.c) / 3.0
+    }
+
+    /// Returns the barycentric coordinates of `point`, or `None` if the triangle is degenerate
+    ///
+    /// The coordinates are the weights of `a`, `b` and `c` in that order, and they sum to one.
+    /// They are all in `[0, 1]` exactly when `point` is inside the triangle.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::triangle::Triangle2f;
+    /// use yavml::vec2::Vec2f;
+    /// use yavml::vec3::Vec3f;
+    /// let triangle = Triangle2f::new(Vec2f::ZERO, Vec2f::new(4.0, 0.0), Vec2f::new(0.0, 4.0));
+    /// let bary = triangle.barycentric(Vec2f::new(1.0, 2.0)).unwrap();
+    /// assert_eq!(bary, Vec3f::new(0.25, 0.25, 0.5));
+    /// ```
+    pub fn barycentric(&self, point: Vec2f) -> Option<Vec3f> {
+        let double_area = (self.b - self.a).cross(self.c - self.a);
+        if double_area == 0.0 {
+            return None;
+        }
+        let v = (point - self.a).cross(self.c - self.a) / double_area;
+        let w = (self.b - self.a).cross(point - self.a) / double_area;
+        Some(Vec3f::new(1.0 - v - w, v, w))
+    }
+
+    /// Returns `true` if `point` is inside the triangle or on its edges
+    ///
+    /// The test uses the signs of the edge cross products, so a degenerate triangle contains
+    /// exactly the points of the segment its corners span.
+    pub fn contains_point(&self, point: Vec2f) -> bool {
+        let sides = [
+            (self.b - self.a).cross(point - self.a),
+            (self.c - self.b).cross(point - self.b),
+            (self.a - self.c).cross(point - self.c),
+        ];
+        let positive = sides.iter().any(|&side| side > 0.0);
+        let negative = sides.iter().any(|&side| side < 0.0);
+        if positive || negative {
+            return !(positive && negative);
+        }
+        Rect2f::from_points([self.a, self.b, self.c]).is_some_and(|rect| rect.contains_point(point))
+    }
+
+    /// Returns the point of the triangle closest to `point`, which is `point` itself when it is
+    /// inside
+    ///
+    /// This uses the Voronoi regions of the corners and edges, falling back to the closest of
+    /// the three edges for a degenerate triangle.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::triangle::Triangle2f;
+    /// use yavml::vec2::Vec2f;
+    /// let triangle = Triangle2f::new(Vec2f::ZERO, Vec2f::new(4.0, 0.0), Vec2f::new(0.0, 4.0));
+    /// assert_eq!(triangle.closest_point(Vec2f::new(2.0, -3.0)), Vec2f::new(2.0, 0.0));
+    /// assert_eq!(triangle.closest_point(Vec2f::new(5.0, 5.0)), Vec2f::new(2.0, 2.0));
+    /// ```
+    pub fn closest_point(&self, point: Vec2f) -> Vec2f {
+        if self.contains_point(point) {
+            return point;
+        }
+        if self.signed_area() == 0.0 {
+            return closest_on_edges([self.a, self.b, self.c], point);
+        }
+        let (a, b, c) = (self.a, self.b, self.c);
+        let ab = b - a;
+        let ac = c - a;
+        let ap = point - a;
+        let d1 = ab.dot(ap);
+        let d2 = ac.dot(ap);
+        if d1 <= 0.0 && d2 <= 0.0 {
+            return a;
+        }
+        let bp = point - b;
+        let d3 = ab.dot(bp);
+        let d4 = ac.dot(bp);
+        if d3 >= 0.0 && d4 <= d3 {
+            return b;
+        }
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+            return a + ab * (d1 / (d1 - d3));
+        }
+        let cp = point - c;
+        let d5 = ab.dot(cp);
+        let d6 = ac.dot(cp);
+        if d6 >= 0.0 && d5 <= d6 {
+            return c;
+        }
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+            return a + ac * (d2 / (d2 - d6));
+        }
+        let va = d3 * d6 - d5 * d4;
+        if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+        let denom = 1.0 / (va + vb + vc);
+        a + ab * (vb * denom) + ac * (vc * denom)
+    }
+
+    /// Returns the circle through all three corners, or `None` if the triangle is degenerate
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::circle::Circle;
+    /// use yavml::triangle::Triangle2f;
+    /// use yavml::vec2::Vec2f;
+    /// let triangle = Triangle2f::new(Vec2f::ZERO, Vec2f::new(6.0, 0.0), Vec2f::new(0.0, 8.0));
+    /// assert_eq!(triangle.circumcircle(), Some(Circle::new(Vec2f::new(3.0, 4.0), 5.0)));
+    /// ```
+    pub fn circumcircle(&self) -> Option<Circle> {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let double_area = ab.cross(ac);
+        if double_area == 0.0 {
+            return None;
+        }
+        let (ab_squared, ac_squared) = (ab.dot(ab), ac.dot(ac));
+        let offset = Vec2f::new(
+            ac.y * ab_squared - ab.y * ac_squared,
+            ab.x * ac_squared - ac.x * ab_squared,
+        ) / (2.0 * double_area);
+        Some(Circle::new(self.a + offset, offset.dot(offset).sqrt()))
+    }
+
+    /// Returns the largest circle inside the triangle, which touches all three edges, or `None`
+    /// if the triangle is degenerate
+    pub fn incircle(&self) -> Option<Circle> {
+        let double_area = (self.b - self.a).cross(self.c - self.a).abs();
+        if double_area == 0.0 {
+            return None;
+        }
+        let length = |v: Vec2f| v.dot(v).sqrt();
+        let (la, lb, lc) = (
+            length(self.c - self.b),
+            length(self.a - self.c),
+            length(self.b - self.a),
+        );
+        let perimeter = la + lb + lc;
+        let center = (self.a * la + self.b * lb + self.c * lc) / perimeter;
+        Some(Circle::new(center, double_area / perimeter))
+    }
+}
+
+/// Returns the point of the three edges of a degenerate triangle closest to `point`
+fn closest_on_edges([a, b, c]: [Vec2f; 3], point: Vec2f) -> Vec2f {
+    [
+        Segment2f::new(a, b),
+        Segment2f::new(b, c),
+        Segment2f::new(c, a),
+    ]
+    .map(|edge| edge.closest_point(point))
+    .into_iter()
+    .min_by(|p, q| {
+        let (p, q) = (*p - point, *q - point);
+        p.dot(p).total_cmp(&q.dot(q))
+    })
+    .unwrap_or(a)
+}
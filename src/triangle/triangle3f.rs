@@ -0,0 +1,236 @@
+use crate::circle::Sphere;
+use crate::segment::Segment3f;
+use crate::vec3::Vec3f;
+
+/// A triangle of `f32` values with corners `a`, `b` and `c`
+///
+/// The front of the triangle is the side from which its corners run counterclockwise, and
+/// queries that cannot give a meaningful answer for a degenerate triangle with collinear corners
+/// return `None`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Triangle3f {
+    /// The first corner
+    pub a: Vec3f,
+    /// The second corner
+    pub b: Vec3f,
+    /// The third corner
+    pub c: Vec3f,
+}
+
+impl Triangle3f {
+    /// Creates a new triangle
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - A `Vec3f` holding the first corner
+    ///
+    /// * `b` - A `Vec3f` holding the second corner
+    ///
+    /// * `c` - A `Vec3f` holding the third corner
+    pub const fn new(a: Vec3f, b: Vec3f, c: Vec3f) -> Self {
+        Self { a, b, c }
+    }
+
+    /// Returns the area of the triangle
+    pub fn area(&self) -> f32 {
+        let cross = (self.b - self.a).cross(self.c - self.a);
+        cross.dot(cross).sqrt() * 0.5
+    }
+
+    /// Returns the unit normal on the front of the triangle, or `None` if the triangle is
+    /// degenerate
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::triangle::Triangle3f;
+    /// use yavml::vec3::Vec3f;
+    /// let triangle = Triangle3f::new(Vec3f::ZERO, Vec3f::new(2.0, 0.0, 0.0), Vec3f::Y);
+    /// assert_eq!(triangle.normal(), Some(Vec3f::Z));
+    /// assert_eq!(triangle.area(), 1.0);
+    /// let flat = Triangle3f::new(Vec3f::ZERO, Vec3f::X, Vec3f::X * 2.0);
+    /// assert_eq!(flat.normal(), None);
+    /// ```
+    pub fn normal(&self) -> Option<Vec3f> {
+        let normal = (self.b - self.a).cross(self.c - self.a);
+        if normal.dot(normal) == 0.0 {
+            return None;
+        }
+        Some(normal.normalize())
+    }
+
+    /// Returns the average of the three corners
+    pub fn centroid(&self) -> Vec3f {
+        (self.a + self.b + self.c) / 3.0
+    }
+
+    /// Returns the barycentric coordinates of `point` projected onto the plane of the triangle,
+    /// or `None` if the triangle is degenerate
+    ///
+    /// The coordinates are the weights of `a`, `b` and `c` in that order, and they sum to one.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::triangle::Triangle3f;
+    /// use yavml::vec3::Vec3f;
+    /// let triangle = Triangle3f::new(Vec3f::ZERO, Vec3f::new(4.0, 0.0, 0.0), Vec3f::new(0.0, 4.0, 0.0));
+    /// let bary = triangle.barycentric(Vec3f::new(1.0, 2.0, 7.0)).unwrap();
+    /// assert_eq!(bary, Vec3f::new(0.25, 0.25, 0.5));
+    /// ```
+    pub fn barycentric(&self, point: Vec3f) -> Option<Vec3f> {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let normal = ab.cross(ac);
+        let normal_squared = normal.dot(normal);
+        if normal_squared == 0.0 {
+            return None;
+        }
+        let ap = point - self.a;
+        let v = ap.cross(ac).dot(normal) / normal_squared;
+        let w = ab.cross(ap).dot(normal) / normal_squared;
+        Some(Vec3f::new(1.0 - v - w, v, w))
+    }
+
+    /// Returns `true` if `point` lies on the triangle
+    ///
+    /// The point must be within rounding of the plane of the triangle, relative to the triangle's
+    /// size, and inside or on the edges of the triangle within that plane. A degenerate triangle
+    /// contains no points.
+    pub fn contains_point(&self, point: Vec3f) -> bool {
+        let Some(bary) = self.barycentric(point) else {
+            return false;
+        };
+        if bary.x < 0.0 || bary.y < 0.0 || bary.z < 0.0 {
+            return false;
+        }
+        let normal = (self.b - self.a).cross(self.c - self.a);
+        let height = (point - self.a).dot(normal);
+        height * height <= f32::EPSILON * f32::EPSILON * normal.dot(normal) * self.longest_edge()
+    }
+
+    /// Returns the point of the triangle closest to `point`
+    ///
+    /// This uses the Voronoi regions of the corners and edges, falling back to the closest of
+    /// the three edges for a degenerate triangle.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::triangle::Triangle3f;
+    /// use yavml::vec3::Vec3f;
+    /// let triangle = Triangle3f::new(Vec3f::ZERO, Vec3f::new(4.0, 0.0, 0.0), Vec3f::new(0.0, 4.0, 0.0));
+    /// assert_eq!(triangle.closest_point(Vec3f::new(1.0, 1.0, 5.0)), Vec3f::new(1.0, 1.0, 0.0));
+    /// assert_eq!(triangle.closest_point(Vec3f::new(-1.0, -1.0, 0.0)), Vec3f::ZERO);
+    /// ```
+    pub fn closest_point(&self, point: Vec3f) -> Vec3f {
+        let (a, b, c) = (self.a, self.b, self.c);
+        let ab = b - a;
+        let ac = c - a;
+        let normal = ab.cross(ac);
+        if normal.dot(normal) == 0.0 {
+            return closest_on_edges([a, b, c], point);
+        }
+        let ap = point - a;
+        let d1 = ab.dot(ap);
+        let d2 = ac.dot(ap);
+        if d1 <= 0.0 && d2 <= 0.0 {
+            return a;
+        }
+        let bp = point - b;
+        let d3 = ab.dot(bp);
+        let d4 = ac.dot(bp);
+        if d3 >= 0.0 && d4 <= d3 {
+            return b;
+        }
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+            return a + ab * (d1 / (d1 - d3));
+        }
+        let cp = point - c;
+        let d5 = ab.dot(cp);
+        let d6 = ac.dot(cp);
+        if d6 >= 0.0 && d5 <= d6 {
+            return c;
+        }
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+            return a + ac * (d2 / (d2 - d6));
+        }
+        let va = d3 * d6 - d5 * d4;
+        if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+        let denom = 1.0 / (va + vb + vc);
+        a + ab * (vb * denom) + ac * (vc * denom)
+    }
+
+    /// Returns the circle through all three corners, or `None` if the triangle is degenerate
+    ///
+    /// The circle is returned as the sphere with the same center and radius. Its center lies in
+    /// the plane of the triangle.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::circle::Sphere;
+    /// use yavml::triangle::Triangle3f;
+    /// use yavml::vec3::Vec3f;
+    /// let triangle = Triangle3f::new(Vec3f::ZERO, Vec3f::new(6.0, 0.0, 0.0), Vec3f::new(0.0, 0.0, 8.0));
+    /// assert_eq!(triangle.circumcircle(), Some(Sphere::new(Vec3f::new(3.0, 0.0, 4.0), 5.0)));
+    /// ```
+    pub fn circumcircle(&self) -> Option<Sphere> {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let normal = ab.cross(ac);
+        let normal_squared = normal.dot(normal);
+        if normal_squared == 0.0 {
+            return None;
+        }
+        let offset = (normal.cross(ab) * ac.dot(ac) + ac.cross(normal) * ab.dot(ab))
+            / (2.0 * normal_squared);
+        Some(Sphere::new(self.a + offset, offset.dot(offset).sqrt()))
+    }
+
+    /// Returns the largest circle inside the triangle, which touches all three edges, or `None`
+    /// if the triangle is degenerate
+    ///
+    /// The circle is returned as the sphere with the same center and radius. Its center lies in
+    /// the plane of the triangle.
+    pub fn incircle(&self) -> Option<Sphere> {
+        let cross = (self.b - self.a).cross(self.c - self.a);
+        let double_area = cross.dot(cross).sqrt();
+        if double_area == 0.0 {
+            return None;
+        }
+        let length = |v: Vec3f| v.dot(v).sqrt();
+        let (la, lb, lc) = (
+            length(self.c - self.b),
+            length(self.a - self.c),
+            length(self.b - self.a),
+        );
+        let perimeter = la + lb + lc;
+        let center = (self.a * la + self.b * lb + self.c * lc) / perimeter;
+        Some(Sphere::new(center, double_area / perimeter))
+    }
+
+    /// Returns the squared length of the longest edge
+    fn longest_edge(&self) -> f32 {
+        [self.b - self.a, self.c - self.b, self.a - self.c]
+            .iter()
+            .map(|edge| edge.dot(*edge))
+            .fold(0.0, f32::max)
+    }
+}
+
+/// Returns the point of the three edges of a degenerate triangle closest to `point`
+fn closest_on_edges([a, b, c]: [Vec3f; 3], point: Vec3f) -> Vec3f {
+    [
+        Segment3f::new(a, b),
+        Segment3f::new(b, c),
+        Segment3f::new(c, a),
+    ]
+    .map(|edge| edge.closest_point(point))
+    .into_iter()
+    .min_by(|p, q| {
+        let (p, q) = (*p - point, *q - point);
+        p.dot(p).total_cmp(&q.dot(q))
+    })
+    .unwrap_or(a)
+}
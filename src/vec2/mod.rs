@@ -6,3 +6,6 @@ pub use vec2d::Vec2d;
 
 pub mod vec2i;
 pub use vec2i::Vec2i;
+
+#[cfg(test)]
+mod tests;
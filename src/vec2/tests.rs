@@ -2,6 +2,9 @@ use super::Vec2d;
 use super::Vec2f;
 use super::Vec2i;
 mod vec2i_tests {
+    use super::Vec2d;
+    use super::Vec2f;
+    use super::Vec2i;
 
     // Tests creating a new `Vec2i`
     #[test]
@@ -191,6 +194,17 @@ mod vec2f_tests {
         assert_eq!(Vec2f::splat(0.0), Vec2f::ZERO)
     }
 
+    // Tests rotating a `Vec2f` by radians and by degrees
+    #[test]
+    fn test_vec2f_rotate() {
+        let vec = Vec2f::new(3.0, 4.0);
+        let quarter = vec.rotate(core::f32::consts::FRAC_PI_2);
+        assert!((quarter - Vec2f::new(-4.0, 3.0)).length() < 1e-6);
+        let half = vec.rotate(crate::angle::Degrees(180.0));
+        assert!((half + vec).length() < 1e-5);
+        assert_eq!(vec.rotate(0.0), vec);
+    }
+
     //Tests converting a `Vec2f` to an array
     #[test]
     fn test_vec2f_to_array() {
@@ -353,6 +367,13 @@ mod vec2d_tests {
     use super::Vec2f;
     use super::Vec2i;
 
+    // Tests rotating a `Vec2d` by degrees
+    #[test]
+    fn test_vec2d_rotate() {
+        let rotated = Vec2d::new(1.0, 1.0).rotate(crate::angle::Degrees(-90.0));
+        assert!((rotated - Vec2d::new(1.0, -1.0)).length() < 1e-12);
+    }
+
     // Tests creating a new `Vec2d`
     #[test]
     fn test_vec2d_new() {
@@ -381,10 +402,7 @@ mod vec2d_tests {
     // Tests converting a `Vec2d` to `Vec2i`
     #[test]
     fn test_vec2d_as_vec2i() {
-        assert_eq!(
-            Vec2f::new(6.21999979019165, 7.21999979019165).as_vec2i(),
-            Vec2i { x: 6, y: 7 }
-        )
+        assert_eq!(Vec2d::new(6.22, 7.22).as_vec2i(), Vec2i { x: 6, y: 7 })
     }
 
     // Tests converting a `Vec2d` to `Vec2f`
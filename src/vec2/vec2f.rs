@@ -1,5 +1,7 @@
 use core::ops::*;
 
+use crate::angle::Radians;
+
 use super::{vec2d::Vec2d, vec2i::Vec2i};
 /// An integer-holding vector with 2 values
 #[derive(Clone, Copy, Default, Debug, PartialEq)]
@@ -127,6 +129,24 @@ impl Vec2f {
         self.x * rhs.y - self.y * rhs.x
     }
 
+    /// Returns `self` rotated counter-clockwise by `angle`
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle to rotate by, in radians or as [`Degrees`](crate::angle::Degrees)
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::angle::Degrees;
+    /// use yavml::vec2::Vec2f;
+    /// let rotated = Vec2f::new(2.0, 0.0).rotate(Degrees(90.0));
+    /// assert!((rotated - Vec2f::new(0.0, 2.0)).length() < 1e-6);
+    /// ```
+    pub fn rotate(self, angle: impl Into<Radians<f32>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        Self::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+
     /// Sets the x and y value of a vector
     ///
     /// #Arguments
@@ -237,3 +257,12 @@ impl DivAssign<f32> for Vec2f {
         self.y /= rhs;
     }
 }
+
+/// Negation of a vector
+impl Neg for Vec2f {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
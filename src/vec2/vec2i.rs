@@ -75,7 +75,6 @@ impl Vec2i {
     /// use yavml::vec2::Vec2i;
     /// use yavml::vec2::Vec2f;
     /// assert_eq!(Vec2i::new(6,7).as_vec2f(),Vec2f{x: 6.0, y: 7.0})
-
     /// ```
     pub const fn as_vec2f(&self) -> Vec2f {
         Vec2f::new(self.x as f32, self.y as f32)
@@ -238,3 +237,12 @@ impl DivAssign<i32> for Vec2i {
         self.y /= rhs;
     }
 }
+
+/// Negation of a vector
+impl Neg for Vec2i {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
@@ -1 +1,11 @@
+pub mod vec3f;
+pub use vec3f::Vec3f;
 
+pub mod vec3d;
+pub use vec3d::Vec3d;
+
+pub mod vec3i;
+pub use vec3i::Vec3i;
+
+#[cfg(test)]
+mod tests;
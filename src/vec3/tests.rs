@@ -0,0 +1,144 @@
+use super::Vec3d;
+use super::Vec3f;
+use super::Vec3i;
+
+mod vec3i_tests {
+    use super::Vec3f;
+    use super::Vec3i;
+
+    // Tests creating a new `Vec3i`
+    #[test]
+    fn test_vec3i_new() {
+        assert_eq!(Vec3i { x: 1, y: 1, z: 1 }, Vec3i::ONE);
+        assert_eq!(Vec3i::splat(0), Vec3i::ZERO);
+    }
+
+    // Tests converting a `Vec3i` to and from an array
+    #[test]
+    fn test_vec3i_array() {
+        assert_eq!(Vec3i::new(1, 5, 9).to_array(), [1, 5, 9]);
+        assert_eq!(Vec3i::from_arr([1, 5, 9]), Vec3i::new(1, 5, 9));
+    }
+
+    // Tests converting a `Vec3i` to `Vec3f`
+    #[test]
+    fn test_vec3i_as_vec3f() {
+        assert_eq!(Vec3i::new(6, 7, 8).as_vec3f(), Vec3f::new(6.0, 7.0, 8.0));
+    }
+
+    // Tests that the cross product of the x and y axes is the z axis
+    #[test]
+    fn test_vec3i_cross_product() {
+        assert_eq!(Vec3i::X.cross(Vec3i::Y), Vec3i::Z);
+        assert_eq!(Vec3i::Y.cross(Vec3i::X), -Vec3i::Z);
+    }
+
+    // Tests the arithmetic operators for `Vec3i`
+    #[test]
+    fn test_vec3i_arithmetic() {
+        let mut vec = Vec3i::new(1, 2, 3) + Vec3i::ONE;
+        assert_eq!(vec, Vec3i::new(2, 3, 4));
+        vec -= Vec3i::ONE;
+        assert_eq!(vec * 2, Vec3i::new(2, 4, 6));
+        vec *= Vec3i::new(3, 2, 1);
+        assert_eq!(vec, Vec3i::new(3, 4, 3));
+        assert_eq!(vec / 3, Vec3i::new(1, 1, 1));
+    }
+}
+
+mod vec3f_tests {
+    use super::Vec3d;
+    use super::Vec3f;
+
+    // Tests calculating the dot product of two `Vec3f`
+    #[test]
+    fn test_vec3f_dot_product() {
+        let vec1 = Vec3f::new(5.0, 8.0, 1.0);
+        let vec2 = Vec3f::new(7.0, 8.0, 2.0);
+        assert_eq!(vec1.dot(vec2), 101.0);
+    }
+
+    // Tests calculating the cross product of two `Vec3f`
+    #[test]
+    fn test_vec3f_cross_product() {
+        let vec1 = Vec3f::new(1.0, 2.0, 3.0);
+        let vec2 = Vec3f::new(4.0, 5.0, 6.0);
+        let cross = vec1.cross(vec2);
+        assert_eq!(cross, Vec3f::new(-3.0, 6.0, -3.0));
+        assert_eq!(cross.dot(vec1), 0.0);
+        assert_eq!(cross.dot(vec2), 0.0);
+    }
+
+    // Tests rotating a `Vec3f` around an axis, keeping the component along the axis
+    #[test]
+    fn test_vec3f_rotate_axis_angle() {
+        let vec = Vec3f::new(1.0, 0.0, 2.0);
+        let rotated = vec.rotate_axis_angle(Vec3f::Z, core::f32::consts::FRAC_PI_2);
+        assert!((rotated - Vec3f::new(0.0, 1.0, 2.0)).length() < 1e-6);
+        let axis = Vec3f::ONE.normalize();
+        let third = Vec3f::X.rotate_axis_angle(axis, crate::angle::Degrees(120.0));
+        assert!((third - Vec3f::Y).length() < 1e-6);
+    }
+
+    // Tests calculating the length of a vector
+    #[test]
+    fn test_vec3f_length() {
+        assert_eq!(Vec3f::new(2.0, 3.0, 6.0).length(), 7.0);
+    }
+
+    // Tests setting x, y and z of a vector to new values
+    #[test]
+    fn test_vec3f_set() {
+        let mut vec = Vec3f::ZERO;
+        vec.set(1.0, 2.0, 3.0);
+        assert_eq!(vec, Vec3f::new(1.0, 2.0, 3.0));
+    }
+
+    // Tests the division operators for `Vec3f`
+    #[test]
+    fn test_vec3f_division() {
+        let mut vec = Vec3f::new(2.0, 4.0, 8.0);
+        assert_eq!(vec / 2.0, Vec3f::new(1.0, 2.0, 4.0));
+        vec /= Vec3f::new(2.0, 4.0, 8.0);
+        assert_eq!(vec, Vec3f::ONE);
+    }
+
+    // Tests converting a `Vec3f` to `Vec3d`
+    #[test]
+    fn test_vec3f_as_vec3d() {
+        assert_eq!(
+            Vec3f::new(0.5, 1.5, -2.5).as_vec3d(),
+            Vec3d::new(0.5, 1.5, -2.5)
+        );
+    }
+}
+
+mod vec3d_tests {
+    use super::Vec3d;
+    use super::Vec3i;
+
+    // Tests rotating a `Vec3d` around an axis by degrees
+    #[test]
+    fn test_vec3d_rotate_axis_angle() {
+        let rotated = Vec3d::Y.rotate_axis_angle(Vec3d::X, crate::angle::Degrees(90.0));
+        assert!((rotated - Vec3d::Z).length() < 1e-12);
+    }
+
+    // Tests calculating the length of a vector
+    #[test]
+    fn test_vec3d_length() {
+        assert_eq!(Vec3d::new(1.0, 4.0, 8.0).length(), 9.0);
+    }
+
+    // Tests converting a `Vec3d` to `Vec3i`
+    #[test]
+    fn test_vec3d_as_vec3i() {
+        assert_eq!(Vec3d::new(6.7, -7.2, 8.0).as_vec3i(), Vec3i::new(6, -7, 8));
+    }
+
+    // Tests negating a `Vec3d`
+    #[test]
+    fn test_vec3d_negation() {
+        assert_eq!(-Vec3d::new(1.0, -2.0, 0.5), Vec3d::new(-1.0, 2.0, -0.5));
+    }
+}
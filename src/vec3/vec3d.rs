@@ -0,0 +1,307 @@
+use core::ops::*;
+
+use crate::angle::Radians;
+
+use super::{vec3f::Vec3f, vec3i::Vec3i};
+/// A double floating-point vector with 3 values
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct Vec3d {
+    ///The x value of the vector
+    pub x: f64,
+    ///The y value of the vector
+    pub y: f64,
+    ///The z value of the vector
+    pub z: f64,
+}
+
+impl Vec3d {
+    /// Vector of all zeros
+    pub const ZERO: Self = Self::splat(0.0);
+    /// Vector of all ones
+    pub const ONE: Self = Self::splat(1.0);
+    /// Vector of all negative ones
+    pub const NEG_ONE: Self = Self::splat(-1.0);
+
+    /// A unit vector pointing along positive x
+    pub const X: Self = Self::new(1.0, 0.0, 0.0);
+    /// A unit vector pointing along positive y
+    pub const Y: Self = Self::new(0.0, 1.0, 0.0);
+    /// A unit vector pointing along positive z
+    pub const Z: Self = Self::new(0.0, 0.0, 1.0);
+
+    /// Creates a new vector
+    /// # Arguments
+    ///
+    /// * `x` - An `f64` that holds the x value of the vector
+    ///
+    /// * `y` - An `f64` that holds the y value of the vector
+    ///
+    /// * `z` - An `f64` that holds the z value of the vector
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use yavml::vec3::Vec3d;
+    /// let vector = Vec3d::new(1.0,1.0,1.0);
+    /// ```
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+    /// Creates a Vector with all elements set to `val`
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - An `f64` that will hold the `x`, `y` and `z` values of the vector
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec3::Vec3d;
+    /// let vector = Vec3d::splat(2.0);
+    /// assert_eq!(vector,Vec3d::new(2.0,2.0,2.0));
+    /// ```
+    pub const fn splat(val: f64) -> Self {
+        Self::new(val, val, val)
+    }
+    /// Create a new Vector from an 3 item-length array
+    pub const fn from_arr(arr: [f64; 3]) -> Self {
+        Self::new(arr[0], arr[1], arr[2])
+    }
+
+    /// Create an array from a Vector's `x`, `y` and `z` values
+    pub const fn to_array(&self) -> [f64; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Cast a `Vec3d` vector as a `Vec3f` floating-point vector
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec3::Vec3d;
+    /// use yavml::vec3::Vec3f;
+    /// assert_eq!(Vec3d::new(6.5,7.5,8.5).as_vec3f(),Vec3f{x: 6.5, y: 7.5, z: 8.5})
+    /// ```
+    pub const fn as_vec3f(&self) -> Vec3f {
+        Vec3f::new(self.x as f32, self.y as f32, self.z as f32)
+    }
+    /// Cast a `Vec3d` vector as a `Vec3i` integer vector
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec3::Vec3d;
+    /// use yavml::vec3::Vec3i;
+    /// assert_eq!(Vec3d::new(6.5,7.5,8.5).as_vec3i(),Vec3i{x: 6, y: 7, z: 8})
+    /// ```
+    pub const fn as_vec3i(&self) -> Vec3i {
+        Vec3i::new(self.x as i32, self.y as i32, self.z as i32)
+    }
+    /// Returns the dot product of the `self` and `rhs`
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first `Vec3d`
+    ///
+    /// * `rhs` - The second `Vec3d`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec3::Vec3d;
+    /// let vector1 = Vec3d::new(2.0,3.0,4.0);
+    /// let vector2 = Vec3d::new(5.0,6.0,7.0);
+    /// assert_eq!(vector1.dot(vector2),56.0)
+    /// ```
+    pub fn dot(self, rhs: Self) -> f64 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    /// Returns the cross product of `self` and `rhs`
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first `Vec3d`
+    ///
+    /// * `rhs` - The Second `Vec3d`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec3::Vec3d;
+    /// let vector1 = Vec3d::new(2.0,3.0,4.0);
+    /// let vector2 = Vec3d::new(5.0,6.0,7.0);
+    /// assert_eq!(vector1.cross(vector2),Vec3d::new(-3.0,6.0,-3.0))
+    /// ```
+    pub fn cross(self, rhs: Self) -> Self {
+        Self::new(
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+        )
+    }
+
+    /// Sets the x, y and z value of a vector
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The Vector being set
+    pub fn set(&mut self, new_x: f64, new_y: f64, new_z: f64) {
+        self.x = new_x;
+        self.y = new_y;
+        self.z = new_z;
+    }
+
+    /// Returns the length of the vector `self`
+    pub fn length(self) -> f64 {
+        (self.dot(self)).sqrt()
+    }
+
+    /// Returns `self` scaled to a length of `1`
+    ///
+    /// The result is non-finite if `self` has a length of zero.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec3::Vec3d;
+    /// assert_eq!(Vec3d::new(0.0, 3.0, 4.0).normalize(), Vec3d::new(0.0, 0.6, 0.8));
+    /// ```
+    pub fn normalize(self) -> Self {
+        self / self.length()
+    }
+
+    /// Returns `self` rotated by `angle` around the normalized `axis`
+    ///
+    /// The rotation is counter-clockwise when looking down `axis` towards the origin, matching
+    /// [`Quatd::from_axis_angle`](crate::quat::Quatd::from_axis_angle).
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The normalized axis to rotate around
+    ///
+    /// * `angle` - The angle to rotate by, in radians or as [`Degrees`](crate::angle::Degrees)
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::angle::Degrees;
+    /// use yavml::vec3::Vec3d;
+    /// let rotated = Vec3d::X.rotate_axis_angle(Vec3d::Z, Degrees(90.0));
+    /// assert!((rotated - Vec3d::Y).length() < 1e-6);
+    /// ```
+    pub fn rotate_axis_angle(self, axis: Self, angle: impl Into<Radians<f64>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        self * cos + axis.cross(self) * sin + axis * (axis.dot(self) * (1.0 - cos))
+    }
+}
+
+/// Addition of vectors
+impl Add<Vec3d> for Vec3d {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+/// Addition Assignments of vectors
+impl AddAssign<Vec3d> for Vec3d {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+/// Subtraction of vectors
+impl Sub<Vec3d> for Vec3d {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+/// Subtraction Assignments of vectors
+impl SubAssign<Vec3d> for Vec3d {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+/// Multiplication of vectors
+impl Mul<Vec3d> for Vec3d {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.x * rhs.x, self.y * rhs.y, self.z * rhs.z)
+    }
+}
+
+/// Multiplication assignment of Vectors
+impl MulAssign<Vec3d> for Vec3d {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+        self.z *= rhs.z;
+    }
+}
+
+/// Multiplication of a vector by an `f64`
+impl Mul<f64> for Vec3d {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+/// Multiplication assignment of a vector by an `f64`
+impl MulAssign<f64> for Vec3d {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
+/// Division of vectors
+impl Div<Vec3d> for Vec3d {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(self.x / rhs.x, self.y / rhs.y, self.z / rhs.z)
+    }
+}
+
+/// Division assignment of Vectors
+impl DivAssign<Vec3d> for Vec3d {
+    fn div_assign(&mut self, rhs: Self) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+        self.z /= rhs.z;
+    }
+}
+
+/// Division of a vector by an `f64`
+impl Div<f64> for Vec3d {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+/// Division assignment of a vector by an `f64`
+impl DivAssign<f64> for Vec3d {
+    fn div_assign(&mut self, rhs: f64) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+    }
+}
+
+/// Negation of a vector
+impl Neg for Vec3d {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
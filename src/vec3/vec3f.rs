@@ -0,0 +1,307 @@
+use core::ops::*;
+
+use crate::angle::Radians;
+
+use super::{vec3d::Vec3d, vec3i::Vec3i};
+/// A floating-point vector with 3 values
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct Vec3f {
+    ///The x value of the vector
+    pub x: f32,
+    ///The y value of the vector
+    pub y: f32,
+    ///The z value of the vector
+    pub z: f32,
+}
+
+impl Vec3f {
+    /// Vector of all zeros
+    pub const ZERO: Self = Self::splat(0.0);
+    /// Vector of all ones
+    pub const ONE: Self = Self::splat(1.0);
+    /// Vector of all negative ones
+    pub const NEG_ONE: Self = Self::splat(-1.0);
+
+    /// A unit vector pointing along positive x
+    pub const X: Self = Self::new(1.0, 0.0, 0.0);
+    /// A unit vector pointing along positive y
+    pub const Y: Self = Self::new(0.0, 1.0, 0.0);
+    /// A unit vector pointing along positive z
+    pub const Z: Self = Self::new(0.0, 0.0, 1.0);
+
+    /// Creates a new vector
+    /// # Arguments
+    ///
+    /// * `x` - An `f32` that holds the x value of the vector
+    ///
+    /// * `y` - An `f32` that holds the y value of the vector
+    ///
+    /// * `z` - An `f32` that holds the z value of the vector
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use yavml::vec3::Vec3f;
+    /// let vector = Vec3f::new(1.0,1.0,1.0);
+    /// ```
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+    /// Creates a Vector with all elements set to `val`
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - An `f32` that will hold the `x`, `y` and `z` values of the vector
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec3::Vec3f;
+    /// let vector = Vec3f::splat(2.0);
+    /// assert_eq!(vector,Vec3f::new(2.0,2.0,2.0));
+    /// ```
+    pub const fn splat(val: f32) -> Self {
+        Self::new(val, val, val)
+    }
+    /// Create a new Vector from an 3 item-length array
+    pub const fn from_arr(arr: [f32; 3]) -> Self {
+        Self::new(arr[0], arr[1], arr[2])
+    }
+
+    /// Create an array from a Vector's `x`, `y` and `z` values
+    pub const fn to_array(&self) -> [f32; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Cast a `Vec3f` vector as a `Vec3d` double floating-point vector
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec3::Vec3f;
+    /// use yavml::vec3::Vec3d;
+    /// assert_eq!(Vec3f::new(6.5,7.5,8.5).as_vec3d(),Vec3d{x: 6.5, y: 7.5, z: 8.5})
+    /// ```
+    pub const fn as_vec3d(&self) -> Vec3d {
+        Vec3d::new(self.x as f64, self.y as f64, self.z as f64)
+    }
+    /// Cast a `Vec3f` vector as a `Vec3i` integer vector
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec3::Vec3f;
+    /// use yavml::vec3::Vec3i;
+    /// assert_eq!(Vec3f::new(6.5,7.5,8.5).as_vec3i(),Vec3i{x: 6, y: 7, z: 8})
+    /// ```
+    pub const fn as_vec3i(&self) -> Vec3i {
+        Vec3i::new(self.x as i32, self.y as i32, self.z as i32)
+    }
+    /// Returns the dot product of the `self` and `rhs`
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first `Vec3f`
+    ///
+    /// * `rhs` - The second `Vec3f`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec3::Vec3f;
+    /// let vector1 = Vec3f::new(2.0,3.0,4.0);
+    /// let vector2 = Vec3f::new(5.0,6.0,7.0);
+    /// assert_eq!(vector1.dot(vector2),56.0)
+    /// ```
+    pub fn dot(self, rhs: Self) -> f32 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    /// Returns the cross product of `self` and `rhs`
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first `Vec3f`
+    ///
+    /// * `rhs` - The Second `Vec3f`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec3::Vec3f;
+    /// let vector1 = Vec3f::new(2.0,3.0,4.0);
+    /// let vector2 = Vec3f::new(5.0,6.0,7.0);
+    /// assert_eq!(vector1.cross(vector2),Vec3f::new(-3.0,6.0,-3.0))
+    /// ```
+    pub fn cross(self, rhs: Self) -> Self {
+        Self::new(
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+        )
+    }
+
+    /// Sets the x, y and z value of a vector
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The Vector being set
+    pub fn set(&mut self, new_x: f32, new_y: f32, new_z: f32) {
+        self.x = new_x;
+        self.y = new_y;
+        self.z = new_z;
+    }
+
+    /// Returns the length of the vector `self`
+    pub fn length(self) -> f64 {
+        ((self.dot(self)) as f64).sqrt()
+    }
+
+    /// Returns `self` scaled to a length of `1`
+    ///
+    /// The result is non-finite if `self` has a length of zero.
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec3::Vec3f;
+    /// assert_eq!(Vec3f::new(0.0, 3.0, 4.0).normalize(), Vec3f::new(0.0, 0.6, 0.8));
+    /// ```
+    pub fn normalize(self) -> Self {
+        self / self.length() as f32
+    }
+
+    /// Returns `self` rotated by `angle` around the normalized `axis`
+    ///
+    /// The rotation is counter-clockwise when looking down `axis` towards the origin, matching
+    /// [`Quatf::from_axis_angle`](crate::quat::Quatf::from_axis_angle).
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The normalized axis to rotate around
+    ///
+    /// * `angle` - The angle to rotate by, in radians or as [`Degrees`](crate::angle::Degrees)
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::angle::Degrees;
+    /// use yavml::vec3::Vec3f;
+    /// let rotated = Vec3f::X.rotate_axis_angle(Vec3f::Z, Degrees(90.0));
+    /// assert!((rotated - Vec3f::Y).length() < 1e-6);
+    /// ```
+    pub fn rotate_axis_angle(self, axis: Self, angle: impl Into<Radians<f32>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        self * cos + axis.cross(self) * sin + axis * (axis.dot(self) * (1.0 - cos))
+    }
+}
+
+/// Addition of vectors
+impl Add<Vec3f> for Vec3f {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+/// Addition Assignments of vectors
+impl AddAssign<Vec3f> for Vec3f {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+/// Subtraction of vectors
+impl Sub<Vec3f> for Vec3f {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+/// Subtraction Assignments of vectors
+impl SubAssign<Vec3f> for Vec3f {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+/// Multiplication of vectors
+impl Mul<Vec3f> for Vec3f {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.x * rhs.x, self.y * rhs.y, self.z * rhs.z)
+    }
+}
+
+/// Multiplication assignment of Vectors
+impl MulAssign<Vec3f> for Vec3f {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+        self.z *= rhs.z;
+    }
+}
+
+/// Multiplication of a vector by an `f32`
+impl Mul<f32> for Vec3f {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+/// Multiplication assignment of a vector by an `f32`
+impl MulAssign<f32> for Vec3f {
+    fn mul_assign(&mut self, rhs: f32) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
+/// Division of vectors
+impl Div<Vec3f> for Vec3f {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(self.x / rhs.x, self.y / rhs.y, self.z / rhs.z)
+    }
+}
+
+/// Division assignment of Vectors
+impl DivAssign<Vec3f> for Vec3f {
+    fn div_assign(&mut self, rhs: Self) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+        self.z /= rhs.z;
+    }
+}
+
+/// Division of a vector by an `f32`
+impl Div<f32> for Vec3f {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+/// Division assignment of a vector by an `f32`
+impl DivAssign<f32> for Vec3f {
+    fn div_assign(&mut self, rhs: f32) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+    }
+}
+
+/// Negation of a vector
+impl Neg for Vec3f {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
@@ -0,0 +1,269 @@
+use core::ops::*;
+
+use super::{vec3d::Vec3d, vec3f::Vec3f};
+/// An integer-holding vector with 3 values
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct Vec3i {
+    ///The x value of the vector
+    pub x: i32,
+    ///The y value of the vector
+    pub y: i32,
+    ///The z value of the vector
+    pub z: i32,
+}
+
+impl Vec3i {
+    /// Vector of all zeros
+    pub const ZERO: Self = Self::splat(0);
+    /// Vector of all ones
+    pub const ONE: Self = Self::splat(1);
+    /// Vector of all negative ones
+    pub const NEG_ONE: Self = Self::splat(-1);
+
+    /// A unit vector pointing along positive x
+    pub const X: Self = Self::new(1, 0, 0);
+    /// A unit vector pointing along positive y
+    pub const Y: Self = Self::new(0, 1, 0);
+    /// A unit vector pointing along positive z
+    pub const Z: Self = Self::new(0, 0, 1);
+
+    /// Creates a new vector
+    /// # Arguments
+    ///
+    /// * `x` - An `i32` that holds the x value of the vector
+    ///
+    /// * `y` - An `i32` that holds the y value of the vector
+    ///
+    /// * `z` - An `i32` that holds the z value of the vector
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use yavml::vec3::Vec3i;
+    /// let vector = Vec3i::new(1,1,1);
+    /// ```
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+    /// Creates a Vector with all elements set to `val`
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - An `i32` that will hold the `x`, `y` and `z` values of the vector
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec3::Vec3i;
+    /// let vector = Vec3i::splat(2);
+    /// assert_eq!(vector,Vec3i::new(2,2,2));
+    /// ```
+    pub const fn splat(val: i32) -> Self {
+        Self::new(val, val, val)
+    }
+    /// Create a new Vector from an 3 item-length array
+    pub const fn from_arr(arr: [i32; 3]) -> Self {
+        Self::new(arr[0], arr[1], arr[2])
+    }
+
+    /// Create an array from a Vector's `x`, `y` and `z` values
+    pub const fn to_array(&self) -> [i32; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Cast a `Vec3i` vector as a `Vec3f` floating-point vector
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec3::Vec3i;
+    /// use yavml::vec3::Vec3f;
+    /// assert_eq!(Vec3i::new(6,7,8).as_vec3f(),Vec3f{x: 6.0, y: 7.0, z: 8.0})
+    /// ```
+    pub const fn as_vec3f(&self) -> Vec3f {
+        Vec3f::new(self.x as f32, self.y as f32, self.z as f32)
+    }
+    /// Cast a `Vec3i` vector as a `Vec3d` double floating-point vector
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec3::Vec3i;
+    /// use yavml::vec3::Vec3d;
+    /// assert_eq!(Vec3i::new(6,7,8).as_vec3d(),Vec3d{x: 6.0, y: 7.0, z: 8.0})
+    /// ```
+    pub const fn as_vec3d(&self) -> Vec3d {
+        Vec3d::new(self.x as f64, self.y as f64, self.z as f64)
+    }
+    /// Returns the dot product of the `self` and `rhs`
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first `Vec3i`
+    ///
+    /// * `rhs` - The second `Vec3i`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec3::Vec3i;
+    /// let vector1 = Vec3i::new(2,3,4);
+    /// let vector2 = Vec3i::new(5,6,7);
+    /// assert_eq!(vector1.dot(vector2),56)
+    /// ```
+    pub fn dot(self, rhs: Self) -> i32 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    /// Returns the cross product of `self` and `rhs`
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first `Vec3i`
+    ///
+    /// * `rhs` - The Second `Vec3i`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec3::Vec3i;
+    /// let vector1 = Vec3i::new(2,3,4);
+    /// let vector2 = Vec3i::new(5,6,7);
+    /// assert_eq!(vector1.cross(vector2),Vec3i::new(-3,6,-3))
+    /// ```
+    pub fn cross(self, rhs: Self) -> Self {
+        Self::new(
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+        )
+    }
+
+    /// Sets the x, y and z value of a vector
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The Vector being set
+    pub fn set(&mut self, new_x: i32, new_y: i32, new_z: i32) {
+        self.x = new_x;
+        self.y = new_y;
+        self.z = new_z;
+    }
+
+    /// Returns the length of the vector `self`
+    pub fn length(self) -> f64 {
+        ((self.dot(self)) as f64).sqrt()
+    }
+}
+
+/// Addition of vectors
+impl Add<Vec3i> for Vec3i {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+/// Addition Assignments of vectors
+impl AddAssign<Vec3i> for Vec3i {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+/// Subtraction of vectors
+impl Sub<Vec3i> for Vec3i {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+/// Subtraction Assignments of vectors
+impl SubAssign<Vec3i> for Vec3i {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+/// Multiplication of vectors
+impl Mul<Vec3i> for Vec3i {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.x * rhs.x, self.y * rhs.y, self.z * rhs.z)
+    }
+}
+
+/// Multiplication assignment of Vectors
+impl MulAssign<Vec3i> for Vec3i {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+        self.z *= rhs.z;
+    }
+}
+
+/// Multiplication of a vector by an `i32`
+impl Mul<i32> for Vec3i {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+/// Multiplication assignment of a vector by an `i32`
+impl MulAssign<i32> for Vec3i {
+    fn mul_assign(&mut self, rhs: i32) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
+/// Division of vectors
+impl Div<Vec3i> for Vec3i {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(self.x / rhs.x, self.y / rhs.y, self.z / rhs.z)
+    }
+}
+
+/// Division assignment of Vectors
+impl DivAssign<Vec3i> for Vec3i {
+    fn div_assign(&mut self, rhs: Self) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+        self.z /= rhs.z;
+    }
+}
+
+/// Division of a vector by an `i32`
+impl Div<i32> for Vec3i {
+    type Output = Self;
+
+    fn div(self, rhs: i32) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+/// Division assignment of a vector by an `i32`
+impl DivAssign<i32> for Vec3i {
+    fn div_assign(&mut self, rhs: i32) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+    }
+}
+
+/// Negation of a vector
+impl Neg for Vec3i {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
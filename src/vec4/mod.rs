@@ -1 +1,11 @@
+pub mod vec4f;
+pub use vec4f::Vec4f;
 
+pub mod vec4d;
+pub use vec4d::Vec4d;
+
+pub mod vec4i;
+pub use vec4i::Vec4i;
+
+#[cfg(test)]
+mod tests;
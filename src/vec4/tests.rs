@@ -0,0 +1,101 @@
+use super::Vec4d;
+use super::Vec4f;
+use super::Vec4i;
+
+mod vec4i_tests {
+    use super::Vec4d;
+    use super::Vec4i;
+
+    // Tests creating a new `Vec4i`
+    #[test]
+    fn test_vec4i_new() {
+        assert_eq!(
+            Vec4i {
+                x: 1,
+                y: 1,
+                z: 1,
+                w: 1
+            },
+            Vec4i::ONE
+        );
+        assert_eq!(Vec4i::splat(0), Vec4i::ZERO);
+    }
+
+    // Tests converting a `Vec4i` to and from an array
+    #[test]
+    fn test_vec4i_array() {
+        assert_eq!(Vec4i::new(1, 5, 9, 2).to_array(), [1, 5, 9, 2]);
+        assert_eq!(Vec4i::from_arr([1, 5, 9, 2]), Vec4i::new(1, 5, 9, 2));
+    }
+
+    // Tests converting a `Vec4i` to `Vec4d`
+    #[test]
+    fn test_vec4i_as_vec4d() {
+        assert_eq!(
+            Vec4i::new(6, 7, 8, 9).as_vec4d(),
+            Vec4d::new(6.0, 7.0, 8.0, 9.0)
+        );
+    }
+
+    // Tests the arithmetic operators for `Vec4i`
+    #[test]
+    fn test_vec4i_arithmetic() {
+        let mut vec = Vec4i::new(1, 2, 3, 4) + Vec4i::ONE;
+        assert_eq!(vec, Vec4i::new(2, 3, 4, 5));
+        vec -= Vec4i::ONE;
+        vec *= 2;
+        assert_eq!(vec, Vec4i::new(2, 4, 6, 8));
+        assert_eq!(vec / Vec4i::new(1, 2, 3, 4), Vec4i::splat(2));
+        assert_eq!(-vec, vec * -1);
+    }
+}
+
+mod vec4f_tests {
+    use super::Vec4f;
+
+    // Tests calculating the dot product of two `Vec4f`
+    #[test]
+    fn test_vec4f_dot_product() {
+        let vec1 = Vec4f::new(1.0, 2.0, 3.0, 4.0);
+        let vec2 = Vec4f::new(5.0, 6.0, 7.0, 8.0);
+        assert_eq!(vec1.dot(vec2), 70.0);
+    }
+
+    // Tests calculating the length of a vector
+    #[test]
+    fn test_vec4f_length() {
+        assert_eq!(Vec4f::new(1.0, 1.0, 1.0, 1.0).length(), 2.0);
+    }
+
+    // Tests setting x, y, z and w of a vector to new values
+    #[test]
+    fn test_vec4f_set() {
+        let mut vec = Vec4f::ZERO;
+        vec.set(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(vec, Vec4f::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(vec.to_array(), [1.0, 2.0, 3.0, 4.0]);
+    }
+}
+
+mod vec4d_tests {
+    use super::Vec4d;
+    use super::Vec4f;
+
+    // Tests converting a `Vec4d` to `Vec4f`
+    #[test]
+    fn test_vec4d_as_vec4f() {
+        assert_eq!(
+            Vec4d::new(0.5, 1.5, 2.5, 3.5).as_vec4f(),
+            Vec4f::new(0.5, 1.5, 2.5, 3.5)
+        );
+    }
+
+    // Tests the division operators for `Vec4d`
+    #[test]
+    fn test_vec4d_division() {
+        let mut vec = Vec4d::new(2.0, 4.0, 8.0, 16.0);
+        vec /= 2.0;
+        assert_eq!(vec, Vec4d::new(1.0, 2.0, 4.0, 8.0));
+        assert_eq!(vec / vec, Vec4d::ONE);
+    }
+}
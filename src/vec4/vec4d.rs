@@ -0,0 +1,279 @@
+use core::ops::*;
+
+use super::{vec4f::Vec4f, vec4i::Vec4i};
+/// A double floating-point vector with 4 values
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct Vec4d {
+    ///The x value of the vector
+    pub x: f64,
+    ///The y value of the vector
+    pub y: f64,
+    ///The z value of the vector
+    pub z: f64,
+    ///The w value of the vector
+    pub w: f64,
+}
+
+impl Vec4d {
+    /// Vector of all zeros
+    pub const ZERO: Self = Self::splat(0.0);
+    /// Vector of all ones
+    pub const ONE: Self = Self::splat(1.0);
+    /// Vector of all negative ones
+    pub const NEG_ONE: Self = Self::splat(-1.0);
+
+    /// A unit vector pointing along positive x
+    pub const X: Self = Self::new(1.0, 0.0, 0.0, 0.0);
+    /// A unit vector pointing along positive y
+    pub const Y: Self = Self::new(0.0, 1.0, 0.0, 0.0);
+    /// A unit vector pointing along positive z
+    pub const Z: Self = Self::new(0.0, 0.0, 1.0, 0.0);
+    /// A unit vector pointing along positive w
+    pub const W: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+    /// Creates a new vector
+    /// # Arguments
+    ///
+    /// * `x` - An `f64` that holds the x value of the vector
+    ///
+    /// * `y` - An `f64` that holds the y value of the vector
+    ///
+    /// * `z` - An `f64` that holds the z value of the vector
+    ///
+    /// * `w` - An `f64` that holds the w value of the vector
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use yavml::vec4::Vec4d;
+    /// let vector = Vec4d::new(1.0,1.0,1.0,1.0);
+    /// ```
+    pub const fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self { x, y, z, w }
+    }
+    /// Creates a Vector with all elements set to `val`
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - An `f64` that will hold the `x`, `y`, `z` and `w` values of the vector
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec4::Vec4d;
+    /// let vector = Vec4d::splat(2.0);
+    /// assert_eq!(vector,Vec4d::new(2.0,2.0,2.0,2.0));
+    /// ```
+    pub const fn splat(val: f64) -> Self {
+        Self::new(val, val, val, val)
+    }
+    /// Create a new Vector from an 4 item-length array
+    pub const fn from_arr(arr: [f64; 4]) -> Self {
+        Self::new(arr[0], arr[1], arr[2], arr[3])
+    }
+
+    /// Create an array from a Vector's `x`, `y`, `z` and `w` values
+    pub const fn to_array(&self) -> [f64; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    /// Cast a `Vec4d` vector as a `Vec4f` floating-point vector
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec4::Vec4d;
+    /// use yavml::vec4::Vec4f;
+    /// assert_eq!(Vec4d::new(6.5,7.5,8.5,9.5).as_vec4f(),Vec4f{x: 6.5, y: 7.5, z: 8.5, w: 9.5})
+    /// ```
+    pub const fn as_vec4f(&self) -> Vec4f {
+        Vec4f::new(self.x as f32, self.y as f32, self.z as f32, self.w as f32)
+    }
+    /// Cast a `Vec4d` vector as a `Vec4i` integer vector
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec4::Vec4d;
+    /// use yavml::vec4::Vec4i;
+    /// assert_eq!(Vec4d::new(6.5,7.5,8.5,9.5).as_vec4i(),Vec4i{x: 6, y: 7, z: 8, w: 9})
+    /// ```
+    pub const fn as_vec4i(&self) -> Vec4i {
+        Vec4i::new(self.x as i32, self.y as i32, self.z as i32, self.w as i32)
+    }
+    /// Returns the dot product of the `self` and `rhs`
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first `Vec4d`
+    ///
+    /// * `rhs` - The second `Vec4d`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec4::Vec4d;
+    /// let vector1 = Vec4d::new(2.0,3.0,4.0,1.0);
+    /// let vector2 = Vec4d::new(5.0,6.0,7.0,2.0);
+    /// assert_eq!(vector1.dot(vector2),58.0)
+    /// ```
+    pub fn dot(self, rhs: Self) -> f64 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+
+    /// Sets the x, y, z and w value of a vector
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The Vector being set
+    pub fn set(&mut self, new_x: f64, new_y: f64, new_z: f64, new_w: f64) {
+        self.x = new_x;
+        self.y = new_y;
+        self.z = new_z;
+        self.w = new_w;
+    }
+
+    /// Returns the length of the vector `self`
+    pub fn length(self) -> f64 {
+        (self.dot(self)).sqrt()
+    }
+}
+
+/// Addition of vectors
+impl Add<Vec4d> for Vec4d {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+            self.w + rhs.w,
+        )
+    }
+}
+
+/// Addition Assignments of vectors
+impl AddAssign<Vec4d> for Vec4d {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+        self.w += rhs.w;
+    }
+}
+
+/// Subtraction of vectors
+impl Sub<Vec4d> for Vec4d {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+            self.w - rhs.w,
+        )
+    }
+}
+
+/// Subtraction Assignments of vectors
+impl SubAssign<Vec4d> for Vec4d {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+        self.w -= rhs.w;
+    }
+}
+
+/// Multiplication of vectors
+impl Mul<Vec4d> for Vec4d {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.x * rhs.x,
+            self.y * rhs.y,
+            self.z * rhs.z,
+            self.w * rhs.w,
+        )
+    }
+}
+
+/// Multiplication assignment of Vectors
+impl MulAssign<Vec4d> for Vec4d {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+        self.z *= rhs.z;
+        self.w *= rhs.w;
+    }
+}
+
+/// Multiplication of a vector by an `f64`
+impl Mul<f64> for Vec4d {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs, self.w * rhs)
+    }
+}
+
+/// Multiplication assignment of a vector by an `f64`
+impl MulAssign<f64> for Vec4d {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+        self.w *= rhs;
+    }
+}
+
+/// Division of vectors
+impl Div<Vec4d> for Vec4d {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.x / rhs.x,
+            self.y / rhs.y,
+            self.z / rhs.z,
+            self.w / rhs.w,
+        )
+    }
+}
+
+/// Division assignment of Vectors
+impl DivAssign<Vec4d> for Vec4d {
+    fn div_assign(&mut self, rhs: Self) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+        self.z /= rhs.z;
+        self.w /= rhs.w;
+    }
+}
+
+/// Division of a vector by an `f64`
+impl Div<f64> for Vec4d {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs, self.z / rhs, self.w / rhs)
+    }
+}
+
+/// Division assignment of a vector by an `f64`
+impl DivAssign<f64> for Vec4d {
+    fn div_assign(&mut self, rhs: f64) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+        self.w /= rhs;
+    }
+}
+
+/// Negation of a vector
+impl Neg for Vec4d {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
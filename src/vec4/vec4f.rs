@@ -0,0 +1,279 @@
+use core::ops::*;
+
+use super::{vec4d::Vec4d, vec4i::Vec4i};
+/// A floating-point vector with 4 values
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct Vec4f {
+    ///The x value of the vector
+    pub x: f32,
+    ///The y value of the vector
+    pub y: f32,
+    ///The z value of the vector
+    pub z: f32,
+    ///The w value of the vector
+    pub w: f32,
+}
+
+impl Vec4f {
+    /// Vector of all zeros
+    pub const ZERO: Self = Self::splat(0.0);
+    /// Vector of all ones
+    pub const ONE: Self = Self::splat(1.0);
+    /// Vector of all negative ones
+    pub const NEG_ONE: Self = Self::splat(-1.0);
+
+    /// A unit vector pointing along positive x
+    pub const X: Self = Self::new(1.0, 0.0, 0.0, 0.0);
+    /// A unit vector pointing along positive y
+    pub const Y: Self = Self::new(0.0, 1.0, 0.0, 0.0);
+    /// A unit vector pointing along positive z
+    pub const Z: Self = Self::new(0.0, 0.0, 1.0, 0.0);
+    /// A unit vector pointing along positive w
+    pub const W: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+    /// Creates a new vector
+    /// # Arguments
+    ///
+    /// * `x` - An `f32` that holds the x value of the vector
+    ///
+    /// * `y` - An `f32` that holds the y value of the vector
+    ///
+    /// * `z` - An `f32` that holds the z value of the vector
+    ///
+    /// * `w` - An `f32` that holds the w value of the vector
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use yavml::vec4::Vec4f;
+    /// let vector = Vec4f::new(1.0,1.0,1.0,1.0);
+    /// ```
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+    /// Creates a Vector with all elements set to `val`
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - An `f32` that will hold the `x`, `y`, `z` and `w` values of the vector
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec4::Vec4f;
+    /// let vector = Vec4f::splat(2.0);
+    /// assert_eq!(vector,Vec4f::new(2.0,2.0,2.0,2.0));
+    /// ```
+    pub const fn splat(val: f32) -> Self {
+        Self::new(val, val, val, val)
+    }
+    /// Create a new Vector from an 4 item-length array
+    pub const fn from_arr(arr: [f32; 4]) -> Self {
+        Self::new(arr[0], arr[1], arr[2], arr[3])
+    }
+
+    /// Create an array from a Vector's `x`, `y`, `z` and `w` values
+    pub const fn to_array(&self) -> [f32; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    /// Cast a `Vec4f` vector as a `Vec4d` double floating-point vector
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec4::Vec4f;
+    /// use yavml::vec4::Vec4d;
+    /// assert_eq!(Vec4f::new(6.5,7.5,8.5,9.5).as_vec4d(),Vec4d{x: 6.5, y: 7.5, z: 8.5, w: 9.5})
+    /// ```
+    pub const fn as_vec4d(&self) -> Vec4d {
+        Vec4d::new(self.x as f64, self.y as f64, self.z as f64, self.w as f64)
+    }
+    /// Cast a `Vec4f` vector as a `Vec4i` integer vector
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec4::Vec4f;
+    /// use yavml::vec4::Vec4i;
+    /// assert_eq!(Vec4f::new(6.5,7.5,8.5,9.5).as_vec4i(),Vec4i{x: 6, y: 7, z: 8, w: 9})
+    /// ```
+    pub const fn as_vec4i(&self) -> Vec4i {
+        Vec4i::new(self.x as i32, self.y as i32, self.z as i32, self.w as i32)
+    }
+    /// Returns the dot product of the `self` and `rhs`
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first `Vec4f`
+    ///
+    /// * `rhs` - The second `Vec4f`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec4::Vec4f;
+    /// let vector1 = Vec4f::new(2.0,3.0,4.0,1.0);
+    /// let vector2 = Vec4f::new(5.0,6.0,7.0,2.0);
+    /// assert_eq!(vector1.dot(vector2),58.0)
+    /// ```
+    pub fn dot(self, rhs: Self) -> f32 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+
+    /// Sets the x, y, z and w value of a vector
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The Vector being set
+    pub fn set(&mut self, new_x: f32, new_y: f32, new_z: f32, new_w: f32) {
+        self.x = new_x;
+        self.y = new_y;
+        self.z = new_z;
+        self.w = new_w;
+    }
+
+    /// Returns the length of the vector `self`
+    pub fn length(self) -> f64 {
+        ((self.dot(self)) as f64).sqrt()
+    }
+}
+
+/// Addition of vectors
+impl Add<Vec4f> for Vec4f {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+            self.w + rhs.w,
+        )
+    }
+}
+
+/// Addition Assignments of vectors
+impl AddAssign<Vec4f> for Vec4f {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+        self.w += rhs.w;
+    }
+}
+
+/// Subtraction of vectors
+impl Sub<Vec4f> for Vec4f {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+            self.w - rhs.w,
+        )
+    }
+}
+
+/// Subtraction Assignments of vectors
+impl SubAssign<Vec4f> for Vec4f {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+        self.w -= rhs.w;
+    }
+}
+
+/// Multiplication of vectors
+impl Mul<Vec4f> for Vec4f {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.x * rhs.x,
+            self.y * rhs.y,
+            self.z * rhs.z,
+            self.w * rhs.w,
+        )
+    }
+}
+
+/// Multiplication assignment of Vectors
+impl MulAssign<Vec4f> for Vec4f {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+        self.z *= rhs.z;
+        self.w *= rhs.w;
+    }
+}
+
+/// Multiplication of a vector by an `f32`
+impl Mul<f32> for Vec4f {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs, self.w * rhs)
+    }
+}
+
+/// Multiplication assignment of a vector by an `f32`
+impl MulAssign<f32> for Vec4f {
+    fn mul_assign(&mut self, rhs: f32) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+        self.w *= rhs;
+    }
+}
+
+/// Division of vectors
+impl Div<Vec4f> for Vec4f {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.x / rhs.x,
+            self.y / rhs.y,
+            self.z / rhs.z,
+            self.w / rhs.w,
+        )
+    }
+}
+
+/// Division assignment of Vectors
+impl DivAssign<Vec4f> for Vec4f {
+    fn div_assign(&mut self, rhs: Self) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+        self.z /= rhs.z;
+        self.w /= rhs.w;
+    }
+}
+
+/// Division of a vector by an `f32`
+impl Div<f32> for Vec4f {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs, self.z / rhs, self.w / rhs)
+    }
+}
+
+/// Division assignment of a vector by an `f32`
+impl DivAssign<f32> for Vec4f {
+    fn div_assign(&mut self, rhs: f32) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+        self.w /= rhs;
+    }
+}
+
+/// Negation of a vector
+impl Neg for Vec4f {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
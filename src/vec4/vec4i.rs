@@ -0,0 +1,279 @@
+use core::ops::*;
+
+use super::{vec4d::Vec4d, vec4f::Vec4f};
+/// An integer-holding vector with 4 values
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct Vec4i {
+    ///The x value of the vector
+    pub x: i32,
+    ///The y value of the vector
+    pub y: i32,
+    ///The z value of the vector
+    pub z: i32,
+    ///The w value of the vector
+    pub w: i32,
+}
+
+impl Vec4i {
+    /// Vector of all zeros
+    pub const ZERO: Self = Self::splat(0);
+    /// Vector of all ones
+    pub const ONE: Self = Self::splat(1);
+    /// Vector of all negative ones
+    pub const NEG_ONE: Self = Self::splat(-1);
+
+    /// A unit vector pointing along positive x
+    pub const X: Self = Self::new(1, 0, 0, 0);
+    /// A unit vector pointing along positive y
+    pub const Y: Self = Self::new(0, 1, 0, 0);
+    /// A unit vector pointing along positive z
+    pub const Z: Self = Self::new(0, 0, 1, 0);
+    /// A unit vector pointing along positive w
+    pub const W: Self = Self::new(0, 0, 0, 1);
+
+    /// Creates a new vector
+    /// # Arguments
+    ///
+    /// * `x` - An `i32` that holds the x value of the vector
+    ///
+    /// * `y` - An `i32` that holds the y value of the vector
+    ///
+    /// * `z` - An `i32` that holds the z value of the vector
+    ///
+    /// * `w` - An `i32` that holds the w value of the vector
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use yavml::vec4::Vec4i;
+    /// let vector = Vec4i::new(1,1,1,1);
+    /// ```
+    pub const fn new(x: i32, y: i32, z: i32, w: i32) -> Self {
+        Self { x, y, z, w }
+    }
+    /// Creates a Vector with all elements set to `val`
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - An `i32` that will hold the `x`, `y`, `z` and `w` values of the vector
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec4::Vec4i;
+    /// let vector = Vec4i::splat(2);
+    /// assert_eq!(vector,Vec4i::new(2,2,2,2));
+    /// ```
+    pub const fn splat(val: i32) -> Self {
+        Self::new(val, val, val, val)
+    }
+    /// Create a new Vector from an 4 item-length array
+    pub const fn from_arr(arr: [i32; 4]) -> Self {
+        Self::new(arr[0], arr[1], arr[2], arr[3])
+    }
+
+    /// Create an array from a Vector's `x`, `y`, `z` and `w` values
+    pub const fn to_array(&self) -> [i32; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    /// Cast a `Vec4i` vector as a `Vec4f` floating-point vector
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec4::Vec4i;
+    /// use yavml::vec4::Vec4f;
+    /// assert_eq!(Vec4i::new(6,7,8,9).as_vec4f(),Vec4f{x: 6.0, y: 7.0, z: 8.0, w: 9.0})
+    /// ```
+    pub const fn as_vec4f(&self) -> Vec4f {
+        Vec4f::new(self.x as f32, self.y as f32, self.z as f32, self.w as f32)
+    }
+    /// Cast a `Vec4i` vector as a `Vec4d` double floating-point vector
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec4::Vec4i;
+    /// use yavml::vec4::Vec4d;
+    /// assert_eq!(Vec4i::new(6,7,8,9).as_vec4d(),Vec4d{x: 6.0, y: 7.0, z: 8.0, w: 9.0})
+    /// ```
+    pub const fn as_vec4d(&self) -> Vec4d {
+        Vec4d::new(self.x as f64, self.y as f64, self.z as f64, self.w as f64)
+    }
+    /// Returns the dot product of the `self` and `rhs`
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first `Vec4i`
+    ///
+    /// * `rhs` - The second `Vec4i`
+    ///
+    /// # Examples:
+    /// ```
+    /// use yavml::vec4::Vec4i;
+    /// let vector1 = Vec4i::new(2,3,4,1);
+    /// let vector2 = Vec4i::new(5,6,7,2);
+    /// assert_eq!(vector1.dot(vector2),58)
+    /// ```
+    pub fn dot(self, rhs: Self) -> i32 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+
+    /// Sets the x, y, z and w value of a vector
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The Vector being set
+    pub fn set(&mut self, new_x: i32, new_y: i32, new_z: i32, new_w: i32) {
+        self.x = new_x;
+        self.y = new_y;
+        self.z = new_z;
+        self.w = new_w;
+    }
+
+    /// Returns the length of the vector `self`
+    pub fn length(self) -> f64 {
+        ((self.dot(self)) as f64).sqrt()
+    }
+}
+
+/// Addition of vectors
+impl Add<Vec4i> for Vec4i {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+            self.w + rhs.w,
+        )
+    }
+}
+
+/// Addition Assignments of vectors
+impl AddAssign<Vec4i> for Vec4i {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+        self.w += rhs.w;
+    }
+}
+
+/// Subtraction of vectors
+impl Sub<Vec4i> for Vec4i {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+            self.w - rhs.w,
+        )
+    }
+}
+
+/// Subtraction Assignments of vectors
+impl SubAssign<Vec4i> for Vec4i {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+        self.w -= rhs.w;
+    }
+}
+
+/// Multiplication of vectors
+impl Mul<Vec4i> for Vec4i {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.x * rhs.x,
+            self.y * rhs.y,
+            self.z * rhs.z,
+            self.w * rhs.w,
+        )
+    }
+}
+
+/// Multiplication assignment of Vectors
+impl MulAssign<Vec4i> for Vec4i {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+        self.z *= rhs.z;
+        self.w *= rhs.w;
+    }
+}
+
+/// Multiplication of a vector by an `i32`
+impl Mul<i32> for Vec4i {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs, self.w * rhs)
+    }
+}
+
+/// Multiplication assignment of a vector by an `i32`
+impl MulAssign<i32> for Vec4i {
+    fn mul_assign(&mut self, rhs: i32) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+        self.w *= rhs;
+    }
+}
+
+/// Division of vectors
+impl Div<Vec4i> for Vec4i {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.x / rhs.x,
+            self.y / rhs.y,
+            self.z / rhs.z,
+            self.w / rhs.w,
+        )
+    }
+}
+
+/// Division assignment of Vectors
+impl DivAssign<Vec4i> for Vec4i {
+    fn div_assign(&mut self, rhs: Self) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+        self.z /= rhs.z;
+        self.w /= rhs.w;
+    }
+}
+
+/// Division of a vector by an `i32`
+impl Div<i32> for Vec4i {
+    type Output = Self;
+
+    fn div(self, rhs: i32) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs, self.z / rhs, self.w / rhs)
+    }
+}
+
+/// Division assignment of a vector by an `i32`
+impl DivAssign<i32> for Vec4i {
+    fn div_assign(&mut self, rhs: i32) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+        self.w /= rhs;
+    }
+}
+
+/// Negation of a vector
+impl Neg for Vec4i {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}